@@ -31,6 +31,9 @@ pub enum ClientErr {
 
     #[error("invalid page size")]
     PageSizeErr(#[from] PageSizeErr),
+
+    #[error("the restore target does not exist")]
+    RestoreTargetMissing,
 }
 
 impl From<http::Error> for ClientErr {
@@ -67,13 +70,43 @@ impl ClientErr {
         }
     }
 
-    pub(crate) fn is_commit_rejected(&self) -> bool {
+    pub fn is_commit_rejected(&self) -> bool {
         match self {
             Self::GraftErr(err) => err.code() == GraftErrCode::CommitRejected,
             _ => false,
         }
     }
 
+    pub fn is_limit_exceeded(&self) -> bool {
+        match self {
+            Self::GraftErr(err) => err.code() == GraftErrCode::LimitExceeded,
+            _ => false,
+        }
+    }
+
+    pub fn is_volume_deleted(&self) -> bool {
+        match self {
+            Self::GraftErr(err) => err.code() == GraftErrCode::VolumeDeleted,
+            _ => false,
+        }
+    }
+
+    /// Returns true if the metastore rejected a compaction or checkpoint
+    /// because the volume changed since it was computed
+    pub fn is_rewrite_rejected(&self) -> bool {
+        match self {
+            Self::GraftErr(err) => err.code() == GraftErrCode::RewriteRejected,
+            _ => false,
+        }
+    }
+
+    pub(crate) fn is_stale_checkpoint(&self) -> bool {
+        match self {
+            Self::GraftErr(err) => err.code() == GraftErrCode::StaleCheckpoint,
+            _ => false,
+        }
+    }
+
     pub(crate) fn is_auth_err(&self) -> bool {
         match self {
             Self::GraftErr(err) => err.code() == GraftErrCode::Unauthorized,
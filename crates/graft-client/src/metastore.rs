@@ -1,15 +1,22 @@
 use bytes::Bytes;
 use culprit::{Culprit, ResultExt};
-use graft_core::{VolumeId, gid::ClientId, lsn::LSN, page_count::PageCount};
+use graft_core::{PageSize, VolumeId, gid::ClientId, lsn::LSN, page_count::PageCount};
 use graft_proto::{
     common::v1::{Commit, LsnRange, SegmentInfo, Snapshot},
     metastore::v1::{
-        CommitRequest, CommitResponse, PullCommitsRequest, PullCommitsResponse, PullGraftRequest,
-        PullGraftResponse, SnapshotRequest, SnapshotResponse,
+        CheckpointRequest, CheckpointResponse, CommitRequest, CommitResponse,
+        CommitTransactionRequest, CommitTransactionResponse, CompactRequest, CompactResponse,
+        DeleteVolumeRequest, DeleteVolumeResponse, ForkRequest, ForkResponse, ListVolumesRequest,
+        ListVolumesResponse, PullCommitsRequest, PullCommitsResponse, PullGraftRequest,
+        PullGraftResponse, SnapshotRequest, SnapshotResponse, VolumeInfo, WatchRequest,
+        WatchResponse, WatchedVolume,
     },
 };
 use splinter_rs::SplinterRef;
-use std::ops::RangeBounds;
+use std::{
+    ops::RangeBounds,
+    time::{Duration, SystemTime},
+};
 use url::Url;
 
 use crate::NetClient;
@@ -35,6 +42,27 @@ impl MetastoreClient {
         let req = SnapshotRequest {
             vid: vid.copy_to_bytes(),
             lsn: lsn.map(Into::into),
+            timestamp: None,
+        };
+        match self.client.send::<_, SnapshotResponse>(uri, req) {
+            Ok(resp) => Ok(resp.snapshot),
+            Err(err) if err.ctx().is_snapshot_missing() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Retrieve the latest snapshot of the volume committed at or before the
+    /// provided time.
+    pub fn snapshot_at_time(
+        &self,
+        vid: &VolumeId,
+        time: SystemTime,
+    ) -> Result<Option<Snapshot>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/snapshot")?;
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: None,
+            timestamp: Some(time.into()),
         };
         match self.client.send::<_, SnapshotResponse>(uri, req) {
             Ok(resp) => Ok(resp.snapshot),
@@ -90,18 +118,162 @@ impl MetastoreClient {
         cid: &ClientId,
         snapshot_lsn: Option<LSN>,
         page_count: PageCount,
+        page_size: PageSize,
         segments: Vec<SegmentInfo>,
     ) -> Result<Snapshot, Culprit<error::ClientErr>> {
+        self.send_commit(CommitRequest::new(
+            vid,
+            cid,
+            snapshot_lsn,
+            page_count,
+            page_size,
+            segments,
+        ))
+    }
+
+    /// Send a commit request to the metastore. Resending a request which
+    /// already landed returns the original snapshot, as long as the request
+    /// has a commit token.
+    pub fn send_commit(&self, req: CommitRequest) -> Result<Snapshot, Culprit<error::ClientErr>> {
         let uri = self.endpoint.build("/metastore/v1/commit")?;
-        let req = CommitRequest {
+        self.client
+            .send::<_, CommitResponse>(uri, req)
+            .map(|r| r.snapshot.expect("missing snapshot after commit"))
+    }
+
+    /// Atomically commit to several volumes. Every commit must carry the same
+    /// commit token, which identifies the transaction. Returns the new
+    /// snapshot of each volume in the same order as the commits.
+    pub fn commit_transaction(
+        &self,
+        commits: Vec<CommitRequest>,
+    ) -> Result<Vec<Snapshot>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/commit_transaction")?;
+        let req = CommitTransactionRequest { commits };
+        self.client
+            .send::<_, CommitTransactionResponse>(uri, req)
+            .map(|r| r.snapshots)
+    }
+
+    /// Append the output of a segment compaction to the Volume. The segments
+    /// must contain the latest version of every page changed since the
+    /// Volume's last checkpoint, as of the provided LSN.
+    pub fn compact(
+        &self,
+        vid: &VolumeId,
+        lsn: LSN,
+        segments: Vec<SegmentInfo>,
+    ) -> Result<Snapshot, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/compact")?;
+        let req = CompactRequest {
             vid: vid.copy_to_bytes(),
-            cid: cid.copy_to_bytes(),
-            snapshot_lsn: snapshot_lsn.map(Into::into),
-            page_count: page_count.into(),
+            lsn: lsn.into(),
             segments,
         };
         self.client
-            .send::<_, CommitResponse>(uri, req)
-            .map(|r| r.snapshot.expect("missing snapshot after commit"))
+            .send::<_, CompactResponse>(uri, req)
+            .map(|r| r.snapshot.expect("missing snapshot after compaction"))
+    }
+
+    pub fn checkpoint(
+        &self,
+        vid: &VolumeId,
+        lsn: LSN,
+        segments: Vec<SegmentInfo>,
+    ) -> Result<Snapshot, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/checkpoint")?;
+        let req = CheckpointRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: lsn.into(),
+            segments,
+        };
+        self.client
+            .send::<_, CheckpointResponse>(uri, req)
+            .map(|r| r.snapshot.expect("missing snapshot after checkpoint"))
+    }
+
+    /// Create a new Volume which starts as a copy of the source Volume at the
+    /// provided LSN, or the latest LSN if not specified.
+    pub fn fork(
+        &self,
+        source_vid: &VolumeId,
+        lsn: Option<LSN>,
+        vid: &VolumeId,
+        cid: &ClientId,
+    ) -> Result<Snapshot, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/fork")?;
+        let req = ForkRequest {
+            source_vid: source_vid.copy_to_bytes(),
+            lsn: lsn.map(Into::into),
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+        };
+        self.client
+            .send::<_, ForkResponse>(uri, req)
+            .map(|r| r.snapshot.expect("missing snapshot after fork"))
+    }
+
+    /// Delete a Volume by writing a tombstone after its latest commit. Once
+    /// deleted, commits and reads of the Volume fail with a `VolumeDeleted`
+    /// error. Returns the tombstone Snapshot.
+    pub fn delete_volume(
+        &self,
+        vid: &VolumeId,
+        cid: &ClientId,
+    ) -> Result<Snapshot, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/delete_volume")?;
+        let req = DeleteVolumeRequest {
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+        };
+        self.client
+            .send::<_, DeleteVolumeResponse>(uri, req)
+            .map(|r| r.snapshot.expect("missing snapshot after delete"))
+    }
+
+    /// List Volumes along with their latest Snapshot, creation time, and
+    /// size, ordered by the string encoding of their Volume ID. Returns at
+    /// most `limit` Volumes following the provided cursor, along with the
+    /// cursor of the next page or None once every Volume has been listed.
+    pub fn list_volumes(
+        &self,
+        cursor: Option<Bytes>,
+        limit: u32,
+    ) -> Result<(Vec<VolumeInfo>, Option<Bytes>), Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/list_volumes")?;
+        let req = ListVolumesRequest {
+            cursor: cursor.unwrap_or_default(),
+            limit,
+        };
+        self.client
+            .send::<_, ListVolumesResponse>(uri, req)
+            .map(|r| {
+                let next_cursor = (!r.next_cursor.is_empty()).then_some(r.next_cursor);
+                (r.volumes, next_cursor)
+            })
+    }
+
+    /// Wait until at least one of the provided Volumes has a commit newer than
+    /// the paired LSN, or until the timeout elapses. Returns the latest
+    /// Snapshot of every Volume which changed, which is empty on timeout.
+    pub fn watch(
+        &self,
+        volumes: impl IntoIterator<Item = (VolumeId, Option<LSN>)>,
+        timeout: Duration,
+    ) -> Result<Vec<Snapshot>, Culprit<error::ClientErr>> {
+        let uri = self.endpoint.build("/metastore/v1/watch")?;
+        let req = WatchRequest {
+            volumes: volumes
+                .into_iter()
+                .map(|(vid, lsn)| WatchedVolume {
+                    vid: vid.copy_to_bytes(),
+                    lsn: lsn.map(Into::into),
+                })
+                .collect(),
+            timeout_ms: timeout.as_millis().try_into().unwrap_or(u32::MAX),
+        };
+        self.client
+            .send::<_, WatchResponse>(uri, req)
+            .map(|r| r.volumes)
     }
 }
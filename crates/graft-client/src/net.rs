@@ -1,17 +1,19 @@
-use bytes::{BufMut, BytesMut};
+use bytes::{BufMut, Bytes, BytesMut};
 use culprit::{Culprit, ResultExt};
 use graft_core::byte_unit::ByteUnit;
 use graft_proto::common::v1::GraftErr;
 use http::{
-    HeaderName, HeaderValue, Uri,
+    HeaderName, HeaderValue, StatusCode, Uri,
     header::AUTHORIZATION,
     uri::{Builder, PathAndQuery},
 };
-use std::{any::type_name, sync::Arc, time::Duration};
+use std::{any::type_name, io::Read, marker::PhantomData, sync::Arc, time::Duration};
 use tracing::field;
 use url::Url;
 
-use ureq::{Agent, config::AutoHeaderValue};
+use ureq::{
+    Agent, Body, BodyReader, RequestBuilder, SendBody, config::AutoHeaderValue, typestate::WithBody,
+};
 
 use crate::{USER_AGENT, error::ClientErr};
 
@@ -79,46 +81,96 @@ impl NetClient {
             tracing::trace_span!("NetClient::send", path = uri.path(), status = field::Empty)
                 .entered();
 
+        let resp = self.post(uri).send(&msg.encode_to_vec())?;
+        span.record("status", resp.status().as_u16());
+        Self::decode_response(resp)
+    }
+
+    /// Send a request whose body is read incrementally from `body`. Since
+    /// streamed requests may be arbitrarily large, the caller provides a
+    /// timeout scaled to the size of the body which replaces the global
+    /// request timeout.
+    pub(crate) fn send_stream<Resp: Message + Default>(
+        &self,
+        uri: Uri,
+        body: &mut dyn Read,
+        timeout: Duration,
+    ) -> Result<Resp, Culprit<ClientErr>> {
+        let span = tracing::trace_span!(
+            "NetClient::send_stream",
+            path = uri.path(),
+            status = field::Empty
+        )
+        .entered();
+
+        let resp = self
+            .post(uri)
+            .config()
+            .timeout_global(Some(timeout))
+            .build()
+            .send(SendBody::from_reader(body))?;
+        span.record("status", resp.status().as_u16());
+        Self::decode_response(resp)
+    }
+
+    /// Send a request whose response is a stream of length delimited
+    /// messages. Each message is limited to `MAX_READ_SIZE`, but the stream
+    /// itself is unbounded and not subject to the global request timeout.
+    pub(crate) fn send_recv_stream<Msg: Message, Resp: Message + Default>(
+        &self,
+        uri: Uri,
+        msg: Msg,
+    ) -> Result<ResponseStream<Resp>, Culprit<ClientErr>> {
+        let span = tracing::trace_span!(
+            "NetClient::send_recv_stream",
+            path = uri.path(),
+            status = field::Empty
+        )
+        .entered();
+
+        let resp = self
+            .post(uri)
+            .config()
+            .timeout_global(None)
+            .build()
+            .send(&msg.encode_to_vec())?;
+        let status = resp.status();
+        span.record("status", status.as_u16());
+
+        check_content_type(&resp)?;
+        if !status.is_success() {
+            return Err(decode_err(status, read_body(resp)?));
+        }
+
+        Ok(ResponseStream {
+            reader: resp.into_body().into_reader(),
+            done: false,
+            _phantom: PhantomData,
+        })
+    }
+
+    fn post(&self, uri: Uri) -> RequestBuilder<WithBody> {
         let req = self
             .agent
             .post(uri)
             .header(CONTENT_TYPE, APPLICATION_PROTOBUF);
 
-        let req = if let Some(token) = &self.api_token {
+        if let Some(token) = &self.api_token {
             req.header(AUTHORIZATION, format!("Bearer {}", token))
         } else {
             req
-        };
-
-        let resp = req.send(&msg.encode_to_vec())?;
-
-        let status = resp.status();
-        span.record("status", status.as_u16());
-
-        let content_type = resp.headers().get(CONTENT_TYPE);
-        if content_type != Some(&APPLICATION_PROTOBUF) {
-            return Err(
-                Culprit::new(ClientErr::ProtobufDecodeErr).with_note(format!(
-                    "expected content type '{}' but received {:?}",
-                    APPLICATION_PROTOBUF.to_str().unwrap(),
-                    content_type
-                )),
-            );
         }
+    }
 
-        let success = (200..300).contains(&status.as_u16());
-
-        // read the response into a Bytes object
-        let reader = resp
-            .into_body()
-            .into_with_config()
-            .limit(MAX_READ_SIZE.as_u64());
-        let mut writer = BytesMut::new().writer();
-        std::io::copy(&mut reader.reader(), &mut writer).or_into_ctx()?;
-        let body = writer.into_inner().freeze();
-        let body_size = ByteUnit::new(body.len() as u64);
+    fn decode_response<Resp: Message + Default>(
+        resp: http::Response<Body>,
+    ) -> Result<Resp, Culprit<ClientErr>> {
+        let status = resp.status();
+        check_content_type(&resp)?;
+        let body = read_body(resp)?;
 
-        if success {
+        if status.is_success() {
+            let body_size = ByteUnit::new(body.len() as u64);
             Ok(Resp::decode(body).map_err(|err| {
                 let note = format!(
                     "failed to decode response body into {} from buffer of size {}",
@@ -128,25 +180,116 @@ impl NetClient {
                 Culprit::from_err(err).with_note(note)
             })?)
         } else {
-            let err = GraftErr::decode(body).map_err(|err| {
-                let note = format!(
-                    "failed to decode response body into GraftErr from buffer of size {body_size}"
-                );
-                Culprit::from_err(err).with_note(note)
-            })?;
-
-            // 5xx errors are not expected from client requests unless the graft
-            // error signals that the service is temporarily unavailable
-            precept::expect_always_or_unreachable!(
-                !(500..600).contains(&status.as_u16()) || err.code() == graft_proto::GraftErrCode::ServiceUnavailable,
-                "client requests should not return 5xx errors",
-                {
-                    "status": status.as_u16(),
-                    "code": err.code().as_str_name(),
-                    "message": err.message
-                }
+            Err(decode_err(status, body))
+        }
+    }
+}
+
+fn check_content_type(resp: &http::Response<Body>) -> Result<(), Culprit<ClientErr>> {
+    let content_type = resp.headers().get(CONTENT_TYPE);
+    if content_type != Some(&APPLICATION_PROTOBUF) {
+        return Err(
+            Culprit::new(ClientErr::ProtobufDecodeErr).with_note(format!(
+                "expected content type '{}' but received {:?}",
+                APPLICATION_PROTOBUF.to_str().unwrap(),
+                content_type
+            )),
+        );
+    }
+    Ok(())
+}
+
+/// read the response into a Bytes object
+fn read_body(resp: http::Response<Body>) -> Result<Bytes, Culprit<ClientErr>> {
+    let reader = resp
+        .into_body()
+        .into_with_config()
+        .limit(MAX_READ_SIZE.as_u64());
+    let mut writer = BytesMut::new().writer();
+    std::io::copy(&mut reader.reader(), &mut writer).or_into_ctx()?;
+    Ok(writer.into_inner().freeze())
+}
+
+fn decode_err(status: StatusCode, body: Bytes) -> Culprit<ClientErr> {
+    let body_size = ByteUnit::new(body.len() as u64);
+    let err = match GraftErr::decode(body) {
+        Ok(err) => err,
+        Err(err) => {
+            let note = format!(
+                "failed to decode response body into GraftErr from buffer of size {body_size}"
             );
-            Err(err.into())
+            return Culprit::from_err(err).with_note(note);
+        }
+    };
+
+    // 5xx errors are not expected from client requests unless the graft
+    // error signals that the service is temporarily unavailable
+    precept::expect_always_or_unreachable!(
+        !(500..600).contains(&status.as_u16()) || err.code() == graft_proto::GraftErrCode::ServiceUnavailable,
+        "client requests should not return 5xx errors",
+        {
+            "status": status.as_u16(),
+            "code": err.code().as_str_name(),
+            "message": err.message
+        }
+    );
+    err.into()
+}
+
+/// An iterator over the length delimited messages in a streaming response
+pub(crate) struct ResponseStream<Resp> {
+    reader: BodyReader<'static>,
+    done: bool,
+    _phantom: PhantomData<Resp>,
+}
+
+impl<Resp: Message + Default> ResponseStream<Resp> {
+    fn read_next(&mut self) -> Result<Option<Resp>, Culprit<ClientErr>> {
+        // read the varint length delimiter one byte at a time
+        let mut delimiter = Vec::with_capacity(10);
+        loop {
+            let mut byte = [0u8];
+            if self.reader.read(&mut byte).or_into_ctx()? == 0 {
+                if delimiter.is_empty() {
+                    return Ok(None);
+                }
+                return Err(Culprit::new_with_note(
+                    ClientErr::ProtobufDecodeErr,
+                    "response stream ended with a partial frame",
+                ));
+            }
+            delimiter.push(byte[0]);
+            if byte[0] & 0x80 == 0 {
+                break;
+            }
+        }
+        let len = prost::decode_length_delimiter(delimiter.as_slice())?;
+        if len as u64 > MAX_READ_SIZE.as_u64() {
+            return Err(Culprit::new_with_note(
+                ClientErr::ProtobufDecodeErr,
+                format!(
+                    "response frame of size {} exceeds {MAX_READ_SIZE}",
+                    ByteUnit::new(len as u64)
+                ),
+            ));
+        }
+
+        let mut frame = vec![0; len];
+        self.reader.read_exact(&mut frame).or_into_ctx()?;
+        Ok(Some(Resp::decode(frame.as_slice())?))
+    }
+}
+
+impl<Resp: Message + Default> Iterator for ResponseStream<Resp> {
+    type Item = Result<Resp, Culprit<ClientErr>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
         }
+        let result = self.read_next().transpose();
+        // stop reading after the stream ends or fails
+        self.done = !matches!(result, Some(Ok(_)));
+        result
     }
 }
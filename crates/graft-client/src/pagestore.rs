@@ -1,13 +1,20 @@
-use bytes::Bytes;
+use std::{
+    io::{self, Read},
+    time::Duration,
+};
+
+use bytes::{Buf, Bytes};
 use culprit::Culprit;
 use graft_core::VolumeId;
 use graft_core::lsn::LSN;
 use graft_proto::{
     common::v1::SegmentInfo,
     pagestore::v1::{
-        PageAtIdx, ReadPagesRequest, ReadPagesResponse, WritePagesRequest, WritePagesResponse,
+        PageAtIdx, ReadPagesRequest, ReadPagesResponse, ReplicateSegmentRequest,
+        ReplicateSegmentResponse, WritePagesRequest, WritePagesResponse,
     },
 };
+use prost::Message;
 use url::Url;
 
 use crate::NetClient;
@@ -17,13 +24,32 @@ use crate::{ClientErr, net::EndpointBuilder};
 pub struct PagestoreClient {
     endpoint: EndpointBuilder,
     client: NetClient,
+    prefetch: bool,
 }
 
 impl PagestoreClient {
     pub fn new(root: Url, client: NetClient) -> Self {
-        Self { endpoint: root.into(), client }
+        Self {
+            endpoint: root.into(),
+            client,
+            prefetch: false,
+        }
+    }
+
+    /// Allow the pagestore to return additional pages alongside the pages
+    /// requested by `read_pages`
+    pub fn with_prefetch(self, prefetch: bool) -> Self {
+        Self { prefetch, ..self }
+    }
+
+    /// Returns true if the pagestore may return additional pages from
+    /// `read_pages`
+    pub fn prefetch(&self) -> bool {
+        self.prefetch
     }
 
+    /// Read pages from the pagestore. If prefetching is enabled, the response
+    /// may contain additional pages which were not requested.
     pub fn read_pages(
         &self,
         vid: &VolumeId,
@@ -35,21 +61,159 @@ impl PagestoreClient {
             vid: vid.copy_to_bytes(),
             lsn: lsn.into(),
             graft,
+            prefetch: self.prefetch,
         };
         self.client
             .send::<_, ReadPagesResponse>(uri, req)
             .map(|r| r.pages)
     }
 
+    /// Read an arbitrarily large set of pages from the pagestore. Pages are
+    /// returned in batches of bounded size as the pagestore reads them.
+    pub fn read_pages_stream(
+        &self,
+        vid: &VolumeId,
+        lsn: LSN,
+        graft: Bytes,
+    ) -> Result<
+        impl Iterator<Item = Result<Vec<PageAtIdx>, Culprit<ClientErr>>> + use<>,
+        Culprit<ClientErr>,
+    > {
+        let uri = self.endpoint.build("/pagestore/v1/read_pages_stream")?;
+        let req = ReadPagesRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: lsn.into(),
+            graft,
+            prefetch: false,
+        };
+        let stream = self
+            .client
+            .send_recv_stream::<_, ReadPagesResponse>(uri, req)?;
+        Ok(stream.map(|r| r.map(|r| r.pages)))
+    }
+
+    /// Write pages to the pagestore. If the snapshot the pages will be
+    /// committed on top of is provided, the pagestore may store pages as
+    /// deltas against the snapshot's checkpoint.
     pub fn write_pages(
         &self,
         vid: &VolumeId,
+        snapshot_lsn: Option<LSN>,
         pages: Vec<PageAtIdx>,
     ) -> Result<Vec<SegmentInfo>, Culprit<ClientErr>> {
         let uri = self.endpoint.build("/pagestore/v1/write_pages")?;
-        let req = WritePagesRequest { vid: vid.copy_to_bytes(), pages };
+        let req = WritePagesRequest {
+            vid: vid.copy_to_bytes(),
+            pages,
+            snapshot_lsn: snapshot_lsn.map(Into::into),
+        };
         self.client
             .send::<_, WritePagesResponse>(uri, req)
             .map(|r| r.segments)
     }
+
+    /// Stream pages to the pagestore. Unlike `write_pages`, pages are pulled
+    /// from the iterator as the request is sent, allowing arbitrarily large
+    /// writes without holding every page in memory. `num_pages` is the number
+    /// of pages the iterator yields, which bounds how long the request may
+    /// take.
+    pub fn write_pages_stream<I>(
+        &self,
+        vid: &VolumeId,
+        snapshot_lsn: Option<LSN>,
+        num_pages: usize,
+        pages: I,
+    ) -> Result<Vec<SegmentInfo>, Culprit<ClientErr>>
+    where
+        I: Iterator<Item = Result<PageAtIdx, Culprit<ClientErr>>>,
+    {
+        let uri = self.endpoint.build("/pagestore/v1/write_pages_stream")?;
+        let mut body = PageFrameReader {
+            vid: vid.copy_to_bytes(),
+            snapshot_lsn: snapshot_lsn.map(Into::into),
+            pages,
+            frame: Bytes::new(),
+            err: None,
+        };
+        let timeout = STREAM_BASE_TIMEOUT + STREAM_PAGE_TIMEOUT * num_pages as u32;
+        let result = self
+            .client
+            .send_stream::<WritePagesResponse>(uri, &mut body, timeout);
+
+        // if reading a page failed, the request was aborted
+        if let Some(err) = body.err {
+            return Err(err);
+        }
+        result.map(|r| r.segments)
+    }
+
+    /// Send a serialized segment to the pagestore, which holds a copy of the
+    /// segment and uploads it to object storage. Returns once the pagestore
+    /// has a copy of the segment.
+    pub fn replicate_segment(&self, segment: Bytes) -> Result<(), Culprit<ClientErr>> {
+        let uri = self.endpoint.build("/pagestore/v1/replicate_segment")?;
+        let req = ReplicateSegmentRequest { segment };
+        self.client
+            .send::<_, ReplicateSegmentResponse>(uri, req)
+            .map(|_| ())
+    }
+}
+
+/// The number of pages sent in each frame of a streaming write
+const PAGES_PER_FRAME: usize = 64;
+
+/// The minimum amount of time a streaming write may take
+const STREAM_BASE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// The additional amount of time a streaming write may take per page
+const STREAM_PAGE_TIMEOUT: Duration = Duration::from_millis(10);
+
+/// Encodes pages into length delimited `WritePagesRequest` frames as they are
+/// read
+struct PageFrameReader<I> {
+    vid: Bytes,
+    snapshot_lsn: Option<u64>,
+    pages: I,
+
+    /// the unread remainder of the current frame
+    frame: Bytes,
+
+    /// the first error returned by the pages iterator
+    err: Option<Culprit<ClientErr>>,
+}
+
+impl<I> Read for PageFrameReader<I>
+where
+    I: Iterator<Item = Result<PageAtIdx, Culprit<ClientErr>>>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.frame.is_empty() {
+            let pages = self
+                .pages
+                .by_ref()
+                .take(PAGES_PER_FRAME)
+                .collect::<Result<Vec<_>, _>>();
+            let pages = match pages {
+                Ok(pages) => pages,
+                Err(err) => {
+                    let ioerr = io::Error::other(err.to_string());
+                    self.err = Some(err);
+                    return Err(ioerr);
+                }
+            };
+            if pages.is_empty() {
+                return Ok(0);
+            }
+            let req = WritePagesRequest {
+                vid: self.vid.clone(),
+                pages,
+                snapshot_lsn: self.snapshot_lsn,
+            };
+            self.frame = req.encode_length_delimited_to_vec().into();
+        }
+
+        let n = buf.len().min(self.frame.len());
+        self.frame.copy_to_slice(&mut buf[..n]);
+        Ok(n)
+    }
 }
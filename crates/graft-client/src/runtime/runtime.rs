@@ -4,14 +4,17 @@ use std::{
     time::{Duration, Instant},
 };
 
-use graft_core::{VolumeId, gid::ClientId};
+use graft_core::{VolumeId, commit_token::CommitToken, gid::ClientId, page::PageSizeErr};
+use graft_proto::{metastore::v1::CommitRequest, pagestore::v1::PageAtIdx};
 
 use crate::{ClientErr, ClientPair};
 
 use super::{
-    storage::{Storage, volume_state::VolumeConfig},
+    storage::{Storage, StorageErr, volume_state::VolumeConfig},
     sync::{ShutdownErr, StartupErr, SyncTaskErr, SyncTaskHandle},
     volume_handle::VolumeHandle,
+    volume_reader::{VolumeRead, VolumeReader},
+    volume_writer::VolumeWriter,
 };
 
 #[derive(Clone)]
@@ -20,6 +23,7 @@ pub struct Runtime {
     clients: Arc<ClientPair>,
     storage: Arc<Storage>,
     sync: SyncTaskHandle,
+    watch: bool,
 }
 
 impl Runtime {
@@ -29,9 +33,18 @@ impl Runtime {
             clients: Arc::new(clients),
             storage: Arc::new(storage),
             sync: SyncTaskHandle::default(),
+            watch: false,
         }
     }
 
+    /// When watch is enabled, the sync task long-polls the metastore for
+    /// remote commits rather than pulling every volume every refresh
+    /// interval. The refresh interval is still used to retry failed pushes
+    /// and pulls.
+    pub fn with_watch(self, watch: bool) -> Self {
+        Self { watch, ..self }
+    }
+
     pub fn cid(&self) -> &ClientId {
         &self.cid
     }
@@ -50,6 +63,7 @@ impl Runtime {
             refresh_interval,
             control_channel_size,
             autosync,
+            self.watch,
             thread_name,
         )
     }
@@ -79,10 +93,25 @@ impl Runtime {
         vid: &VolumeId,
         config: VolumeConfig,
     ) -> Result<VolumeHandle, ClientErr> {
+        // the page size of a volume can't change once it contains data
+        let page_size = config.page_size();
+        let existing = self.storage.get_volume_config(vid).or_into_ctx()?;
+        if existing.page_size() != page_size && self.storage.snapshot(vid).or_into_ctx()?.is_some()
+        {
+            return Err(Culprit::new_with_note(
+                ClientErr::PageSizeErr(PageSizeErr),
+                format!(
+                    "volume {vid:?} has page size {}; can't open it with page size {page_size}",
+                    existing.page_size()
+                ),
+            ));
+        }
+
         self.storage.set_volume_config(vid, config).or_into_ctx()?;
 
         Ok(VolumeHandle::new(
             vid.clone(),
+            page_size,
             self.clients.clone(),
             self.storage.clone(),
             self.sync.rpc(),
@@ -96,6 +125,122 @@ impl Runtime {
         self.storage.update_volume_config(vid, f).or_into_ctx()?;
         Ok(())
     }
+
+    /// Atomically commit a set of writers to different volumes. The writes
+    /// are committed directly to the remote as a single transaction, so
+    /// either every volume changes or none do. Each volume must be in sync
+    /// with the remote, which means any local commits must be pushed first.
+    /// Returns a reader for each writer, in the same order.
+    ///
+    /// If another writer commits locally to one of the volumes while the
+    /// transaction is in flight, the transaction still commits remotely but
+    /// can't be applied to that volume. In that case this returns a
+    /// `CommittedRemotely` error, and the volume must be reset to the remote
+    /// with `VolumeHandle::reset_to_remote`.
+    pub fn commit_atomic(
+        &self,
+        writers: impl IntoIterator<Item = VolumeWriter>,
+    ) -> Result<Vec<VolumeReader>, ClientErr> {
+        let writers: Vec<_> = writers.into_iter().collect();
+        let mut staged = Vec::with_capacity(writers.len());
+        for (idx, writer) in writers.iter().enumerate() {
+            if !writer.is_unchanged() {
+                writer.check_page_size()?;
+                staged.push(idx);
+            }
+        }
+        let mut writers: Vec<_> = writers.into_iter().map(VolumeWriter::into_parts).collect();
+        if staged.is_empty() {
+            return Ok(writers.into_iter().map(|(reader, _, _)| reader).collect());
+        }
+
+        // every commit in the transaction carries the same token
+        let token = CommitToken::random();
+        let _span =
+            tracing::debug_span!("commit_atomic", ?token, num_volumes = staged.len()).entered();
+
+        let mut commits = Vec::with_capacity(staged.len());
+        for &idx in &staged {
+            let (reader, pages, memtable) = &writers[idx];
+            let vid = reader.vid();
+
+            // the transaction is committed against the remote snapshot, so
+            // the writer's snapshot must be the latest local snapshot and
+            // the volume must not have any local commits
+            let state = self.storage.volume_state(vid).or_into_ctx()?;
+            if state.has_pending_commits() || state.is_syncing() {
+                return Err(Culprit::new_with_note(
+                    StorageErr::PendingCommits.into(),
+                    format!("volume {vid:?} must be pushed before committing a transaction"),
+                ));
+            }
+            let latest = state.snapshot();
+            if latest.map(|s| s.local()) != reader.snapshot().map(|s| s.local()) {
+                return Err(Culprit::new_with_note(
+                    StorageErr::ConcurrentWrite.into(),
+                    format!("Illegal concurrent write to Volume {vid:?}"),
+                ));
+            }
+            let remote_lsn = latest.and_then(|s| s.remote());
+
+            // write the pages to the pagestore if there are any pages
+            let segments = if !memtable.is_empty() {
+                let pages = memtable
+                    .iter()
+                    .map(|(pageidx, page)| Ok(PageAtIdx::new(pageidx, page.clone())));
+                self.clients.pagestore().write_pages_stream(
+                    vid,
+                    remote_lsn,
+                    memtable.len(),
+                    pages,
+                )?
+            } else {
+                Vec::new()
+            };
+
+            commits.push(
+                CommitRequest::new(
+                    vid,
+                    &self.cid,
+                    remote_lsn,
+                    *pages,
+                    reader.page_size(),
+                    segments,
+                )
+                .with_commit_token(token),
+            );
+        }
+
+        let remote_snapshots = self.clients.metastore().commit_transaction(commits)?;
+
+        // replicate the transaction to local storage
+        let commits = staged
+            .iter()
+            .zip(remote_snapshots)
+            .map(|(&idx, remote_snapshot)| {
+                let (reader, _, memtable) = &mut writers[idx];
+                (
+                    reader.vid().clone(),
+                    reader.snapshot().cloned(),
+                    remote_snapshot,
+                    std::mem::take(memtable),
+                )
+            })
+            .collect();
+        let snapshots = self.storage.commit_transaction(commits).or_into_ctx()?;
+        for (&idx, snapshot) in staged.iter().zip(snapshots) {
+            let (reader, _, _) = &mut writers[idx];
+            *reader = VolumeReader::new(
+                reader.vid().clone(),
+                reader.page_size(),
+                Some(snapshot),
+                self.clients.clone(),
+                self.storage.clone(),
+            );
+        }
+
+        Ok(writers.into_iter().map(|(reader, _, _)| reader).collect())
+    }
 }
 
 #[cfg(test)]
@@ -75,6 +75,14 @@ pub enum StorageErr {
     #[error("Volume needs recovery")]
     VolumeIsSyncing,
 
+    #[error("Volume has local commits which have not been pushed to the remote")]
+    PendingCommits,
+
+    #[error(
+        "The transaction committed remotely, but a concurrent local commit means the Volume must be reset to the remote"
+    )]
+    CommittedRemotely,
+
     #[error(
         "The local Volume state is ahead of the remote state, refusing to accept remote changes"
     )]
@@ -224,6 +232,17 @@ impl Storage {
         Ok(self.volumes.insert(key, f(config))?)
     }
 
+    /// Retrieve a Volume's config, returning the default config if the Volume
+    /// has not been configured
+    pub fn get_volume_config(&self, vid: &VolumeId) -> Result<VolumeConfig> {
+        let key = VolumeStateKey::new(vid.clone(), VolumeStateTag::Config);
+        if let Some(value) = self.volumes.get(key)? {
+            Ok(VolumeConfig::from_bytes(&value)?)
+        } else {
+            Ok(VolumeConfig::DEFAULT)
+        }
+    }
+
     fn set_volume_status(&self, batch: &mut fjall::Batch, vid: &VolumeId, status: VolumeStatus) {
         let key = VolumeStateKey::new(vid.clone(), VolumeStateTag::Status);
         batch.insert(&self.volumes, key, status)
@@ -238,6 +257,29 @@ impl Storage {
         }
     }
 
+    /// Durably store the serialized commit request for the Volume's current
+    /// push. If the push is interrupted, the same commit is sent again when
+    /// the push resumes.
+    pub fn set_pending_commit(&self, vid: &VolumeId, commit: &[u8]) -> Result<()> {
+        let key = VolumeStateKey::new(vid.clone(), VolumeStateTag::PendingCommit);
+        let mut batch = self.keyspace.batch();
+        batch = batch.durability(Some(fjall::PersistMode::SyncAll));
+        batch.insert(&self.volumes, key, commit);
+        Ok(batch.commit()?)
+    }
+
+    pub fn get_pending_commit(&self, vid: &VolumeId) -> Result<Option<Slice>> {
+        let key = VolumeStateKey::new(vid.clone(), VolumeStateTag::PendingCommit);
+        Ok(self.volumes.get(key)?)
+    }
+
+    /// Discard the Volume's pending commit, causing the next push to build a
+    /// new commit.
+    pub fn clear_pending_commit(&self, vid: &VolumeId) -> Result<()> {
+        let key = VolumeStateKey::new(vid.clone(), VolumeStateTag::PendingCommit);
+        Ok(self.volumes.remove(key)?)
+    }
+
     pub fn volume_state(&self, vid: &VolumeId) -> Result<VolumeState> {
         let mut state = VolumeState::new(vid.clone());
         let mut iter = self.volumes.snapshot().prefix(vid);
@@ -409,6 +451,87 @@ impl Storage {
         Ok(snapshot)
     }
 
+    /// Apply a transaction which has already committed to the remote. Each
+    /// volume's memtable becomes a new local commit mapped to the volume's new
+    /// remote snapshot, so the commit is never pushed. Volumes which already
+    /// pulled the remote commit are left unchanged. Returns the latest snapshot
+    /// of each volume.
+    #[allow(clippy::type_complexity)]
+    pub fn commit_transaction(
+        &self,
+        commits: Vec<(VolumeId, Option<Snapshot>, graft_proto::Snapshot, Memtable)>,
+    ) -> Result<Vec<Snapshot>> {
+        let _span =
+            tracing::debug_span!("commit_transaction", num_volumes = commits.len()).entered();
+
+        let mut batch = self.keyspace.batch();
+        batch = batch.durability(Some(fjall::PersistMode::SyncAll));
+
+        // acquire the commit lock; every volume is updated in the same batch
+        // so readers never observe part of the transaction
+        let _permit = self.commit_lock.lock();
+
+        let mut snapshots = Vec::with_capacity(commits.len());
+        let mut changed = Vec::with_capacity(commits.len());
+        let mut conflicts = vec![];
+        for (vid, snapshot, remote_snapshot, memtable) in commits {
+            let remote_lsn = remote_snapshot.lsn().expect("invalid remote LSN");
+
+            let latest = self.snapshot(&vid)?;
+            if let Some(latest) = latest.as_ref().filter(|l| l.remote() >= Some(remote_lsn)) {
+                tracing::debug!(?vid, %latest, "volume already pulled the transaction");
+                snapshots.push(latest.clone());
+                continue;
+            }
+
+            // the transaction landed remotely, but a concurrent local commit
+            // means the volume will need to be reset to the remote. The other
+            // volumes still receive the transaction.
+            let read_lsn = snapshot.as_ref().map(|s| s.local());
+            if latest.as_ref().map(|l| l.local()) != read_lsn {
+                conflicts.push(vid);
+                continue;
+            }
+
+            // persist the memtable
+            let commit_lsn = read_lsn.map_or(LSN::FIRST, |lsn| lsn.next().expect("lsn overflow"));
+            let mut page_key = PageKey::new(vid.clone(), PageIdx::FIRST, commit_lsn);
+            for (pageidx, page) in memtable {
+                page_key = page_key.with_index(pageidx);
+                batch.insert(&self.pages, page_key.as_bytes(), PageValue::from(page));
+            }
+
+            // persist the new volume snapshot, which is already in sync with
+            // the remote
+            let remote_mapping = RemoteMapping::new(remote_lsn, commit_lsn);
+            let new_snapshot = Snapshot::new(commit_lsn, remote_mapping, remote_snapshot.pages());
+            batch.insert(
+                &self.volumes,
+                VolumeStateKey::new(vid.clone(), VolumeStateTag::Snapshot),
+                new_snapshot.as_bytes(),
+            );
+
+            snapshots.push(new_snapshot);
+            changed.push(vid);
+        }
+
+        batch.commit()?;
+
+        // notify listeners of the new local commits
+        for vid in &changed {
+            self.local_changeset.mark_changed(vid);
+        }
+
+        if !conflicts.is_empty() {
+            return Err(Culprit::new_with_note(
+                StorageErr::CommittedRemotely,
+                format!("Volumes {conflicts:?} must be reset to the remote"),
+            ));
+        }
+
+        Ok(snapshots)
+    }
+
     /// Replicate a remote commit to local storage.
     pub fn receive_remote_commit(
         &self,
@@ -640,6 +763,12 @@ impl Storage {
             .with_pending_sync(Watermark::default());
         batch.insert(&self.volumes, watermarks_key, watermarks);
 
+        // the rejected commit will never be sent again
+        batch.remove(
+            &self.volumes,
+            VolumeStateKey::new(vid.clone(), VolumeStateTag::PendingCommit),
+        );
+
         // update the volume status
         self.set_volume_status(&mut batch, vid, VolumeStatus::RejectedCommit);
 
@@ -700,6 +829,12 @@ impl Storage {
                 .with_pending_sync(Watermark::default()),
         );
 
+        // clear the pending commit
+        batch.remove(
+            &self.volumes,
+            VolumeStateKey::new(vid.clone(), VolumeStateTag::PendingCommit),
+        );
+
         // if the status is interrupted push, clear the status
         if state.status() == VolumeStatus::InterruptedPush {
             batch.remove(
@@ -722,6 +857,29 @@ impl Storage {
         Ok(())
     }
 
+    /// Remove the volume from storage, including its config, snapshot, pages,
+    /// and pending commits. Used once the volume has been deleted remotely.
+    pub fn delete_volume(&self, vid: &VolumeId) -> Result<()> {
+        let _permit = self.commit_lock.lock();
+
+        let mut batch = self.keyspace.batch();
+        batch = batch.durability(Some(fjall::PersistMode::SyncAll));
+        for partition in [&self.volumes, &self.pages, &self.commits] {
+            let mut keys = partition.snapshot().prefix(vid);
+            while let Some((key, _)) = keys.try_next().or_into_ctx()? {
+                batch.remove(partition, key);
+            }
+        }
+        batch.commit()?;
+
+        // notify listeners that the volume has changed
+        self.remote_changeset.mark_changed(vid);
+
+        tracing::debug!(?vid, "deleted volume from storage");
+
+        Ok(())
+    }
+
     /// Reset the volume to the provided remote snapshot.
     /// This will cause all pending commits to be rolled back and the volume
     /// status to be cleared.
@@ -814,6 +972,12 @@ impl Storage {
                 .with_pending_sync(Watermark::default()),
         );
 
+        // clear the pending commit
+        batch.remove(
+            &self.volumes,
+            VolumeStateKey::new(vid.clone(), VolumeStateTag::PendingCommit),
+        );
+
         // remove all pending commits
         let mut commits = self.commits.snapshot().prefix(vid);
         while let Some((key, graft)) = commits.try_next().or_into_ctx()? {
@@ -896,7 +1060,9 @@ impl Debug for Storage {
 
 #[cfg(test)]
 mod tests {
-    use graft_core::{page::Page, pageidx};
+    use std::time::SystemTime;
+
+    use graft_core::{PageSize, gid::ClientId, page::Page, pageidx};
 
     use super::*;
 
@@ -975,4 +1141,55 @@ mod tests {
         // iter is empty
         assert!(iter.next().is_none());
     }
+
+    #[graft_test::test]
+    fn test_commit_transaction_concurrent_write() {
+        let storage = Storage::open_temporary().unwrap();
+        let cid = ClientId::random();
+
+        let mut memtable = Memtable::default();
+        memtable.insert(pageidx!(1), Page::test_filled(0x42));
+
+        let vids = [VolumeId::random(), VolumeId::random()];
+        let snapshots = vids
+            .each_ref()
+            .map(|vid| storage.commit(vid, None, 1, memtable.clone()).unwrap());
+
+        // the first volume receives a local commit while the transaction is
+        // in flight
+        storage
+            .commit(&vids[0], Some(snapshots[0].clone()), 1, memtable.clone())
+            .unwrap();
+
+        let commits = vids
+            .iter()
+            .zip(snapshots)
+            .map(|(vid, snapshot)| {
+                let remote = graft_proto::Snapshot::new(
+                    vid,
+                    &cid,
+                    LSN::FIRST,
+                    LSN::FIRST,
+                    PageCount::new(1),
+                    PageSize::DEFAULT,
+                    SystemTime::now(),
+                );
+                (vid.clone(), Some(snapshot), remote, memtable.clone())
+            })
+            .collect();
+        let err = storage.commit_transaction(commits).unwrap_err();
+        assert!(
+            matches!(err.ctx(), StorageErr::CommittedRemotely),
+            "unexpected error: {err:?}"
+        );
+
+        // the conflicting volume is left for a reset, while the other volume
+        // receives the transaction
+        let snapshot = storage.snapshot(&vids[0]).unwrap().unwrap();
+        assert_eq!(snapshot.local(), LSN::new(2));
+        assert_eq!(snapshot.remote(), None);
+        let snapshot = storage.snapshot(&vids[1]).unwrap().unwrap();
+        assert_eq!(snapshot.local(), LSN::new(2));
+        assert_eq!(snapshot.remote(), Some(LSN::FIRST));
+    }
 }
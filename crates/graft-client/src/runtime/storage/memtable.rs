@@ -21,6 +21,10 @@ impl Memtable {
         self.pages.is_empty()
     }
 
+    pub fn len(&self) -> usize {
+        self.pages.len()
+    }
+
     pub fn insert(&mut self, pageidx: PageIdx, page: Page) {
         self.pages.insert(pageidx, page);
     }
@@ -28,6 +32,10 @@ impl Memtable {
     pub fn get(&self, pageidx: PageIdx) -> Option<&Page> {
         self.pages.get(&pageidx)
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = (PageIdx, &Page)> {
+        self.pages.iter().map(|(&pageidx, page)| (pageidx, page))
+    }
 }
 
 impl IntoIterator for Memtable {
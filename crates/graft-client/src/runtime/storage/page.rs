@@ -2,9 +2,9 @@ use bytes::Bytes;
 use culprit::{Culprit, ResultExt};
 use fjall::Slice;
 use graft_core::{
-    PageIdx, VolumeId,
+    PageIdx, PageSize, VolumeId,
     lsn::LSN,
-    page::{EMPTY_PAGE, Page, PageSizeErr},
+    page::{Page, PageSizeErr},
     zerocopy_ext::TryFromBytesExt,
 };
 use std::fmt::{Debug, Display};
@@ -102,10 +102,10 @@ pub enum PageValue {
 
 impl PageValue {
     /// resolves the `PageValue` to a Page if it's not pending
-    pub fn try_into_page(self) -> Option<Page> {
+    pub fn try_into_page(self, page_size: PageSize) -> Option<Page> {
         match self {
             PageValue::Pending => None,
-            PageValue::Empty => Some(EMPTY_PAGE),
+            PageValue::Empty => Some(Page::empty(page_size)),
             PageValue::Available(page) => Some(page),
         }
     }
@@ -5,7 +5,11 @@
 
 use culprit::{Culprit, ResultExt};
 use fjall::{KvPair, Slice};
-use graft_core::{PageCount, VolumeId, lsn::LSN, zerocopy_ext::TryFromBytesExt};
+use graft_core::{
+    PageCount, PageSize, VolumeId,
+    lsn::LSN,
+    zerocopy_ext::{TryFromBytesExt, ZerocopyErr},
+};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Debug, Display},
@@ -25,6 +29,8 @@ pub enum VolumeStateTag {
     Status = 2,
     Snapshot = 3,
     Watermarks = 4,
+    /// The serialized commit request of an in-progress push
+    PendingCommit = 5,
 }
 
 #[derive(
@@ -114,26 +120,57 @@ impl SyncDirection {
 #[repr(C)]
 pub struct VolumeConfig {
     sync: SyncDirection,
+
+    /// the size of each page in the volume. This must match the page size of
+    /// the remote volume, and can't be changed once the volume has been
+    /// written to.
+    page_size: PageSize,
 }
 
 impl VolumeConfig {
-    pub const DEFAULT: Self = Self { sync: SyncDirection::Disabled };
+    pub const DEFAULT: Self = Self {
+        sync: SyncDirection::Disabled,
+        page_size: PageSize::DEFAULT,
+    };
 
     pub fn new(sync: SyncDirection) -> Self {
-        Self { sync }
+        Self { sync, ..Self::DEFAULT }
     }
 
     pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, Culprit<StorageErr>> {
-        Self::try_read_from_bytes(bytes)
-            .or_ctx(|e| StorageErr::CorruptVolumeState(VolumeStateTag::Config, e.into()))
+        // configs written before volumes had a page size only contain the sync
+        // direction, and always use the default page size
+        if bytes.len() == size_of::<SyncDirection>() {
+            let sync = SyncDirection::try_read_from_bytes(bytes)
+                .or_ctx(|e| StorageErr::CorruptVolumeState(VolumeStateTag::Config, e.into()))?;
+            return Ok(Self::new(sync));
+        }
+
+        let config = Self::try_read_from_bytes(bytes)
+            .or_ctx(|e| StorageErr::CorruptVolumeState(VolumeStateTag::Config, e.into()))?;
+        if !config.page_size.is_valid() {
+            return Err(Culprit::new_with_note(
+                StorageErr::CorruptVolumeState(VolumeStateTag::Config, ZerocopyErr::InvalidData),
+                format!("invalid page size {:?}", config.page_size),
+            ));
+        }
+        Ok(config)
     }
 
     pub fn sync(&self) -> SyncDirection {
         self.sync
     }
 
+    pub fn page_size(&self) -> PageSize {
+        self.page_size
+    }
+
     pub fn with_sync(self, sync: SyncDirection) -> Self {
-        Self { sync }
+        Self { sync, ..self }
+    }
+
+    pub fn with_page_size(self, page_size: PageSize) -> Self {
+        Self { page_size, ..self }
     }
 }
 
@@ -435,6 +472,9 @@ impl VolumeState {
             VolumeStateTag::Watermarks => {
                 self.watermarks = Some(Watermarks::from_bytes(&value)?);
             }
+            VolumeStateTag::PendingCommit => {
+                // the pending commit is only read by the push job
+            }
         }
         Ok(())
     }
@@ -502,3 +542,21 @@ where
 
 // VolumeQueryIter fuses
 impl<I> FusedIterator for VolumeQueryIter<I> where I: Iterator<Item = lsm_tree::Result<KvPair>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[graft_test::test]
+    fn test_legacy_volume_config() {
+        let config = VolumeConfig::from_bytes(SyncDirection::Push.as_bytes()).unwrap();
+        assert_eq!(config.sync(), SyncDirection::Push);
+        assert_eq!(config.page_size(), PageSize::DEFAULT);
+
+        let current = VolumeConfig::new(SyncDirection::Pull).with_page_size(PageSize::MAX);
+        assert_eq!(
+            VolumeConfig::from_bytes(current.as_bytes()).unwrap(),
+            current
+        );
+    }
+}
@@ -7,13 +7,14 @@ use std::{
 };
 
 use control::{SyncControl, SyncRpc};
-use crossbeam::channel::{Receiver, Sender, TrySendError, bounded, select_biased};
+use crossbeam::channel::{Receiver, Sender, TrySendError, bounded, never, select_biased};
 use culprit::{Culprit, Result, ResultExt};
 use graft_core::{ClientId, VolumeId};
 use job::Job;
 use parking_lot::RwLock;
 use thiserror::Error;
 use tryiter::{TryIterator, TryIteratorExt};
+use watcher::RemoteWatcher;
 
 use crate::{ClientErr, ClientPair};
 
@@ -27,6 +28,7 @@ const MAX_RECENT_ERRORS: usize = 16;
 
 pub mod control;
 mod job;
+mod watcher;
 
 #[derive(Debug, Error)]
 pub enum StartupErr {
@@ -75,6 +77,7 @@ impl SyncTaskHandle {
         refresh_interval: Duration,
         control_channel_size: usize,
         autosync: bool,
+        watch: bool,
         thread_name: &str,
     ) -> Result<(), StartupErr> {
         let mut inner = self.inner.write();
@@ -85,6 +88,25 @@ impl SyncTaskHandle {
         let (control_tx, control_rx) = bounded(control_channel_size);
         let commits = storage.local_changeset().subscribe_all();
 
+        let (remote_changes, watcher_shutdown) = if watch {
+            let (changes_tx, changes_rx) = bounded(1);
+            let (shutdown_tx, shutdown_rx) = bounded(0);
+            let watcher = RemoteWatcher::new(
+                storage.clone(),
+                clients.clone(),
+                refresh_interval,
+                changes_tx,
+                shutdown_rx,
+            );
+            thread::Builder::new()
+                .name(format!("{thread_name}-watch"))
+                .spawn(move || watcher.run())
+                .expect("failed to spawn remote watcher");
+            (changes_rx, Some(shutdown_tx))
+        } else {
+            (never(), None)
+        };
+
         let task = SyncTask {
             cid,
             storage,
@@ -93,6 +115,10 @@ impl SyncTaskHandle {
             commits,
             control: control_rx,
             autosync,
+            watch,
+            remote_changes,
+            changed_remotes: Default::default(),
+            _watcher_shutdown: watcher_shutdown,
             recent_errors: Default::default(),
         };
 
@@ -188,6 +214,18 @@ pub struct SyncTask {
     /// to the server when they change or every `refresh_interval`.
     autosync: bool,
 
+    /// when watch is true, remote changes are discovered by a `RemoteWatcher`
+    /// rather than by pulling every volume every `refresh_interval`.
+    watch: bool,
+    remote_changes: Receiver<HashSet<VolumeId>>,
+
+    /// volumes which the `RemoteWatcher` reported as changed and which have
+    /// not been pulled yet
+    changed_remotes: HashSet<VolumeId>,
+
+    /// dropped to shut down the `RemoteWatcher`
+    _watcher_shutdown: Option<Sender<()>>,
+
     recent_errors: Vec<(Instant, Culprit<SyncTaskErr>)>,
 }
 
@@ -204,6 +242,12 @@ impl SyncTask {
                         SyncTaskErr::Client(err) if err.is_network_err() || err.is_auth_err() => {
                             tracing::debug!("sync task: network error: {:?}", err)
                         }
+                        SyncTaskErr::Client(err) if err.is_limit_exceeded() => {
+                            tracing::debug!("sync task: limit exceeded: {:?}", err)
+                        }
+                        SyncTaskErr::Client(err) if err.is_volume_deleted() => {
+                            tracing::debug!("sync task: volume deleted: {:?}", err)
+                        }
                         _ => tracing::error!("sync task error: {:?}", err),
                     }
 
@@ -242,6 +286,19 @@ impl SyncTask {
                     }
                 }
 
+                recv(self.remote_changes) -> changed => {
+                    match changed {
+                        Ok(vids) => {
+                            self.changed_remotes.extend(vids);
+                            self.handle_remote_changes()?;
+                        }
+                        Err(_) => {
+                            tracing::warn!("remote watcher stopped unexpectedly");
+                            self.remote_changes = never();
+                        }
+                    }
+                }
+
                 default(self.refresh_interval) => self.handle_tick()?,
             }
         }
@@ -319,10 +376,39 @@ impl SyncTask {
             return Ok(());
         }
 
-        let mut jobs = self.jobs(SyncDirection::Both, None);
+        // when watching, only pull volumes which the watcher reported
+        let sync = if self.watch {
+            SyncDirection::Push
+        } else {
+            SyncDirection::Both
+        };
+        let mut jobs = self.jobs(sync, None);
         while let Some(job) = jobs.try_next()? {
             job.run(&self.storage, &self.clients).or_into_ctx()?;
         }
+        drop(jobs);
+
+        // retry pulling volumes which failed to pull
+        self.handle_remote_changes()
+    }
+
+    fn handle_remote_changes(&mut self) -> Result<(), SyncTaskErr> {
+        if !self.autosync || self.changed_remotes.is_empty() {
+            return Ok(());
+        }
+
+        let jobs = self
+            .jobs(SyncDirection::Both, Some(self.changed_remotes.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // forget volumes which can't be synced right now
+        self.changed_remotes = jobs.iter().map(|job| job.vid().clone()).collect();
+
+        for job in jobs {
+            let vid = job.vid().clone();
+            job.run(&self.storage, &self.clients).or_into_ctx()?;
+            self.changed_remotes.remove(&vid);
+        }
         Ok(())
     }
 
@@ -1,6 +1,8 @@
-use culprit::{Result, ResultExt};
-use graft_core::{PageIdx, VolumeId, gid::ClientId, lsn::LSN, page::Page};
-use graft_proto::pagestore::v1::PageAtIdx;
+use culprit::{Culprit, Result, ResultExt};
+use graft_core::{PageIdx, VolumeId, gid::ClientId, lsn::LSN, page::PageSizeErr};
+use graft_proto::{metastore::v1::CommitRequest, pagestore::v1::PageAtIdx};
+use prost::Message;
+use splinter_rs::{Splinter, ops::Merge};
 use tryiter::TryIteratorExt;
 
 use crate::{ClientErr, ClientPair, runtime::storage::Storage};
@@ -24,10 +26,34 @@ impl Job {
         Job::Push(PushJob { vid, cid })
     }
 
-    pub fn run(self, storage: &Storage, clients: &ClientPair) -> Result<(), ClientErr> {
+    pub fn vid(&self) -> &VolumeId {
         match self {
-            Job::Pull(job) => job.run(storage, clients),
-            Job::Push(job) => job.run(storage, clients),
+            Job::Pull(job) => &job.vid,
+            Job::Push(job) => &job.vid,
+        }
+    }
+
+    pub fn run(self, storage: &Storage, clients: &ClientPair) -> Result<(), ClientErr> {
+        let job = match self {
+            Job::Pull(job) => job,
+            // a push which fails because the volume was deleted only surfaces
+            // the error; the volume is dropped by the next pull
+            Job::Push(job) => return job.run(storage, clients),
+        };
+        let vid = job.vid.clone();
+
+        // once a pull observes that a volume has been deleted remotely, we
+        // drop it from storage so it's no longer synced
+        match job.run(storage, clients) {
+            Err(err) if err.ctx().is_volume_deleted() => {
+                if let Err(storage_err) = storage.delete_volume(&vid) {
+                    return Err(
+                        err.with_note(format!("storage.delete_volume() error: {storage_err}"))
+                    );
+                }
+                Err(err)
+            }
+            result => result,
         }
     }
 }
@@ -63,6 +89,19 @@ impl PullJob {
         {
             let snapshot_lsn = snapshot.lsn().expect("invalid LSN");
 
+            // the local and remote volume must agree on the page size
+            let page_size = state.config().page_size();
+            let remote_page_size = snapshot.page_size().or_into_ctx()?;
+            if page_size != remote_page_size {
+                return Err(Culprit::new_with_note(
+                    ClientErr::PageSizeErr(PageSizeErr),
+                    format!(
+                        "volume {:?} is configured with page size {page_size} but the remote volume has page size {remote_page_size}",
+                        self.vid
+                    ),
+                ));
+            }
+
             assert!(
                 snapshot_lsn >= start_lsn,
                 "invalid snapshot LSN; expected >= {}; got {}; last snapshot {:?}",
@@ -95,6 +134,10 @@ pub struct PushJob {
 impl PushJob {
     fn run(self, storage: &Storage, clients: &ClientPair) -> Result<(), ClientErr> {
         // prepare the sync
+        let page_size = storage
+            .get_volume_config(&self.vid)
+            .or_into_ctx()?
+            .page_size();
         let (remote_lsn, page_count, lsns, mut commits) =
             storage.prepare_sync_to_remote(&self.vid).or_into_ctx()?;
 
@@ -102,68 +145,72 @@ impl PushJob {
             tracing::debug_span!("PushJob", vid=?self.vid, ?remote_lsn, ?lsns, ?page_count,)
                 .entered();
 
-        // setup temporary storage for pages
-        // TODO: we will eventually stream pages directly to the remote
-        let mut pages = Vec::new();
-        let mut upsert_page = |pageidx: PageIdx, page: Page| {
-            // binary search upsert the page into pages
-            match pages.binary_search_by_key(&pageidx.to_u32(), |p: &PageAtIdx| p.pageidx) {
-                Ok(i) => {
-                    // replace the page in the list with this page
-                    pages[i].data = page.into();
-                }
-                Err(i) => {
-                    // insert the page into the list
-                    pages.insert(i, PageAtIdx::new(pageidx, page));
-                }
+        // if a previous push was interrupted after building its commit, resend
+        // the exact same commit so the metastore can detect if it already landed
+        let req = match storage.get_pending_commit(&self.vid).or_into_ctx()? {
+            Some(req) => {
+                tracing::debug!("resending pending commit");
+                CommitRequest::decode(req.as_ref()).or_into_ctx()?
             }
-        };
-
-        #[allow(unused)]
-        let mut num_commits = 0;
-
-        // load all of the pages into memory
-        // TODO: stream pages directly to the remote
-        while let Some((lsn, graft)) = commits.try_next().or_into_ctx()? {
-            num_commits += 1;
-            let pageidxs = graft.iter().map(PageIdx::try_from).err_into();
-            let mut commit_pages = storage.query_pages(&self.vid, lsn, pageidxs);
-            while let Some((pageidx, page)) = commit_pages.try_next().or_into_ctx()? {
-                // it's a fatal error if the page is None or Pending
-                let page = page
-                    .and_then(|p| p.try_into_page())
-                    .expect("page missing from storage");
-
-                // if the page is still contained within the page_count, include it
-                if page_count.contains(pageidx) {
-                    upsert_page(pageidx, page);
+            None => {
+                // collect the set of pages changed by the commits being pushed
+                let mut changed = Splinter::default();
+                #[allow(unused)]
+                let mut num_commits = 0;
+                while let Some((_, graft)) = commits.try_next().or_into_ctx()? {
+                    num_commits += 1;
+                    changed.merge(&graft);
                 }
-            }
-        }
 
-        precept::expect_always_or_unreachable!(
-            num_commits == graft_core::lsn::LSNRangeExt::try_len(&lsns).expect("lsns is RangeInclusive"),
-            "push job always pushes all expected commits",
-            { "vid": self.vid, "cid": self.cid, "lsns": format!("{lsns:?}") }
-        );
-
-        // write the pages to the pagestore if there are any pages
-        let segments = if !pages.is_empty() {
-            clients
-                .pagestore()
-                .write_pages(&self.vid, pages)
-                .or_into_ctx()?
-        } else {
-            Vec::new()
+                precept::expect_always_or_unreachable!(
+                    num_commits == graft_core::lsn::LSNRangeExt::try_len(&lsns).expect("lsns is RangeInclusive"),
+                    "push job always pushes all expected commits",
+                    { "vid": self.vid, "cid": self.cid, "lsns": format!("{lsns:?}") }
+                );
+
+                // only push pages which are still contained within the page_count
+                let changed = Splinter::from_iter(
+                    changed
+                        .iter()
+                        .take_while(|&pageidx| pageidx <= page_count.to_u32()),
+                );
+
+                // stream the latest version of each changed page to the pagestore
+                let last_lsn = *lsns.end();
+                let pages = changed.iter().map(|pageidx| {
+                    let pageidx = PageIdx::try_from(pageidx).or_into_ctx()?;
+                    let (_, page) = storage.read(&self.vid, last_lsn, pageidx).or_into_ctx()?;
+                    // it's a fatal error if the page is Pending
+                    let page = page
+                        .try_into_page(page_size)
+                        .expect("page missing from storage");
+                    Ok(PageAtIdx::new(pageidx, page))
+                });
+
+                // write the pages to the pagestore if there are any pages
+                let segments = if !changed.is_empty() {
+                    clients
+                        .pagestore()
+                        .write_pages_stream(&self.vid, remote_lsn, changed.cardinality(), pages)
+                        .or_into_ctx()?
+                } else {
+                    Vec::new()
+                };
+
+                let req = CommitRequest::new(
+                    &self.vid, &self.cid, remote_lsn, page_count, page_size, segments,
+                );
+                storage
+                    .set_pending_commit(&self.vid, &req.encode_to_vec())
+                    .or_into_ctx()?;
+                req
+            }
         };
 
         precept::maybe_fault!(0.1, "PushJob: before metastore commit", std::process::exit(0), { "cid": self.cid });
 
         // commit the segments to the metastore
-        let remote_snapshot = match clients
-            .metastore()
-            .commit(&self.vid, &self.cid, remote_lsn, page_count, segments)
-        {
+        let remote_snapshot = match clients.metastore().send_commit(req) {
             Ok(remote_snapshot) => remote_snapshot,
             Err(err) => {
                 tracing::debug!("metastore commit failed: {:?}", err);
@@ -176,6 +223,17 @@ impl PushJob {
                         )));
                     }
                 }
+
+                // the pending commit can't land if its pages were written
+                // against a stale checkpoint, so the next push must write
+                // them again
+                if err.ctx().is_stale_checkpoint() {
+                    if let Err(clear_err) = storage.clear_pending_commit(&self.vid) {
+                        return Err(err.with_note(format!(
+                            "storage.clear_pending_commit() error: {clear_err}"
+                        )));
+                    }
+                }
                 return Err(err);
             }
         };
@@ -0,0 +1,139 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use crossbeam::channel::{Receiver, RecvTimeoutError, Sender, TryRecvError};
+use culprit::{Result, ResultExt};
+use graft_core::{VolumeId, lsn::LSN};
+use tryiter::TryIteratorExt;
+
+use crate::{
+    ClientErr, ClientPair,
+    runtime::storage::{Storage, volume_state::SyncDirection},
+};
+
+/// How long each watch request waits for a remote commit. The watcher
+/// refreshes the set of watched volumes between requests.
+const WATCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The maximum number of volumes the metastore accepts in a single watch
+/// request
+const MAX_WATCHED_VOLUMES: usize = 256;
+
+/// A `RemoteWatcher` long-polls the metastore for new commits to every volume
+/// which pulls from the remote, and sends the changed volumes to the
+/// `SyncTask`.
+pub struct RemoteWatcher {
+    storage: Arc<Storage>,
+    clients: Arc<ClientPair>,
+
+    /// how long to wait after an error or when there are no volumes to watch
+    retry_interval: Duration,
+
+    changes: Sender<HashSet<VolumeId>>,
+
+    /// disconnected when the `SyncTask` shuts down
+    shutdown: Receiver<()>,
+
+    /// the latest remote LSN sent to the `SyncTask` for each volume. Storage
+    /// lags behind this LSN until the `SyncTask` pulls the volume.
+    sent: HashMap<VolumeId, LSN>,
+}
+
+impl RemoteWatcher {
+    pub fn new(
+        storage: Arc<Storage>,
+        clients: Arc<ClientPair>,
+        retry_interval: Duration,
+        changes: Sender<HashSet<VolumeId>>,
+        shutdown: Receiver<()>,
+    ) -> Self {
+        Self {
+            storage,
+            clients,
+            retry_interval,
+            changes,
+            shutdown,
+            sent: HashMap::new(),
+        }
+    }
+
+    pub fn run(mut self) {
+        loop {
+            match self.watch() {
+                Ok(Some(changed)) => {
+                    if !changed.is_empty() && self.changes.send(changed).is_err() {
+                        break;
+                    }
+                    if matches!(self.shutdown.try_recv(), Err(TryRecvError::Disconnected)) {
+                        break;
+                    }
+                }
+                Ok(None) => {
+                    if self.wait_for_retry() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    tracing::debug!("remote watcher error: {:?}", err);
+                    if self.wait_for_retry() {
+                        break;
+                    }
+                }
+            }
+        }
+        tracing::debug!("remote watcher shutting down");
+    }
+
+    /// Waits for the retry interval. Returns true if the `SyncTask` has shut
+    /// down.
+    fn wait_for_retry(&self) -> bool {
+        matches!(
+            self.shutdown.recv_timeout(self.retry_interval),
+            Err(RecvTimeoutError::Disconnected)
+        )
+    }
+
+    /// Wait for a remote commit to any volume which pulls from the remote.
+    /// Returns None if there are no volumes to watch.
+    fn watch(&mut self) -> Result<Option<HashSet<VolumeId>>, ClientErr> {
+        let mut volumes = vec![];
+        let mut states = self.storage.query_volumes(SyncDirection::Pull, None);
+        while let Some(state) = states.try_next().or_into_ctx()? {
+            let remote = state.snapshot().and_then(|s| s.remote());
+            let sent = self.sent.get(state.vid()).copied();
+            volumes.push((state.vid().clone(), remote.max(sent)));
+        }
+        let watched: HashSet<&VolumeId> = volumes.iter().map(|(vid, _)| vid).collect();
+        self.sent.retain(|vid, _| watched.contains(vid));
+        if volumes.is_empty() {
+            return Ok(None);
+        }
+
+        // larger sets of volumes are watched in chunks which share the
+        // timeout. Once a change is found the remaining chunks are only polled.
+        let chunks = volumes.chunks(MAX_WATCHED_VOLUMES);
+        let timeout = WATCH_TIMEOUT / chunks.len() as u32;
+        let mut changed = HashSet::new();
+        for chunk in chunks {
+            let timeout = if changed.is_empty() {
+                timeout
+            } else {
+                Duration::ZERO
+            };
+            let snapshots = self
+                .clients
+                .metastore()
+                .watch(chunk.iter().cloned(), timeout)?;
+            for snapshot in snapshots {
+                let vid = snapshot.vid().expect("invalid VolumeId").clone();
+                let lsn = snapshot.lsn().expect("invalid LSN");
+                self.sent.insert(vid.clone(), lsn);
+                changed.insert(vid);
+            }
+        }
+        Ok(Some(changed))
+    }
+}
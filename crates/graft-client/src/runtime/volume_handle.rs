@@ -1,7 +1,8 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::SystemTime};
 
-use culprit::{Result, ResultExt};
-use graft_core::VolumeId;
+use culprit::{Culprit, Result, ResultExt};
+use graft_core::{PageIdx, PageSize, VolumeId, lsn::LSN, page::Page};
+use splinter_rs::Splinter;
 
 use crate::{ClientErr, ClientPair};
 
@@ -12,13 +13,24 @@ use super::{
         volume_state::{SyncDirection, VolumeStatus},
     },
     sync::control::SyncRpc,
-    volume_reader::VolumeReader,
-    volume_writer::VolumeWriter,
+    volume_reader::{VolumeRead, VolumeReader},
+    volume_writer::{VolumeWrite, VolumeWriter},
 };
 
+/// A point in a Volume's remote history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestoreTarget {
+    /// The Volume as of the specified remote LSN
+    Lsn(LSN),
+
+    /// The Volume as of the latest remote commit at or before the specified time
+    Time(SystemTime),
+}
+
 #[derive(Clone, Debug)]
 pub struct VolumeHandle {
     vid: VolumeId,
+    page_size: PageSize,
     clients: Arc<ClientPair>,
     storage: Arc<Storage>,
     sync_rpc: SyncRpc,
@@ -27,11 +39,18 @@ pub struct VolumeHandle {
 impl VolumeHandle {
     pub(crate) fn new(
         vid: VolumeId,
+        page_size: PageSize,
         clients: Arc<ClientPair>,
         storage: Arc<Storage>,
         sync_rpc: SyncRpc,
     ) -> Self {
-        Self { vid, clients, storage, sync_rpc }
+        Self {
+            vid,
+            page_size,
+            clients,
+            storage,
+            sync_rpc,
+        }
     }
 
     #[inline]
@@ -39,6 +58,12 @@ impl VolumeHandle {
         &self.vid
     }
 
+    /// The size of each page in the Volume
+    #[inline]
+    pub fn page_size(&self) -> PageSize {
+        self.page_size
+    }
+
     /// Retrieve the current volume status
     pub fn status(&self) -> Result<VolumeStatus, ClientErr> {
         self.storage.get_volume_status(&self.vid).or_into_ctx()
@@ -53,6 +78,7 @@ impl VolumeHandle {
     pub fn reader(&self) -> Result<VolumeReader, ClientErr> {
         Ok(VolumeReader::new(
             self.vid.clone(),
+            self.page_size,
             self.snapshot()?,
             self.clients.clone(),
             self.storage.clone(),
@@ -63,6 +89,7 @@ impl VolumeHandle {
     pub fn reader_at(&self, snapshot: Option<Snapshot>) -> VolumeReader {
         VolumeReader::new(
             self.vid.clone(),
+            self.page_size,
             snapshot,
             self.clients.clone(),
             self.storage.clone(),
@@ -104,4 +131,100 @@ impl VolumeHandle {
             .reset_to_remote(self.vid.clone())
             .or_into_ctx()
     }
+
+    /// Restore this volume to a point in its remote history. This will cause
+    /// all pending commits to be rolled back, after which a new commit is
+    /// created and pushed to the remote whose contents equal the volume as of
+    /// the target. Returns the resulting snapshot.
+    ///
+    /// The restore fails if another client commits to the volume concurrently,
+    /// or if the target precedes the volume's retained history.
+    pub fn restore(&self, target: RestoreTarget) -> Result<Option<Snapshot>, ClientErr> {
+        let _span =
+            tracing::debug_span!("VolumeHandle::restore", vid = ?self.vid, ?target).entered();
+
+        // start from the latest remote snapshot
+        self.reset_to_remote()?;
+        let mut writer = self.writer()?;
+
+        let metastore = self.clients.metastore();
+        let snapshot = match target {
+            RestoreTarget::Lsn(lsn) => metastore.snapshot(&self.vid, Some(lsn))?,
+            RestoreTarget::Time(time) => metastore.snapshot_at_time(&self.vid, time)?,
+        };
+        let remote_lsn = writer.snapshot().and_then(|s| s.remote());
+        let (Some(snapshot), Some(remote_lsn)) = (snapshot, remote_lsn) else {
+            return Err(Culprit::new_with_note(
+                ClientErr::RestoreTargetMissing,
+                format!("volume {:?} has no snapshot matching {target:?}", self.vid),
+            ));
+        };
+        let target_lsn = snapshot.lsn().expect("invalid LSN");
+        let target_pages = snapshot.pages();
+
+        // nothing has changed since the target
+        if target_lsn >= remote_lsn {
+            return Ok(writer.snapshot().cloned());
+        }
+
+        // collect every page which has changed since the target. pages which
+        // were truncated away and later re-extended have changed too, even if
+        // they were never written to. rewrites never change the contents of
+        // the volume, so their pages are skipped.
+        let mut changed = Splinter::default();
+        let mut min_pages = target_pages;
+        let commits = metastore.pull_commits(
+            &self.vid,
+            target_lsn.next().expect("lsn overflow")..=remote_lsn,
+        )?;
+        for commit in commits {
+            if commit.snapshot().is_rewrite() {
+                continue;
+            }
+            min_pages = min_pages.min(commit.snapshot().pages());
+            for segment in commit.segments {
+                for pageidx in segment.graft().or_into_ctx()?.iter() {
+                    changed.insert(pageidx);
+                }
+            }
+        }
+        for pageidx in (min_pages.to_u32() + 1)..=target_pages.to_u32() {
+            changed.insert(pageidx);
+        }
+        let changed = changed
+            .iter()
+            .take_while(|&pageidx| pageidx <= target_pages.to_u32())
+            .collect::<Splinter>();
+
+        // rewrite every changed page with its contents as of the target
+        writer.truncate(target_pages);
+        let mut received = Splinter::default();
+        let batches = self.clients.pagestore().read_pages_stream(
+            &self.vid,
+            target_lsn,
+            changed.serialize_to_bytes(),
+        )?;
+        for batch in batches {
+            for page in batch? {
+                received.insert(page.pageidx);
+                writer.write(page.pageidx().or_into_ctx()?, page.page().or_into_ctx()?);
+            }
+        }
+
+        // pages which the pagestore doesn't return are empty
+        for pageidx in changed
+            .iter()
+            .filter(|&pageidx| !received.contains(pageidx))
+        {
+            writer.write(
+                PageIdx::try_from(pageidx).or_into_ctx()?,
+                Page::empty(self.page_size),
+            );
+        }
+        writer.commit()?;
+
+        // push the restore commit to the remote
+        self.sync_with_remote(SyncDirection::Push)?;
+        self.snapshot()
+    }
 }
@@ -1,11 +1,11 @@
 use std::{collections::HashMap, iter::once, sync::Arc};
 
-use culprit::{Result, ResultExt};
+use culprit::{Culprit, Result, ResultExt};
 
 use graft_core::{
-    PageIdx, VolumeId,
+    PageIdx, PageSize, VolumeId,
     lsn::LSN,
-    page::{EMPTY_PAGE, Page},
+    page::{Page, PageSizeErr},
 };
 use splinter_rs::Splinter;
 
@@ -29,6 +29,7 @@ pub trait VolumeRead {
 #[derive(Debug, Clone)]
 pub struct VolumeReader {
     vid: VolumeId,
+    page_size: PageSize,
     snapshot: Option<Snapshot>,
     clients: Arc<ClientPair>,
     storage: Arc<Storage>,
@@ -37,11 +38,24 @@ pub struct VolumeReader {
 impl VolumeReader {
     pub(crate) fn new(
         vid: VolumeId,
+        page_size: PageSize,
         snapshot: Option<Snapshot>,
         clients: Arc<ClientPair>,
         storage: Arc<Storage>,
     ) -> Self {
-        Self { vid, snapshot, clients, storage }
+        Self {
+            vid,
+            page_size,
+            snapshot,
+            clients,
+            storage,
+        }
+    }
+
+    /// The size of each page in the Volume
+    #[inline]
+    pub fn page_size(&self) -> PageSize {
+        self.page_size
     }
 
     /// Upgrade this reader into a writer
@@ -50,8 +64,23 @@ impl VolumeReader {
     }
 
     /// decompose this reader into snapshot and storage
-    pub(crate) fn into_parts(self) -> (VolumeId, Option<Snapshot>, Arc<ClientPair>, Arc<Storage>) {
-        (self.vid, self.snapshot, self.clients, self.storage)
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn into_parts(
+        self,
+    ) -> (
+        VolumeId,
+        PageSize,
+        Option<Snapshot>,
+        Arc<ClientPair>,
+        Arc<Storage>,
+    ) {
+        (
+            self.vid,
+            self.page_size,
+            self.snapshot,
+            self.clients,
+            self.storage,
+        )
     }
 
     /// Read a page from the local page cache
@@ -91,90 +120,107 @@ impl VolumeRead for VolumeReader {
                 }
                 (_, PageValue::Empty) => {
                     oracle.observe_cache_hit(pageidx);
-                    Ok(EMPTY_PAGE)
+                    Ok(Page::empty(self.page_size))
                 }
                 (_, PageValue::Pending) => {
                     if let Some((remote_lsn, local_lsn)) = snapshot.remote_mapping().splat() {
-                        fetch_page(
-                            &self.clients,
-                            &self.storage,
-                            oracle,
-                            self.vid(),
-                            remote_lsn,
-                            local_lsn,
-                            pageidx,
-                        )
-                        .or_into_ctx()
+                        self.fetch_page(oracle, remote_lsn, local_lsn, pageidx)
+                            .or_into_ctx()
                     } else {
-                        Ok(EMPTY_PAGE)
+                        Ok(Page::empty(self.page_size))
                     }
                 }
             }
         } else {
-            Ok(EMPTY_PAGE)
+            Ok(Page::empty(self.page_size))
         }
     }
 }
 
-fn fetch_page<O: Oracle>(
-    clients: &ClientPair,
-    storage: &Storage,
-    oracle: &mut O,
-    vid: &VolumeId,
-    remote_lsn: LSN,
-    local_lsn: LSN,
-    pageidx: PageIdx,
-) -> Result<Page, ClientErr> {
-    let _span = tracing::trace_span!(
-        "fetching page from pagestore",
-        ?vid,
-        %remote_lsn,
-        %local_lsn,
-        %pageidx,
-    )
-    .entered();
-
-    // predict future page fetches using the oracle, then eliminate pages we
-    // have already fetched while building our update hashmap.
-    let mut graft = Splinter::default();
-    let mut pages = HashMap::new();
-    for idx in once(pageidx).chain(oracle.predict_next(pageidx)) {
-        let (lsn, page) = storage.read(vid, local_lsn, idx).or_into_ctx()?;
-        if matches!(page, PageValue::Pending) {
-            graft.insert(idx.to_u32());
-            pages.insert(idx, (lsn, PageValue::Empty));
+impl VolumeReader {
+    fn fetch_page<O: Oracle>(
+        &self,
+        oracle: &mut O,
+        remote_lsn: LSN,
+        local_lsn: LSN,
+        pageidx: PageIdx,
+    ) -> Result<Page, ClientErr> {
+        let vid = self.vid();
+        let page_size = self.page_size;
+        let _span = tracing::trace_span!(
+            "fetching page from pagestore",
+            ?vid,
+            %remote_lsn,
+            %local_lsn,
+            %pageidx,
+        )
+        .entered();
+
+        // predict future page fetches using the oracle, then eliminate pages we
+        // have already fetched while building our update hashmap.
+        let mut graft = Splinter::default();
+        let mut pages = HashMap::new();
+        for idx in once(pageidx).chain(oracle.predict_next(pageidx)) {
+            let (lsn, page) = self.storage.read(vid, local_lsn, idx).or_into_ctx()?;
+            if matches!(page, PageValue::Pending) {
+                graft.insert(idx.to_u32());
+                pages.insert(idx, (lsn, PageValue::Empty));
+            }
         }
-    }
 
-    // process client results and update the hashmap
-    let response = clients
-        .pagestore()
-        .read_pages(vid, remote_lsn, graft.serialize_to_bytes())?;
-    for page in response {
-        if let Some(entry) = pages.get_mut(&page.pageidx().or_into_ctx()?) {
-            entry.1 = page.page().or_into_ctx()?.into();
-        } else {
-            tracing::warn!(?vid, %remote_lsn, pageidx=page.pageidx, "unexpected page");
-            precept::expect_unreachable!(
-                "received unexpected page from pagestore",
-                {
-                    "vid": vid,
-                    "remote_lsn": remote_lsn,
-                    "pageidx": page.pageidx,
+        // process client results and update the hashmap
+        let pagestore = self.clients.pagestore();
+        let response = pagestore.read_pages(vid, remote_lsn, graft.serialize_to_bytes())?;
+        for page in response {
+            let idx = page.pageidx().or_into_ctx()?;
+            if let Some(entry) = pages.get_mut(&idx) {
+                entry.1 = self.check_page_size(page.page().or_into_ctx()?)?.into();
+            } else if pagestore.prefetch() {
+                // the pagestore returned a page we didn't request; cache it
+                // if we haven't already
+                let (lsn, value) = self.storage.read(vid, local_lsn, idx).or_into_ctx()?;
+                if matches!(value, PageValue::Pending) {
+                    let page = self.check_page_size(page.page().or_into_ctx()?)?;
+                    pages.insert(idx, (lsn, page.into()));
                 }
-            );
+            } else {
+                tracing::warn!(?vid, %remote_lsn, pageidx=page.pageidx, "unexpected page");
+                precept::expect_unreachable!(
+                    "received unexpected page from pagestore",
+                    {
+                        "vid": vid,
+                        "remote_lsn": remote_lsn,
+                        "pageidx": page.pageidx,
+                    }
+                );
+            }
         }
-    }
 
-    let requested_page = pages
-        .get(&pageidx)
-        .cloned()
-        .and_then(|(_, p)| p.try_into_page())
-        .expect("requested page not found");
+        let requested_page = pages
+            .get(&pageidx)
+            .cloned()
+            .and_then(|(_, p)| p.try_into_page(page_size))
+            .expect("requested page not found");
 
-    // update local storage with fetched pages
-    storage.receive_pages(vid, pages).or_into_ctx()?;
+        // update local storage with fetched pages
+        self.storage.receive_pages(vid, pages).or_into_ctx()?;
 
-    // return the requested page
-    Ok(requested_page)
+        // return the requested page
+        Ok(requested_page)
+    }
+
+    fn check_page_size(&self, page: Page) -> Result<Page, ClientErr> {
+        if page.size() != self.page_size {
+            return Err(Culprit::new_with_note(
+                ClientErr::PageSizeErr(PageSizeErr),
+                format!(
+                    "received page of size {} from volume {:?} with page size {}",
+                    page.size(),
+                    self.vid,
+                    self.page_size
+                ),
+            ));
+        }
+        Ok(page)
+    }
 }
@@ -1,5 +1,9 @@
-use culprit::{Result, ResultExt};
-use graft_core::{PageIdx, VolumeId, page::Page, page_count::PageCount};
+use culprit::{Culprit, Result, ResultExt};
+use graft_core::{
+    PageIdx, VolumeId,
+    page::{Page, PageSizeErr},
+    page_count::PageCount,
+};
 
 use crate::{ClientErr, oracle::Oracle};
 
@@ -33,6 +37,34 @@ impl VolumeWriter {
     pub fn pages(&self) -> PageCount {
         self.pages
     }
+
+    /// we have nothing to commit if the page count is equal to the snapshot
+    /// pagecount *and* the memtable is empty
+    pub(crate) fn is_unchanged(&self) -> bool {
+        let snapshot_pagecount = self.snapshot().map_or(PageCount::ZERO, |s| s.pages());
+        self.pages == snapshot_pagecount && self.memtable.is_empty()
+    }
+
+    /// every page must match the volume's page size
+    pub(crate) fn check_page_size(&self) -> Result<(), ClientErr> {
+        let page_size = self.reader.page_size();
+        if let Some((pageidx, page)) = self.memtable.iter().find(|(_, p)| p.size() != page_size) {
+            return Err(Culprit::new_with_note(
+                ClientErr::PageSizeErr(PageSizeErr),
+                format!(
+                    "page {pageidx} has size {}; volume {:?} has page size {page_size}",
+                    page.size(),
+                    self.vid()
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// decompose this writer into its reader, page count, and memtable
+    pub(crate) fn into_parts(self) -> (VolumeReader, PageCount, Memtable) {
+        (self.reader, self.pages, self.memtable)
+    }
 }
 
 impl From<VolumeReader> for VolumeWriter {
@@ -82,19 +114,21 @@ impl VolumeWrite for VolumeWriter {
     }
 
     fn commit(self) -> Result<VolumeReader, ClientErr> {
-        let (vid, snapshot, clients, storage) = self.reader.into_parts();
-
-        // we have nothing to commit if the page count is equal to the snapshot
-        // pagecount *and* the memtable is empty
-        let snapshot_pagecount = snapshot.as_ref().map_or(PageCount::ZERO, |s| s.pages());
-        let memtable_empty = self.memtable.is_empty();
-        if self.pages == snapshot_pagecount && memtable_empty {
-            return Ok(VolumeReader::new(vid, snapshot, clients, storage));
+        if self.is_unchanged() {
+            return Ok(self.reader);
         }
+        self.check_page_size()?;
 
+        let (vid, page_size, snapshot, clients, storage) = self.reader.into_parts();
         let snapshot = storage
             .commit(&vid, snapshot, self.pages, self.memtable)
             .or_into_ctx()?;
-        Ok(VolumeReader::new(vid, Some(snapshot), clients, storage))
+        Ok(VolumeReader::new(
+            vid,
+            page_size,
+            Some(snapshot),
+            clients,
+            storage,
+        ))
     }
 }
@@ -0,0 +1,114 @@
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+use bytes::Bytes;
+use culprit::Culprit;
+use thiserror::Error;
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+const TOKEN_SIZE: usize = 16;
+
+#[derive(Debug, Error)]
+#[error("invalid commit token")]
+pub struct InvalidCommitToken;
+
+/// A random token generated by a client for each commit it sends to the
+/// Metastore. The client resends the same token when retrying a commit, which
+/// allows the Metastore to recognize a commit which already landed.
+///
+/// The all-zero token is reserved to represent a missing token.
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    KnownLayout,
+    IntoBytes,
+    FromBytes,
+    Immutable,
+    Unaligned,
+)]
+#[repr(transparent)]
+pub struct CommitToken([u8; TOKEN_SIZE]);
+
+impl CommitToken {
+    pub const EMPTY: Self = Self([0; TOKEN_SIZE]);
+
+    pub fn random() -> Self {
+        loop {
+            let token = Self(rand::random());
+            if !token.is_empty() {
+                return token;
+            }
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::EMPTY
+    }
+
+    pub fn copy_to_bytes(&self) -> Bytes {
+        Bytes::copy_from_slice(self.as_bytes())
+    }
+}
+
+impl TryFrom<&[u8]> for CommitToken {
+    type Error = Culprit<InvalidCommitToken>;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let token: [u8; TOKEN_SIZE] = value.try_into().map_err(|_| {
+            Culprit::new_with_note(
+                InvalidCommitToken,
+                format!("expected {TOKEN_SIZE} bytes; got {}", value.len()),
+            )
+        })?;
+        Ok(Self(token))
+    }
+}
+
+impl Display for CommitToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", bs58::encode(self.as_bytes()).into_string())
+    }
+}
+
+impl FromStr for CommitToken {
+    type Err = Culprit<InvalidCommitToken>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = bs58::decode(s)
+            .into_vec()
+            .map_err(|err| Culprit::new_with_note(InvalidCommitToken, err.to_string()))?;
+        bytes.as_slice().try_into()
+    }
+}
+
+impl Debug for CommitToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CommitToken({self})")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[graft_test::test]
+    fn test_commit_token() {
+        let token = CommitToken::random();
+        assert!(!token.is_empty());
+        assert_ne!(token, CommitToken::random());
+
+        let bytes = token.copy_to_bytes();
+        assert_eq!(CommitToken::try_from(bytes.as_ref()).unwrap(), token);
+        assert!(CommitToken::try_from(&bytes[1..]).is_err());
+
+        assert_eq!(token.to_string().parse::<CommitToken>().unwrap(), token);
+        assert!("not a token".parse::<CommitToken>().is_err());
+    }
+}
@@ -1,15 +1,18 @@
 pub mod byte_unit;
+pub mod commit_token;
 pub mod gid;
 pub mod hash_table;
 pub mod lsn;
 pub mod page;
 pub mod page_count;
 pub mod page_idx;
+pub mod page_size;
 pub mod zerocopy_ext;
 
 pub use gid::{ClientId, SegmentId, VolumeId};
 pub use page_count::PageCount;
 pub use page_idx::PageIdx;
+pub use page_size::PageSize;
 
 #[cfg(any(test, feature = "testutil"))]
 pub mod testutil;
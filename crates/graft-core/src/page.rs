@@ -4,21 +4,36 @@ use bytes::{Bytes, BytesMut};
 use culprit::Culprit;
 use thiserror::Error;
 
-use crate::byte_unit::ByteUnit;
+use crate::{byte_unit::ByteUnit, page_size::PageSize};
 
-pub const PAGESIZE: ByteUnit = ByteUnit::from_kb(4);
-static_assertions::const_assert!(PAGESIZE.is_power_of_two());
+/// The default page size. Volumes may be configured to use a different page
+/// size, see `PageSize`.
+pub const PAGESIZE: ByteUnit = PageSize::DEFAULT.as_byte_unit();
 
-static STATIC_EMPTY_PAGE: [u8; PAGESIZE.as_usize()] = [0; PAGESIZE.as_usize()];
-pub const EMPTY_PAGE: Page = Page(Bytes::from_static(&STATIC_EMPTY_PAGE));
+static STATIC_EMPTY_PAGE: [u8; PageSize::MAX.as_usize()] = [0; PageSize::MAX.as_usize()];
+
+/// An empty page of the default page size
+pub const EMPTY_PAGE: Page = Page(Bytes::from_static(
+    STATIC_EMPTY_PAGE.split_at(PAGESIZE.as_usize()).0,
+));
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Page(Bytes);
 
 impl Page {
+    /// Returns an empty page of the specified size
+    pub fn empty(size: PageSize) -> Page {
+        Page(Bytes::from_static(&STATIC_EMPTY_PAGE[..size.as_usize()]))
+    }
+
     /// Returns true if all of the page's bytes are 0.
     pub fn is_empty(&self) -> bool {
-        self.0.as_ref() == STATIC_EMPTY_PAGE
+        self.0.as_ref() == &STATIC_EMPTY_PAGE[..self.0.len()]
+    }
+
+    /// Returns the size of this page
+    pub fn size(&self) -> PageSize {
+        PageSize::new(ByteUnit::new(self.0.len() as u64)).expect("page has invalid size")
     }
 }
 
@@ -37,7 +52,7 @@ impl AsRef<[u8]> for Page {
 }
 
 #[derive(Debug, Error)]
-#[error("Pages must have size {PAGESIZE}")]
+#[error("invalid page size")]
 pub struct PageSizeErr;
 
 impl From<&[u8; PAGESIZE.as_usize()]> for Page {
@@ -70,13 +85,7 @@ impl TryFrom<Bytes> for Page {
     type Error = Culprit<PageSizeErr>;
 
     fn try_from(value: Bytes) -> Result<Self, Self::Error> {
-        if value.len() != PAGESIZE.as_usize() {
-            let size = ByteUnit::new(value.len() as u64);
-            return Err(Culprit::new_with_note(
-                PageSizeErr,
-                format!("invalid page size {size}"),
-            ));
-        }
+        PageSize::new(ByteUnit::new(value.len() as u64))?;
 
         Ok(Page(value))
     }
@@ -86,13 +95,7 @@ impl TryFrom<&[u8]> for Page {
     type Error = Culprit<PageSizeErr>;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        if value.len() != PAGESIZE.as_usize() {
-            let size = ByteUnit::new(value.len() as u64);
-            return Err(Culprit::new_with_note(
-                PageSizeErr,
-                format!("invalid page size {size}"),
-            ));
-        }
+        PageSize::new(ByteUnit::new(value.len() as u64))?;
 
         Ok(Page(Bytes::copy_from_slice(value)))
     }
@@ -100,6 +103,6 @@ impl TryFrom<&[u8]> for Page {
 
 impl Debug for Page {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Page({PAGESIZE})")
+        write!(f, "Page({})", ByteUnit::new(self.0.len() as u64))
     }
 }
@@ -5,8 +5,8 @@ use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
 
 use crate::{
     byte_unit::ByteUnit,
-    page::PAGESIZE,
     page_idx::{PageIdx, PageIdxIter},
+    page_size::PageSize,
 };
 
 #[derive(
@@ -92,9 +92,10 @@ impl PageCount {
         pageidx.to_u32() <= self.0
     }
 
+    /// Returns the size of this many pages of the specified page size
     #[inline]
-    pub fn size(self) -> ByteUnit {
-        PAGESIZE * self.0
+    pub fn size(self, page_size: PageSize) -> ByteUnit {
+        page_size.as_byte_unit() * self.0
     }
 }
 
@@ -0,0 +1,171 @@
+use std::fmt::{Debug, Display};
+
+use culprit::Culprit;
+use serde::{Deserialize, Deserializer, Serialize};
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+use crate::{byte_unit::ByteUnit, page::PageSizeErr};
+
+/// The size of every page in a Volume. The page size is chosen when a Volume
+/// is created and must be a power of two between `PageSize::MIN` and
+/// `PageSize::MAX`.
+///
+/// `PageSize` is stored as the base 2 logarithm of the page size in bytes
+/// which allows it to be embedded in on-disk structures as a single byte.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    KnownLayout,
+    IntoBytes,
+    FromBytes,
+    Immutable,
+    Unaligned,
+)]
+#[repr(transparent)]
+pub struct PageSize(u8);
+
+impl PageSize {
+    /// 512 bytes
+    pub const MIN: Self = Self(9);
+
+    /// 64 KiB
+    pub const MAX: Self = Self(16);
+
+    /// 4 KiB
+    pub const DEFAULT: Self = Self(12);
+
+    /// Create a `PageSize` from a size in bytes. The size must be a power of
+    /// two between `PageSize::MIN` and `PageSize::MAX`.
+    pub fn new(size: ByteUnit) -> Result<Self, Culprit<PageSizeErr>> {
+        let page_size = size
+            .is_power_of_two()
+            .then(|| Self(size.as_u64().trailing_zeros() as u8))
+            .filter(|ps| ps.is_valid());
+        page_size.ok_or_else(|| {
+            Culprit::new_with_note(
+                PageSizeErr,
+                format!(
+                    "invalid page size {size}; page sizes must be a power of two between {} and {}",
+                    Self::MIN,
+                    Self::MAX
+                ),
+            )
+        })
+    }
+
+    /// Returns true if this `PageSize` is within the supported range. This is
+    /// only necessary to check after reading a `PageSize` from bytes.
+    #[inline]
+    pub const fn is_valid(self) -> bool {
+        self.0 >= Self::MIN.0 && self.0 <= Self::MAX.0
+    }
+
+    #[inline]
+    pub const fn as_byte_unit(self) -> ByteUnit {
+        ByteUnit::new(1 << self.0)
+    }
+
+    #[inline]
+    pub const fn as_usize(self) -> usize {
+        1 << self.0
+    }
+
+    #[inline]
+    pub const fn as_u32(self) -> u32 {
+        1 << self.0
+    }
+}
+
+impl Default for PageSize {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Display for PageSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.as_byte_unit(), f)
+    }
+}
+
+impl Debug for PageSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PageSize({self})")
+    }
+}
+
+impl From<PageSize> for ByteUnit {
+    #[inline]
+    fn from(size: PageSize) -> Self {
+        size.as_byte_unit()
+    }
+}
+
+impl TryFrom<u32> for PageSize {
+    type Error = Culprit<PageSizeErr>;
+
+    #[inline]
+    fn try_from(size: u32) -> Result<Self, Self::Error> {
+        Self::new(ByteUnit::new(size as u64))
+    }
+}
+
+impl From<PageSize> for u32 {
+    #[inline]
+    fn from(size: PageSize) -> Self {
+        size.as_u32()
+    }
+}
+
+impl Serialize for PageSize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_byte_unit().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PageSize {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let size = ByteUnit::deserialize(deserializer)?;
+        Self::new(size).map_err(|err| serde::de::Error::custom(err.ctx()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[graft_test::test]
+    fn test_page_size() {
+        assert_eq!(PageSize::MIN.as_usize(), 512);
+        assert_eq!(PageSize::DEFAULT.as_usize(), 4096);
+        assert_eq!(PageSize::MAX.as_usize(), 65536);
+
+        for size in [512u32, 1024, 4096, 16384, 65536] {
+            let page_size = PageSize::try_from(size).unwrap();
+            assert_eq!(page_size.as_u32(), size);
+            assert!(page_size.is_valid());
+        }
+
+        for size in [0u32, 1, 256, 4095, 4097, 131072] {
+            assert!(
+                PageSize::try_from(size).is_err(),
+                "size {size} should be invalid"
+            );
+        }
+
+        assert!(!PageSize::read_from_bytes(&[0]).unwrap().is_valid());
+        assert!(!PageSize::read_from_bytes(&[17]).unwrap().is_valid());
+    }
+}
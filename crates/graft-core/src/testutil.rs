@@ -11,12 +11,17 @@ use rand::{
 use crate::{
     page::{PAGESIZE, Page},
     page_idx::PageIdx,
+    page_size::PageSize,
 };
 
 impl Page {
     pub fn test_filled(value: u8) -> Self {
         Page::from(&[value; PAGESIZE.as_usize()])
     }
+
+    pub fn test_filled_sized(value: u8, size: PageSize) -> Self {
+        vec![value; size.as_usize()].as_slice().try_into().unwrap()
+    }
 }
 
 impl Distribution<Page> for StandardUniform {
@@ -7,7 +7,8 @@ use bytes::Bytes;
 use common::v1::{Commit, GraftErr, LsnRange, SegmentInfo};
 use culprit::{Culprit, ResultExt};
 use graft_core::{
-    PageCount, PageIdx, SegmentId, VolumeId,
+    PageCount, PageIdx, PageSize, SegmentId, VolumeId,
+    commit_token::{CommitToken, InvalidCommitToken},
     gid::{ClientId, GidParseErr},
     lsn::{InvalidLSN, LSN, LSNRangeExt},
     page::{Page, PageSizeErr},
@@ -42,13 +43,41 @@ impl Commit {
 
 impl SegmentInfo {
     pub fn new(sid: &SegmentId, graft: Bytes) -> Self {
-        Self { sid: sid.copy_to_bytes(), graft }
+        Self {
+            sid: sid.copy_to_bytes(),
+            graft,
+            source_vid: Bytes::new(),
+            delta_checkpoint: None,
+            delta_base: false,
+        }
+    }
+
+    /// Set the volume which the graft's pages are stored under in the segment
+    pub fn with_source_vid(mut self, source_vid: &VolumeId) -> Self {
+        self.source_vid = source_vid.copy_to_bytes();
+        self
+    }
+
+    /// Mark the graft as containing the base pages of inherited deltas
+    pub fn with_delta_base(mut self) -> Self {
+        self.delta_base = true;
+        self
     }
 
     pub fn sid(&self) -> Result<&SegmentId, Culprit<GidParseErr>> {
         Ok(self.sid.as_ref().try_into()?)
     }
 
+    /// Returns the volume which the graft's pages are stored under in the
+    /// segment, if it differs from the volume referencing the segment
+    pub fn source_vid(&self) -> Result<Option<&VolumeId>, Culprit<GidParseErr>> {
+        if self.source_vid.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(self.source_vid.as_ref().try_into()?))
+        }
+    }
+
     pub fn graft(&self) -> Result<SplinterRef<Bytes>, Culprit<DecodeErr>> {
         SplinterRef::from_bytes(self.graft.clone())
     }
@@ -61,6 +90,7 @@ impl Snapshot {
         lsn: LSN,
         checkpoint_lsn: LSN,
         page_count: PageCount,
+        page_size: PageSize,
         timestamp: SystemTime,
     ) -> Self {
         Self {
@@ -70,9 +100,22 @@ impl Snapshot {
             checkpoint_lsn: checkpoint_lsn.into(),
             page_count: page_count.into(),
             timestamp: Some(timestamp.into()),
+            page_size: page_size.into(),
+            rewrite: false,
         }
     }
 
+    /// Mark the snapshot as a rewrite, see `is_rewrite`
+    pub fn with_rewrite(self, rewrite: bool) -> Self {
+        Self { rewrite, ..self }
+    }
+
+    /// Returns true if the snapshot was written by compaction or
+    /// checkpointing, which never change the contents of the volume
+    pub fn is_rewrite(&self) -> bool {
+        self.rewrite
+    }
+
     pub fn vid(&self) -> Result<&VolumeId, Culprit<GidParseErr>> {
         Ok(self.vid.as_ref().try_into()?)
     }
@@ -93,11 +136,76 @@ impl Snapshot {
         self.page_count.into()
     }
 
+    /// The size of each page in the Volume. Snapshots which don't specify a
+    /// page size use the default page size.
+    pub fn page_size(&self) -> Result<PageSize, Culprit<PageSizeErr>> {
+        decode_page_size(self.page_size)
+    }
+
     pub fn system_time(&self) -> Result<Option<SystemTime>, TimestampError> {
         self.timestamp.map(|ts| ts.try_into()).transpose()
     }
 }
 
+/// Decode a page size from a protobuf message, where 0 represents the default
+/// page size.
+fn decode_page_size(page_size: u32) -> Result<PageSize, Culprit<PageSizeErr>> {
+    if page_size == 0 {
+        Ok(PageSize::DEFAULT)
+    } else {
+        PageSize::try_from(page_size)
+    }
+}
+
+impl metastore::v1::CommitRequest {
+    /// Create a commit request with a new random commit token
+    pub fn new(
+        vid: &VolumeId,
+        cid: &ClientId,
+        snapshot_lsn: Option<LSN>,
+        page_count: PageCount,
+        page_size: PageSize,
+        segments: Vec<SegmentInfo>,
+    ) -> Self {
+        Self {
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+            snapshot_lsn: snapshot_lsn.map(Into::into),
+            page_count: page_count.into(),
+            segments,
+            page_size: page_size.into(),
+            commit_token: CommitToken::random().copy_to_bytes(),
+        }
+    }
+
+    /// Replace the commit token. Every commit in a transaction must carry
+    /// the transaction's token.
+    pub fn with_commit_token(self, token: CommitToken) -> Self {
+        Self {
+            commit_token: token.copy_to_bytes(),
+            ..self
+        }
+    }
+
+    /// The size of each page in the Volume. Requests which don't specify a
+    /// page size use the default page size.
+    pub fn page_size(&self) -> Result<PageSize, Culprit<PageSizeErr>> {
+        decode_page_size(self.page_size)
+    }
+
+    /// The token which identifies this commit, or None if the client didn't
+    /// send one.
+    pub fn commit_token(&self) -> Result<Option<CommitToken>, Culprit<InvalidCommitToken>> {
+        if self.commit_token.is_empty() {
+            Ok(None)
+        } else {
+            CommitToken::try_from(self.commit_token.as_ref())
+                .map(Some)
+                .or_into_ctx()
+        }
+    }
+}
+
 impl LsnRange {
     pub fn from_range<T: RangeBounds<LSN>>(range: T) -> Self {
         let inclusive_start = range.try_start().unwrap_or(LSN::FIRST).into();
@@ -1,11 +1,15 @@
 use axum::{
-    extract::{Request, State},
+    extract::{FromRequestParts, Request, State},
+    http::request::Parts,
     middleware::Next,
     response::Response,
 };
+use culprit::Culprit;
 use rusty_paseto::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::tenant::Tenant;
+
 use super::error::{ApiErr, ApiErrCtx};
 
 #[derive(Deserialize, Serialize, Clone)]
@@ -26,6 +30,37 @@ where
         .map_err(|err| serde::de::Error::custom(err))
 }
 
+/// The claim which marks a token as belonging to another Graft service, such as
+/// a pagestore calling the metastore or replicating segments to a peer
+pub const SERVICE_CLAIM: &str = "graft_service";
+
+/// The role granted to a request by its API token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Client,
+    Service,
+}
+
+/// Rejects requests which weren't made by another Graft service. Used by
+/// endpoints which trust their input, such as compaction. Every request is
+/// trusted when auth is disabled.
+pub struct RequireService;
+
+impl<S: Send + Sync> FromRequestParts<S> for RequireService {
+    type Rejection = ApiErr;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        match parts.extensions.get::<Role>() {
+            Some(Role::Client) => Err(Culprit::new_with_note(
+                ApiErrCtx::Unauthorized,
+                "this endpoint requires a service token",
+            )
+            .into()),
+            Some(Role::Service) | None => Ok(RequireService),
+        }
+    }
+}
+
 impl std::fmt::Debug for AuthState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AuthConfig")
@@ -36,7 +71,7 @@ impl std::fmt::Debug for AuthState {
 
 pub async fn auth_layer(
     State(state): State<AuthState>,
-    request: Request,
+    mut request: Request,
     next: Next,
 ) -> Result<Response, ApiErr> {
     // extract bearer token from request.headers()
@@ -49,12 +84,26 @@ pub async fn auth_layer(
 
     // validate the token
     let key = state.key.into();
-    PasetoParser::<V4, Local>::default()
+    let claims = PasetoParser::<V4, Local>::default()
         .parse(&token, &key)
         .map_err(|err| {
             tracing::error!("paseto validation failure: {err:?}");
             ApiErr::from(ApiErrCtx::Unauthorized)
         })?;
 
+    let role = match claims.get(SERVICE_CLAIM).and_then(|v| v.as_bool()) {
+        Some(true) => Role::Service,
+        _ => Role::Client,
+    };
+    request.extensions_mut().insert(role);
+
+    // identify the tenant making the request by the token's subject. Tokens
+    // without a subject share the default tenant, so they are still limited.
+    let tenant = claims
+        .get("sub")
+        .and_then(|sub| sub.as_str())
+        .map_or_else(Tenant::default, Tenant::new);
+    request.extensions_mut().insert(tenant);
+
     Ok(next.run(request).await)
 }
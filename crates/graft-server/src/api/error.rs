@@ -14,14 +14,21 @@ use thiserror::Error;
 
 use crate::{
     api::response::ProtoResponse,
-    segment::closed::SegmentValidationErr,
+    segment::{
+        closed::SegmentValidationErr,
+        delta::{self, DeltaErr},
+        loader::SegmentLoaderErr,
+    },
+    tenant::LimitErr,
     volume::{
         catalog::VolumeCatalogErr,
+        commit::CommitValidationErr,
         store::{self, VolumeStoreErr},
         updater::UpdateErr,
     },
 };
 
+#[derive(Debug)]
 pub struct ApiErr(Culprit<ApiErrCtx>);
 
 impl From<Culprit<ApiErrCtx>> for ApiErr {
@@ -63,7 +70,10 @@ pub enum ApiErrCtx {
     IoErr(io::ErrorKind),
 
     #[error("failed to validate segment")]
-    SegmentValidationErr(#[from] SegmentValidationErr),
+    SegmentValidationErr(SegmentValidationErr),
+
+    #[error("checksum mismatch; data may be corrupt")]
+    ChecksumMismatch,
 
     #[error("failed to upload segment")]
     SegmentUploadErr,
@@ -77,6 +87,15 @@ pub enum ApiErrCtx {
     #[error("idempotent commit does not match previous request")]
     InvalidIdempotentCommit,
 
+    #[error("compaction does not match the compacted commit")]
+    InvalidCompaction,
+
+    #[error("checkpoint does not contain every page in the volume")]
+    InvalidCheckpoint,
+
+    #[error("volume changed since the compaction or checkpoint was computed")]
+    RewriteRejected,
+
     #[error("graft client request failed")]
     ClientErr(#[from] graft_client::ClientErr),
 
@@ -100,6 +119,24 @@ pub enum ApiErrCtx {
 
     #[error("unauthorized")]
     Unauthorized,
+
+    #[error("failed to resolve page delta")]
+    DeltaErr(#[from] DeltaErr),
+
+    #[error("page deltas are based on a stale checkpoint")]
+    StaleDeltaCheckpoint,
+
+    #[error("this pagestore does not accept replicated segments")]
+    ReplicationDisabled,
+
+    #[error("tenant limit exceeded: {0}")]
+    LimitExceeded(#[from] LimitErr),
+
+    #[error("volume has been deleted")]
+    VolumeDeleted,
+
+    #[error("service is shutting down")]
+    ShuttingDown,
 }
 
 impl From<io::Error> for ApiErrCtx {
@@ -112,16 +149,28 @@ impl From<VolumeStoreErr> for ApiErrCtx {
     fn from(value: VolumeStoreErr) -> Self {
         match value {
             VolumeStoreErr::CommitAlreadyExists => Self::RejectedCommit,
+            VolumeStoreErr::CommitValidationErr(CommitValidationErr::ChecksumMismatch) => {
+                Self::ChecksumMismatch
+            }
             other => Self::VolumeStoreErr(other),
         }
     }
 }
 
+impl From<SegmentValidationErr> for ApiErrCtx {
+    fn from(value: SegmentValidationErr) -> Self {
+        match value {
+            SegmentValidationErr::ChecksumMismatch => Self::ChecksumMismatch,
+            other => Self::SegmentValidationErr(other),
+        }
+    }
+}
+
 impl From<UpdateErr> for ApiErrCtx {
     fn from(value: UpdateErr) -> Self {
         match value {
             UpdateErr::CatalogErr(err) => Self::CatalogErr(err),
-            UpdateErr::StoreErr(err) => Self::VolumeStoreErr(err),
+            UpdateErr::StoreErr(err) => err.into(),
             UpdateErr::ClientErr(err) => Self::ClientErr(err),
         }
     }
@@ -141,8 +190,14 @@ impl IntoResponse for ApiErr {
             SnapshotMissing => GraftErrCode::SnapshotMissing,
             RejectedCommit => GraftErrCode::CommitRejected,
             Unauthorized => GraftErrCode::Unauthorized,
+            LimitExceeded(_) => GraftErrCode::LimitExceeded,
+            VolumeDeleted => GraftErrCode::VolumeDeleted,
+            StaleDeltaCheckpoint => GraftErrCode::StaleCheckpoint,
+            RewriteRejected => GraftErrCode::RewriteRejected,
 
             InvalidIdempotentCommit
+            | InvalidCompaction
+            | InvalidCheckpoint
             | InvalidRequestBody
             | GidParseErr(_)
             | PageSizeErr(_)
@@ -151,11 +206,14 @@ impl IntoResponse for ApiErr {
             | ConvertToPageIdxErr(_)
             | ZeroPageIdx
             | GraftTooLarge
+            | ReplicationDisabled
             | InvalidLSN => GraftErrCode::Client,
 
             SegmentDownloadErr
             | SegmentUploadErr
+            | ShuttingDown
             | VolumeStoreErr(store::VolumeStoreErr::ObjectStoreErr)
+            | DeltaErr(delta::DeltaErr::LoaderErr(SegmentLoaderErr::DownloadSegment(_)))
             | ClientErr(graft_client::ClientErr::HttpErr(_)) => GraftErrCode::ServiceUnavailable,
 
             ClientErr(graft_client::ClientErr::GraftErr(err)) => err.code(),
@@ -167,7 +225,11 @@ impl IntoResponse for ApiErr {
         match code {
             GraftErrCode::SnapshotMissing
             | GraftErrCode::CommitRejected
-            | GraftErrCode::Unauthorized => {
+            | GraftErrCode::Unauthorized
+            | GraftErrCode::LimitExceeded
+            | GraftErrCode::VolumeDeleted
+            | GraftErrCode::StaleCheckpoint
+            | GraftErrCode::RewriteRejected => {
                 tracing::trace!(culprit = ?self.0, "client error")
             }
             GraftErrCode::Client => {
@@ -218,6 +280,10 @@ fn graft_err_code_to_status(code: GraftErrCode) -> StatusCode {
         GraftErrCode::SnapshotMissing => StatusCode::NOT_FOUND,
         GraftErrCode::CommitRejected => StatusCode::CONFLICT,
         GraftErrCode::Unauthorized => StatusCode::UNAUTHORIZED,
+        GraftErrCode::LimitExceeded => StatusCode::TOO_MANY_REQUESTS,
+        GraftErrCode::VolumeDeleted => StatusCode::GONE,
+        GraftErrCode::StaleCheckpoint => StatusCode::PRECONDITION_FAILED,
+        GraftErrCode::RewriteRejected => StatusCode::CONFLICT,
         GraftErrCode::Server => StatusCode::INTERNAL_SERVER_ERROR,
         GraftErrCode::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
     }
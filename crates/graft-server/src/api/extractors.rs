@@ -1,6 +1,6 @@
 use axum::{
     extract::{FromRequest, Request},
-    http::{HeaderValue, header},
+    http::{HeaderMap, HeaderValue, header},
 };
 use bytes::Bytes;
 use culprit::Culprit;
@@ -11,6 +11,21 @@ pub const CONTENT_TYPE_PROTOBUF: HeaderValue = HeaderValue::from_static("applica
 
 pub struct Protobuf<T>(pub T);
 
+/// Returns an error unless the request has a protobuf content type
+pub fn expect_protobuf(headers: &HeaderMap) -> Result<(), ApiErr> {
+    let content_type = headers.get(header::CONTENT_TYPE);
+    if content_type != Some(&CONTENT_TYPE_PROTOBUF) {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::InvalidRequestBody,
+            format!(
+                "expected content type '{CONTENT_TYPE_PROTOBUF:?}' but received '{content_type:?}'"
+            ),
+        )
+        .into());
+    }
+    Ok(())
+}
+
 impl<S, T> FromRequest<S> for Protobuf<T>
 where
     S: Send + Sync,
@@ -19,17 +34,7 @@ where
     type Rejection = ApiErr;
 
     async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
-        let content_type = req.headers().get(header::CONTENT_TYPE);
-        let is_protobuf = content_type == Some(&CONTENT_TYPE_PROTOBUF);
-        if !is_protobuf {
-            return Err(Culprit::new_with_note(
-                ApiErrCtx::InvalidRequestBody,
-                format!(
-                    "expected content type '{CONTENT_TYPE_PROTOBUF:?}' but received '{content_type:?}'"
-                ),
-            )
-            .into());
-        }
+        expect_protobuf(req.headers())?;
 
         let body = Bytes::from_request(req, state).await.map_err(|err| {
             Culprit::new_with_note(ApiErrCtx::InvalidRequestBody, err.to_string())
@@ -0,0 +1,239 @@
+use std::{sync::Arc, time::SystemTime};
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use graft_core::{VolumeId, lsn::LSN};
+use graft_proto::metastore::v1::{CheckpointRequest, CheckpointResponse};
+use splinter_rs::{
+    Splinter,
+    ops::{Intersection, Merge},
+};
+
+use crate::{
+    api::{
+        auth::RequireService,
+        error::{ApiErr, ApiErrCtx},
+        extractors::Protobuf,
+        response::ProtoResponse,
+    },
+    volume::commit::{CommitBuilder, CommitMeta},
+};
+
+use super::MetastoreApiState;
+
+/// Appends a checkpoint to a Volume. The provided segments must contain every
+/// page visible in the Volume at the provided LSN, which allows readers of the
+/// checkpoint and later snapshots to stop scanning at the checkpoint. Like a
+/// compaction, the checkpoint is appended as a new rewrite commit which doesn't
+/// change the contents of the Volume, so existing commits and their timestamps
+/// are left untouched.
+///
+/// The metastore can't verify the contents of the checkpoint's segments, so
+/// checkpoints are only accepted from other Graft services.
+#[tracing::instrument(name = "metastore/v1/checkpoint", skip(_service, state, req))]
+pub async fn handler(
+    _service: RequireService,
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<CheckpointRequest>,
+) -> Result<ProtoResponse<CheckpointResponse>, ApiErr> {
+    let vid: VolumeId = req.vid.try_into()?;
+    let lsn = LSN::try_from(req.lsn).or_into_ctx()?;
+
+    tracing::info!(?vid, ?lsn, num_segments = req.segments.len());
+
+    // only the latest snapshot can be checkpointed, otherwise the checkpoint
+    // would hide commits which landed after it
+    let latest = state.rewrite_base(&vid, lsn).await?;
+
+    // calculate the set of pages visible in the volume at the checkpoint
+    let page_count = latest.page_count();
+    let lsns = latest.checkpoint()..=lsn;
+    state
+        .updater
+        .update_catalog_from_store_in_range(&state.store, &state.catalog, &vid, &lsns)
+        .await
+        .or_into_ctx()?;
+    let mut visible_pages = Splinter::default();
+    for kv in state.catalog.scan_segments(&vid, &lsns) {
+        let (_, graft) = kv.or_into_ctx()?;
+        for pageidx in graft.iter() {
+            if pageidx <= page_count.to_u32() {
+                visible_pages.insert(pageidx);
+            }
+        }
+    }
+
+    let checkpoint_lsn = lsn.saturating_next();
+    let meta = CommitMeta::rewrite(&latest, checkpoint_lsn, checkpoint_lsn, SystemTime::now());
+    let mut commit = CommitBuilder::new_with_capacity(meta, req.segments.len());
+    let mut checkpoint_pages = Splinter::default();
+    for segment in req.segments {
+        let sid = segment.sid().or_into_ctx()?;
+        let source_vid = segment.source_vid().or_into_ctx()?.unwrap_or(&vid);
+        let graft = segment.graft().or_into_ctx()?;
+        if !checkpoint_pages.intersection(&graft).is_empty() {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::InvalidCheckpoint,
+                format!("checkpoint of volume {vid} at lsn {lsn} contains duplicate page idxs"),
+            )
+            .into());
+        }
+        checkpoint_pages.merge(&graft);
+        commit.write_graft_from(sid.clone(), source_vid.clone(), graft.into_inner());
+    }
+
+    if checkpoint_pages != visible_pages {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::InvalidCheckpoint,
+            format!(
+                "checkpoint of volume {vid} at lsn {lsn} does not contain every page in the volume"
+            ),
+        )
+        .into());
+    }
+
+    let commit = commit.build();
+    state.append_rewrite(commit.clone()).await?;
+
+    tracing::info!("created checkpoint for volume {vid} at lsn {checkpoint_lsn}");
+
+    Ok(ProtoResponse::new(CheckpointResponse {
+        snapshot: Some(commit.into_snapshot()),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::{handler::Handler, http::StatusCode};
+    use axum_test::TestServer;
+    use graft_core::{PageSize, SegmentId, gid::ClientId, page_count::PageCount};
+    use graft_proto::common::v1::SegmentInfo;
+    use object_store::memory::InMemory;
+    use prost::Message;
+
+    use crate::{
+        api::extractors::CONTENT_TYPE_PROTOBUF,
+        volume::{catalog::VolumeCatalog, store::VolumeStore, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_checkpoint_sanity() {
+        let store = Arc::new(InMemory::default());
+        let store = Arc::new(VolumeStore::new(store));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let state = Arc::new(MetastoreApiState::new(
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8),
+        ));
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+
+        // write two commits, the second of which truncates the volume to two pages
+        let commits = [
+            (LSN::new(1), PageCount::new(3), [1u32, 2, 3]),
+            (LSN::new(2), PageCount::new(2), [1u32, 2, 0]),
+        ];
+        let timestamps = [
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(2),
+        ];
+        for ((lsn, page_count, pages), timestamp) in commits.into_iter().zip(timestamps) {
+            let meta = CommitMeta::new(
+                vid.clone(),
+                cid.clone(),
+                lsn,
+                LSN::FIRST,
+                page_count,
+                PageSize::DEFAULT,
+                timestamp,
+            );
+            let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+            let graft = Splinter::from_iter(pages.into_iter().filter(|&p| p != 0));
+            commit.write_graft(SegmentId::random(), graft.serialize_to_bytes());
+            store.commit(commit.build()).await.unwrap();
+        }
+
+        let checkpoint = |lsn: u64, pages: &[u32]| CheckpointRequest {
+            vid: vid.copy_to_bytes(),
+            lsn,
+            segments: vec![SegmentInfo::new(
+                &SegmentId::random(),
+                Splinter::from_iter(pages.iter().copied()).serialize_to_bytes(),
+            )],
+        };
+
+        // only the latest lsn can be checkpointed
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(checkpoint(1, &[1, 2, 3]).encode_to_vec().into())
+            .await;
+
+        // the checkpoint must contain every visible page
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(checkpoint(2, &[1]).encode_to_vec().into())
+            .await;
+
+        // the checkpoint must not contain truncated pages
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(checkpoint(2, &[1, 2, 3]).encode_to_vec().into())
+            .await;
+
+        // a valid checkpoint, whose pages are stored under another volume
+        let source_vid = VolumeId::random();
+        let mut req = checkpoint(2, &[1, 2]);
+        req.segments[0] = req.segments[0].clone().with_source_vid(&source_vid);
+        let resp = server
+            .post("/")
+            .bytes(req.encode_to_vec().into())
+            .await
+            .into_bytes();
+        let snapshot = CheckpointResponse::decode(resp).unwrap().snapshot.unwrap();
+        assert_eq!(snapshot.lsn().unwrap(), LSN::new(3));
+        assert_eq!(snapshot.checkpoint().unwrap(), LSN::new(3));
+        assert_eq!(snapshot.pages(), PageCount::new(2));
+
+        // check the catalog
+        let meta = catalog.latest_snapshot(&vid).unwrap().unwrap();
+        assert_eq!(meta.lsn(), LSN::new(3));
+        assert_eq!(meta.checkpoint(), LSN::new(3));
+        assert!(meta.is_rewrite());
+        let segments = catalog
+            .scan_segments(&vid, &(LSN::new(3)..=LSN::new(3)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].0.source_vid(), &source_vid);
+        assert_eq!(segments[0].1.cardinality(), 2);
+
+        // the checkpointed commit is left untouched
+        let commit = store.get_commit(vid.clone(), LSN::new(2)).await.unwrap();
+        assert_eq!(commit.meta().checkpoint(), LSN::FIRST);
+        assert_eq!(commit.meta().system_time(), timestamps[1]);
+
+        // checkpointing the same lsn again is rejected
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(checkpoint(2, &[1, 2]).encode_to_vec().into())
+            .await
+            .assert_status(StatusCode::CONFLICT);
+    }
+}
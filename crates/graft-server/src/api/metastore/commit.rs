@@ -2,8 +2,13 @@ use std::{sync::Arc, time::SystemTime};
 
 use axum::extract::State;
 use culprit::{Culprit, ResultExt};
-use graft_core::{VolumeId, gid::ClientId, lsn::LSN, page_count::PageCount};
-use graft_proto::metastore::v1::{CommitRequest, CommitResponse};
+use graft_core::{
+    PageSize, VolumeId, gid::ClientId, lsn::LSN, page::PageSizeErr, page_count::PageCount,
+};
+use graft_proto::{
+    common::v1::SegmentInfo,
+    metastore::v1::{CommitRequest, CommitResponse},
+};
 use splinter_rs::{
     Splinter,
     ops::{Cut, Union},
@@ -15,7 +20,8 @@ use crate::{
         extractors::Protobuf,
         response::ProtoResponse,
     },
-    volume::commit::{CommitBuilder, CommitMeta},
+    bytes_vec::BytesVec,
+    volume::commit::{Commit, CommitBuilder, CommitMeta},
 };
 
 use super::MetastoreApiState;
@@ -25,6 +31,10 @@ pub async fn handler(
     State(state): State<Arc<MetastoreApiState>>,
     Protobuf(req): Protobuf<CommitRequest>,
 ) -> Result<ProtoResponse<CommitResponse>, ApiErr> {
+    let page_size = req.page_size().or_into_ctx()?;
+    let token = req
+        .commit_token()
+        .or_ctx(|_| ApiErrCtx::InvalidRequestBody)?;
     let vid = VolumeId::try_from(req.vid).or_into_culprit("failed to parse VolumeId")?;
     let cid = ClientId::try_from(req.cid).or_into_culprit("failed to parse ClientId")?;
     let snapshot_lsn: Option<LSN> = req
@@ -37,98 +47,102 @@ pub async fn handler(
     tracing::info!(
         ?vid,
         ?cid,
+        ?token,
         ?snapshot_lsn,
         ?page_count,
+        ?page_size,
         num_segments = req.segments.len(),
     );
 
-    // calculate the commit_lsn
-    let commit_lsn = snapshot_lsn.map_or(LSN::FIRST, |lsn| lsn.saturating_next());
-
     // load the Volume's latest snapshot
-    let latest_snapshot = state
-        .updater
-        .snapshot(&state.store, &state.catalog, &vid, None)
-        .await
-        .or_into_ctx()?;
+    let latest_snapshot = state.live_snapshot(&vid).await?;
 
     let latest_lsn = latest_snapshot.as_ref().map(|s| s.lsn());
 
-    // if the client's snapshot is out of sync with the latest snapshot we can't
-    // proceed with the commit
-    if latest_lsn != snapshot_lsn {
+    check_page_size(&vid, latest_snapshot.as_ref(), page_size)?;
+
+    // if a commit landed after the client's snapshot we can't proceed with the
+    // commit, unless it's the client's own commit
+    if let Some(landed) = next_commit_since(&state, &vid, snapshot_lsn, latest_lsn).await? {
         // to enable idempotent commits, we check to see if the clients commit
         // already landed.
-        if Some(commit_lsn) <= latest_lsn {
-            // load the commit snapshot
-            let commit_snapshot = if latest_lsn == Some(commit_lsn) {
-                latest_snapshot
-            } else {
-                state
-                    .updater
-                    .snapshot(&state.store, &state.catalog, &vid, Some(commit_lsn))
-                    .await
-                    .or_into_ctx()?
-            };
-
-            // if we have a commit snapshot and the cid matches, return a successful response
-            if let Some(commit_snapshot) = commit_snapshot {
-                if commit_snapshot.cid() == &cid {
-                    if commit_snapshot.page_count() != page_count {
-                        return Err(Culprit::new_with_note(
-                            ApiErrCtx::InvalidIdempotentCommit,
-                            "page count mismatch",
-                        )
-                        .into());
+        let commit_lsn = landed.lsn();
+        if let Some(token) = token {
+            // a commit with a token already landed iff the first commit after
+            // the client's snapshot carries the same token
+            if landed.token() == token && landed.cid() == &cid {
+                precept::expect_reachable!(
+                    "detected retried commit request by its commit token",
+                    {
+                        "vid": vid,
+                        "cid": cid,
+                        "commit_lsn": commit_lsn,
                     }
+                );
+                tracing::debug!(
+                    "detected retried commit {token} for volume {vid}: reusing commit at lsn {commit_lsn:?}"
+                );
+
+                return Ok(ProtoResponse::new(CommitResponse {
+                    snapshot: Some(landed.into_snapshot()),
+                }));
+            }
+        } else if landed.cid() == &cid {
+            // clients which don't send a commit token fall back to
+            // comparing the cid and the committed pages
+            if landed.page_count() != page_count {
+                return Err(Culprit::new_with_note(
+                    ApiErrCtx::InvalidIdempotentCommit,
+                    "page count mismatch",
+                )
+                .into());
+            }
 
-                    // check that the segments being committed contain the same
-                    // set of pages as the segments in the catalog
-                    let mut committed_pages = state
-                        .catalog()
-                        .scan_segments(&vid, &(commit_lsn..=commit_lsn))
-                        .try_fold(Splinter::default(), |acc, kv| {
-                            kv.map(|(_, g)| acc.union(&g))
-                        })
-                        .or_into_ctx()?;
-
-                    for segment in req.segments {
-                        let graft = segment.graft().or_into_ctx()?;
-                        let cut = committed_pages.cut(&graft);
-                        if cut != graft {
-                            return Err(Culprit::new_with_note(
-                                ApiErrCtx::InvalidIdempotentCommit,
-                                "extra page idxs",
-                            )
-                            .into());
-                        }
-                    }
+            // check that the segments being committed contain the same
+            // set of pages as the segments in the catalog
+            let mut committed_pages = state
+                .catalog()
+                .scan_segments(&vid, &(commit_lsn..=commit_lsn))
+                .try_fold(Splinter::default(), |acc, kv| {
+                    kv.map(|(_, g)| acc.union(&g))
+                })
+                .or_into_ctx()?;
+
+            for segment in req.segments {
+                let graft = segment.graft().or_into_ctx()?;
+                let cut = committed_pages.cut(&graft);
+                if cut != graft {
+                    return Err(Culprit::new_with_note(
+                        ApiErrCtx::InvalidIdempotentCommit,
+                        "extra page idxs",
+                    )
+                    .into());
+                }
+            }
 
-                    if !committed_pages.is_empty() {
-                        return Err(Culprit::new_with_note(
-                            ApiErrCtx::InvalidIdempotentCommit,
-                            "missing page idxs",
-                        )
-                        .into());
-                    }
+            if !committed_pages.is_empty() {
+                return Err(Culprit::new_with_note(
+                    ApiErrCtx::InvalidIdempotentCommit,
+                    "missing page idxs",
+                )
+                .into());
+            }
 
-                    precept::expect_reachable!(
-                        "detected idempotent commit request and reused previous response",
-                        {
-                            "vid": vid,
-                            "cid": cid,
-                            "commit_lsn": commit_lsn,
-                        }
-                    );
-                    tracing::debug!(
-                        "detected idempotent commit request for volume {vid}: reusing commit at lsn {commit_lsn:?}"
-                    );
-
-                    return Ok(ProtoResponse::new(CommitResponse {
-                        snapshot: Some(commit_snapshot.into_snapshot()),
-                    }));
+            precept::expect_reachable!(
+                "detected idempotent commit request and reused previous response",
+                {
+                    "vid": vid,
+                    "cid": cid,
+                    "commit_lsn": commit_lsn,
                 }
-            }
+            );
+            tracing::debug!(
+                "detected idempotent commit request for volume {vid}: reusing commit at lsn {commit_lsn:?}"
+            );
+
+            return Ok(ProtoResponse::new(CommitResponse {
+                snapshot: Some(landed.into_snapshot()),
+            }));
         }
         // otherwise reject this commit
         let note = format!(
@@ -138,30 +152,21 @@ pub async fn handler(
         return Err(Culprit::new_with_note(ApiErrCtx::RejectedCommit, note).into());
     }
 
-    // checkpoint doesn't change
-    let checkpoint = latest_snapshot.map_or(LSN::FIRST, |s| s.checkpoint());
-
-    let mut commit = CommitBuilder::new_with_capacity(
-        CommitMeta::new(
-            vid.clone(),
-            cid,
-            commit_lsn,
-            checkpoint,
-            page_count,
-            SystemTime::now(),
-        ),
-        req.segments.len(),
-    );
-    for segment in req.segments {
-        let sid = segment.sid().or_into_ctx()?;
-        let graft = segment.graft().or_into_ctx()?;
-        if graft.contains(0) {
-            return Err(ApiErrCtx::ZeroPageIdx.into());
-        }
-        commit.write_graft(sid.clone(), graft.into_inner());
-    }
-
-    let commit = commit.build();
+    // the commit follows the latest snapshot, which may be a rewrite appended
+    // after the client's snapshot. The checkpoint doesn't change.
+    let commit_lsn = latest_lsn.map_or(LSN::FIRST, |lsn| lsn.saturating_next());
+    let checkpoint = latest_snapshot.map(|s| s.checkpoint());
+    let meta = CommitMeta::new(
+        vid.clone(),
+        cid,
+        commit_lsn,
+        checkpoint.unwrap_or(LSN::FIRST),
+        page_count,
+        page_size,
+        SystemTime::now(),
+    )
+    .with_token(token.unwrap_or_default());
+    let commit = build_commit(meta, checkpoint, req.segments)?;
 
     // commit the new snapshot to the store
     state.store.commit(commit.clone()).await.or_into_ctx()?;
@@ -170,6 +175,7 @@ pub async fn handler(
     let mut batch = state.catalog.batch_insert();
     batch.insert_commit(&commit).or_into_ctx()?;
     batch.commit().or_into_ctx()?;
+    state.notify_commit();
 
     tracing::info!(
         "successful commit to volume {vid}: new snapshot lsn {}",
@@ -182,11 +188,127 @@ pub async fn handler(
     }))
 }
 
+/// Returns the first commit after the client's snapshot which isn't a rewrite.
+/// Rewrites appended by compaction and checkpointing never change the contents
+/// of a volume, so if none is found a commit based on the client's snapshot may
+/// be applied on top of the latest snapshot. Rejects the commit if the client's
+/// snapshot is newer than the latest snapshot, or if the commits since the
+/// client's snapshot are no longer available.
+pub(super) async fn next_commit_since(
+    state: &MetastoreApiState,
+    vid: &VolumeId,
+    snapshot_lsn: Option<LSN>,
+    latest_lsn: Option<LSN>,
+) -> Result<Option<CommitMeta>, ApiErr> {
+    let reject = || {
+        let note = format!(
+            "commit rejected for volume {vid}: client snapshot lsn {snapshot_lsn:?} is out of sync with latest lsn {latest_lsn:?}"
+        );
+        tracing::debug!(%note);
+        Err(Culprit::new_with_note(ApiErrCtx::RejectedCommit, note).into())
+    };
+
+    let start = snapshot_lsn.map_or(LSN::FIRST, |lsn| lsn.saturating_next());
+    let Some(latest_lsn) = latest_lsn.filter(|&latest| start <= latest) else {
+        // the client's snapshot must be the latest snapshot
+        return if snapshot_lsn == latest_lsn {
+            Ok(None)
+        } else {
+            reject()
+        };
+    };
+
+    let lsns = start..=latest_lsn;
+    state
+        .updater
+        .update_catalog_from_store_in_range(&state.store, &state.catalog, vid, &lsns)
+        .await
+        .or_into_ctx()?;
+
+    let mut cursor = start;
+    for result in state.catalog.scan_volume(vid, &lsns) {
+        let (meta, _) = result.or_into_ctx()?;
+        if meta.lsn() != cursor {
+            // commits since the client's snapshot have been garbage collected
+            return reject();
+        }
+        if !meta.is_rewrite() {
+            return Ok(Some(meta));
+        }
+        cursor = cursor.saturating_next();
+    }
+    if cursor <= latest_lsn {
+        return reject();
+    }
+    Ok(None)
+}
+
+/// A volume's page size is chosen by its first commit and can't change
+pub(super) fn check_page_size(
+    vid: &VolumeId,
+    latest: Option<&CommitMeta>,
+    page_size: PageSize,
+) -> Result<(), ApiErr> {
+    if let Some(latest) = latest {
+        if latest.page_size() != page_size {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::PageSizeErr(PageSizeErr),
+                format!(
+                    "commit to volume {vid} has page size {page_size}; expected {}",
+                    latest.page_size()
+                ),
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Builds a commit from the segments sent by a client. `checkpoint` is the
+/// checkpoint of the volume's latest snapshot, if the volume has one.
+pub(super) fn build_commit(
+    meta: CommitMeta,
+    checkpoint: Option<LSN>,
+    segments: Vec<SegmentInfo>,
+) -> Result<Commit<BytesVec>, ApiErr> {
+    let vid = meta.vid().clone();
+
+    // page deltas are resolved against the commit's checkpoint, so they must
+    // have been encoded against it. This fails if the volume was checkpointed
+    // after the pages were written, in which case the client must retry.
+    for segment in &segments {
+        let delta_checkpoint: Option<LSN> = segment
+            .delta_checkpoint
+            .map(LSN::try_from)
+            .transpose()
+            .or_into_ctx()?;
+        if delta_checkpoint.is_some() && delta_checkpoint != checkpoint {
+            let note = format!(
+                "commit to volume {vid} contains deltas against checkpoint {delta_checkpoint:?}; expected {checkpoint:?}"
+            );
+            tracing::debug!(%note);
+            return Err(Culprit::new_with_note(ApiErrCtx::StaleDeltaCheckpoint, note).into());
+        }
+    }
+
+    let mut commit = CommitBuilder::new_with_capacity(meta, segments.len());
+    for segment in segments {
+        let sid = segment.sid().or_into_ctx()?;
+        let graft = segment.graft().or_into_ctx()?;
+        if graft.contains(0) {
+            return Err(ApiErrCtx::ZeroPageIdx.into());
+        }
+        commit.write_graft(sid.clone(), graft.into_inner());
+    }
+    Ok(commit.build())
+}
+
 #[cfg(test)]
 mod tests {
     use axum::handler::Handler;
     use axum_test::TestServer;
-    use graft_core::SegmentId;
+    use bytes::Bytes;
+    use graft_core::{PageSize, SegmentId, commit_token::CommitToken};
     use graft_proto::common::v1::SegmentInfo;
     use object_store::memory::InMemory;
     use prost::Message;
@@ -237,6 +359,8 @@ mod tests {
                 snapshot_lsn,
                 page_count: 1,
                 segments: vec![SegmentInfo::new(&SegmentId::random(), graft.clone())],
+                page_size: 0,
+                commit_token: Bytes::new(),
             };
             commits.push(commit.clone());
 
@@ -319,11 +443,211 @@ mod tests {
             snapshot_lsn: Some(5),
             page_count: 1,
             segments: vec![SegmentInfo::new(&SegmentId::random(), graft.clone())],
+            page_size: 0,
+            commit_token: Bytes::new(),
         };
         server
             .post("/")
             .expect_failure()
             .bytes(commit.encode_to_vec().into())
             .await;
+
+        // ensure that the page size of a volume can't change
+        let commit = CommitRequest {
+            vid: vid.copy_to_bytes(),
+            cid: ClientId::random().copy_to_bytes(),
+            snapshot_lsn: Some(9),
+            page_count: 1,
+            segments: vec![SegmentInfo::new(&SegmentId::random(), graft.clone())],
+            page_size: PageSize::MAX.into(),
+            commit_token: Bytes::new(),
+        };
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(commit.encode_to_vec().into())
+            .await;
+        let snapshot = catalog.latest_snapshot(&vid).unwrap().unwrap();
+        assert_eq!(snapshot.lsn(), LSN::new(9));
+        assert_eq!(snapshot.page_size(), PageSize::DEFAULT);
+
+        // deltas must be based on the volume's checkpoint
+        let mut segment = SegmentInfo::new(&SegmentId::random(), graft.clone());
+        segment.delta_checkpoint = Some(2);
+        let commit = CommitRequest {
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+            snapshot_lsn: Some(9),
+            page_count: 1,
+            segments: vec![segment.clone()],
+            page_size: 0,
+            commit_token: Bytes::new(),
+        };
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(commit.encode_to_vec().into())
+            .await;
+
+        segment.delta_checkpoint = Some(1);
+        let commit = CommitRequest { segments: vec![segment], ..commit };
+        let resp = server.post("/").bytes(commit.encode_to_vec().into()).await;
+        let resp = CommitResponse::decode(resp.into_bytes()).unwrap();
+        assert_eq!(resp.snapshot.unwrap().lsn().unwrap(), 10);
+    }
+
+    #[graft_test::test]
+    async fn test_commit_token() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let state = Arc::new(MetastoreApiState::new(
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8),
+        ));
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+        let graft = Splinter::from_iter([1u32]).serialize_to_bytes();
+
+        let commit = |snapshot_lsn: Option<u64>, token: CommitToken| CommitRequest {
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+            snapshot_lsn,
+            page_count: 1,
+            segments: vec![SegmentInfo::new(&SegmentId::random(), graft.clone())],
+            page_size: 0,
+            commit_token: token.copy_to_bytes(),
+        };
+
+        let first = commit(None, CommitToken::random());
+        let resp = server.post("/").bytes(first.encode_to_vec().into()).await;
+        let snapshot = CommitResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshot
+            .unwrap();
+        assert_eq!(snapshot.lsn().unwrap(), 1);
+
+        let second = commit(Some(1), CommitToken::random());
+        server.post("/").bytes(second.encode_to_vec().into()).await;
+
+        // retrying the first commit returns the original snapshot
+        let resp = server.post("/").bytes(first.encode_to_vec().into()).await;
+        let resp = CommitResponse::decode(resp.into_bytes()).unwrap();
+        assert_eq!(resp.snapshot.unwrap(), snapshot);
+
+        // an identical commit with a different token didn't land, even though
+        // it contains the same pages
+        let other = CommitRequest {
+            commit_token: CommitToken::random().copy_to_bytes(),
+            ..first.clone()
+        };
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(other.encode_to_vec().into())
+            .await;
+
+        // as is the same token sent by another client
+        let other = CommitRequest {
+            cid: ClientId::random().copy_to_bytes(),
+            ..first
+        };
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(other.encode_to_vec().into())
+            .await;
+
+        // malformed tokens are rejected
+        let bad = CommitRequest {
+            commit_token: Bytes::from_static(b"short"),
+            ..commit(Some(2), CommitToken::random())
+        };
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(bad.encode_to_vec().into())
+            .await;
+        assert_eq!(catalog.latest_snapshot(&vid).unwrap().unwrap().lsn(), 2);
+    }
+
+    #[graft_test::test]
+    async fn test_commit_after_rewrite() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let state = Arc::new(MetastoreApiState::new(
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8),
+        ));
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+        let graft = Splinter::from_iter([1u32]).serialize_to_bytes();
+
+        let commit = |snapshot_lsn: Option<u64>, token: CommitToken| CommitRequest {
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+            snapshot_lsn,
+            page_count: 1,
+            segments: vec![SegmentInfo::new(&SegmentId::random(), graft.clone())],
+            page_size: 0,
+            commit_token: token.copy_to_bytes(),
+        };
+        server
+            .post("/")
+            .bytes(commit(None, CommitToken::random()).encode_to_vec().into())
+            .await;
+
+        // append a rewrite, as compaction would
+        let latest = catalog.latest_snapshot(&vid).unwrap().unwrap();
+        let mut rewrite = CommitBuilder::new_with_capacity(
+            CommitMeta::rewrite(&latest, LSN::new(2), LSN::FIRST, SystemTime::now()),
+            1,
+        );
+        rewrite.write_graft(SegmentId::random(), graft.clone());
+        store.commit(rewrite.build()).await.unwrap();
+
+        // a commit based on the snapshot before the rewrite lands after it
+        let second = commit(Some(1), CommitToken::random());
+        let resp = server.post("/").bytes(second.encode_to_vec().into()).await;
+        let snapshot = CommitResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshot
+            .unwrap();
+        assert_eq!(snapshot.lsn().unwrap(), 3);
+        assert_eq!(snapshot.checkpoint().unwrap(), LSN::FIRST);
+
+        // retrying the commit skips over the rewrite to find it
+        let resp = server.post("/").bytes(second.encode_to_vec().into()).await;
+        let resp = CommitResponse::decode(resp.into_bytes()).unwrap();
+        assert_eq!(resp.snapshot.unwrap(), snapshot);
+
+        // commits which aren't rewrites still conflict
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(
+                commit(Some(1), CommitToken::random())
+                    .encode_to_vec()
+                    .into(),
+            )
+            .await;
+        assert_eq!(catalog.latest_snapshot(&vid).unwrap().unwrap().lsn(), 3);
     }
 }
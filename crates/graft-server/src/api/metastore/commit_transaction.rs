@@ -0,0 +1,526 @@
+use std::{collections::HashSet, sync::Arc, time::SystemTime};
+
+use axum::extract::State;
+use bytes::Bytes;
+use culprit::{Culprit, ResultExt};
+use futures::future::join_all;
+use graft_core::{
+    PageSize, VolumeId, commit_token::CommitToken, gid::ClientId, lsn::LSN, page_count::PageCount,
+};
+use graft_proto::{
+    Snapshot,
+    common::v1::SegmentInfo,
+    metastore::v1::{CommitTransactionRequest, CommitTransactionResponse},
+};
+
+use crate::{
+    api::{
+        error::{ApiErr, ApiErrCtx},
+        extractors::Protobuf,
+        response::ProtoResponse,
+    },
+    bytes_vec::BytesVec,
+    volume::{
+        commit::{Commit, CommitMeta},
+        store::VolumeStoreErr,
+        transaction::TransactionOutcome,
+    },
+};
+
+use super::{
+    MetastoreApiState,
+    commit::{build_commit, check_page_size, next_commit_since},
+};
+
+/// The maximum number of volumes a single transaction may commit to
+const MAX_VOLUMES: usize = 64;
+
+#[tracing::instrument(name = "metastore/v1/commit_transaction", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<CommitTransactionRequest>,
+) -> Result<ProtoResponse<CommitTransactionResponse>, ApiErr> {
+    if req.commits.is_empty() || req.commits.len() > MAX_VOLUMES {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::InvalidRequestBody,
+            format!("transactions must contain between 1 and {MAX_VOLUMES} commits"),
+        )
+        .into());
+    }
+
+    // every commit carries the transaction's token
+    let token = req.commits[0]
+        .commit_token()
+        .or_ctx(|_| ApiErrCtx::InvalidRequestBody)?;
+    for commit in &req.commits[1..] {
+        let commit_token = commit
+            .commit_token()
+            .or_ctx(|_| ApiErrCtx::InvalidRequestBody)?;
+        if commit_token != token {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::InvalidRequestBody,
+                "every commit in a transaction must carry the same commit token",
+            )
+            .into());
+        }
+    }
+    let Some(token) = token else {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::InvalidRequestBody,
+            "transactions require a commit token",
+        )
+        .into());
+    };
+
+    let mut vids = HashSet::with_capacity(req.commits.len());
+    let mut targets = Vec::with_capacity(req.commits.len());
+    for commit in &req.commits {
+        let vid =
+            VolumeId::try_from(commit.vid.clone()).or_into_culprit("failed to parse VolumeId")?;
+        let snapshot_lsn: Option<LSN> = commit
+            .snapshot_lsn
+            .map(LSN::try_from)
+            .transpose()
+            .or_into_ctx()?;
+        if !vids.insert(vid.clone()) {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::InvalidRequestBody,
+                format!("transaction commits to volume {vid} more than once"),
+            )
+            .into());
+        }
+        targets.push((vid, snapshot_lsn));
+    }
+
+    tracing::info!(?token, num_volumes = targets.len());
+
+    // if the transaction has already been decided, this is a retry
+    match state
+        .store
+        .transaction_outcome(&token)
+        .await
+        .or_into_ctx()?
+    {
+        Some(TransactionOutcome::Committed) => {
+            tracing::debug!("detected retried transaction {token}");
+            return committed_snapshots(&state, &token, &targets).await;
+        }
+        Some(TransactionOutcome::Aborted) => {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::RejectedCommit,
+                format!("transaction {token} was aborted"),
+            )
+            .into());
+        }
+        None => {}
+    }
+
+    let mut prepared = Vec::with_capacity(targets.len());
+    for (req, (vid, snapshot_lsn)) in req.commits.into_iter().zip(&targets) {
+        let page_size = req.page_size().or_into_ctx()?;
+        let cid = ClientId::try_from(req.cid).or_into_culprit("failed to parse ClientId")?;
+
+        let latest_snapshot = state.live_snapshot(vid).await?;
+        check_page_size(vid, latest_snapshot.as_ref(), page_size)?;
+
+        let latest_lsn = latest_snapshot.as_ref().map(|s| s.lsn());
+        if next_commit_since(&state, vid, *snapshot_lsn, latest_lsn)
+            .await?
+            .is_some()
+        {
+            let note = format!(
+                "transaction {token} rejected for volume {vid}: client snapshot lsn {snapshot_lsn:?} is out of sync with latest lsn {latest_lsn:?}"
+            );
+            tracing::debug!(%note);
+            return Err(Culprit::new_with_note(ApiErrCtx::RejectedCommit, note).into());
+        }
+        let commit_lsn = latest_lsn.map_or(LSN::FIRST, |lsn| lsn.saturating_next());
+
+        // if the transaction aborts, readers fall back to the previous page
+        // count at this lsn
+        let checkpoint = latest_snapshot.as_ref().map(|s| s.checkpoint());
+        let prev_page_count = latest_snapshot.map_or(PageCount::ZERO, |s| s.page_count());
+        prepared.push(PreparedCommit {
+            vid: vid.clone(),
+            cid,
+            lsn: commit_lsn,
+            checkpoint,
+            page_count: req.page_count.into(),
+            page_size,
+            prev_page_count,
+            segments: req.segments,
+        });
+    }
+
+    // other Metastores abort the transaction once its commits are older than
+    // the transaction timeout, so the commits are stamped just before they
+    // are written
+    let now = SystemTime::now();
+    let commits = prepared
+        .into_iter()
+        .map(|commit| commit.build(token, now))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // write every commit to the store, then decide the transaction. If any
+    // commit failed to land the transaction is aborted, which turns the
+    // commits which did land into no-ops.
+    let results = join_all(
+        commits
+            .iter()
+            .map(|commit| write_commit(&state, &token, commit.clone())),
+    )
+    .await;
+    let written = results.iter().all(Result::is_ok);
+    let proposed = if written {
+        TransactionOutcome::Committed
+    } else {
+        TransactionOutcome::Aborted
+    };
+    let outcome = state
+        .store
+        .decide_transaction(&token, proposed)
+        .await
+        .or_into_ctx()?;
+
+    match outcome {
+        TransactionOutcome::Committed if written => {}
+        TransactionOutcome::Committed => {
+            // a concurrent attempt of this transaction landed and committed
+            // the commits we failed to write
+            return committed_snapshots(&state, &token, &targets).await;
+        }
+        TransactionOutcome::Aborted => {
+            let note = format!("transaction {token} was aborted");
+            tracing::debug!(%note);
+            return Err(match results.into_iter().find_map(Result::err) {
+                Some(err) => err.map_ctx(ApiErrCtx::from).into(),
+                None => Culprit::new_with_note(ApiErrCtx::RejectedCommit, note).into(),
+            });
+        }
+    }
+
+    // update the catalog, using the commits written by an earlier attempt of
+    // this transaction in place of our own
+    let mut batch = state.catalog.batch_insert();
+    let mut snapshots = Vec::with_capacity(commits.len());
+    for (commit, result) in commits.into_iter().zip(results) {
+        match result.expect("every commit was written") {
+            Some(existing) => {
+                batch.insert_commit(&existing).or_into_ctx()?;
+                snapshots.push(existing.into_snapshot());
+            }
+            None => {
+                batch.insert_commit(&commit).or_into_ctx()?;
+                snapshots.push(commit.into_snapshot());
+            }
+        }
+    }
+    batch.commit().or_into_ctx()?;
+    state.notify_commit();
+
+    tracing::info!(
+        "successful transaction {token} across {} volumes",
+        snapshots.len()
+    );
+
+    Ok(ProtoResponse::new(CommitTransactionResponse { snapshots }))
+}
+
+/// A validated commit which is waiting to be stamped with the transaction's
+/// commit time
+struct PreparedCommit {
+    vid: VolumeId,
+    cid: ClientId,
+    lsn: LSN,
+    checkpoint: Option<LSN>,
+    page_count: PageCount,
+    page_size: PageSize,
+    prev_page_count: PageCount,
+    segments: Vec<SegmentInfo>,
+}
+
+impl PreparedCommit {
+    fn build(self, token: CommitToken, now: SystemTime) -> Result<Commit<BytesVec>, ApiErr> {
+        let meta = CommitMeta::new(
+            self.vid,
+            self.cid,
+            self.lsn,
+            self.checkpoint.unwrap_or(LSN::FIRST),
+            self.page_count,
+            self.page_size,
+            now,
+        )
+        .with_token(token)
+        .with_transaction(self.prev_page_count);
+        build_commit(meta, self.checkpoint, self.segments)
+    }
+}
+
+/// Write a transaction's commit to the store. If the commit's LSN is already
+/// taken by a commit carrying the same token, an earlier attempt of this
+/// transaction wrote it, in which case the existing commit is returned.
+async fn write_commit(
+    state: &MetastoreApiState,
+    token: &CommitToken,
+    commit: Commit<BytesVec>,
+) -> Result<Option<Commit<Bytes>>, Culprit<VolumeStoreErr>> {
+    let vid = commit.vid().clone();
+    let lsn = commit.meta().lsn();
+    match state.store.commit(commit).await {
+        Ok(()) => Ok(None),
+        Err(err) if matches!(err.ctx(), VolumeStoreErr::CommitAlreadyExists) => {
+            let existing = state.store.get_commit(vid, lsn).await?;
+            if existing.meta().is_transaction() && existing.meta().token() == *token {
+                tracing::debug!("detected commit from an earlier attempt of transaction {token}");
+                Ok(Some(existing))
+            } else {
+                Err(err)
+            }
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Load the snapshot created by a committed transaction for each volume
+async fn committed_snapshots(
+    state: &MetastoreApiState,
+    token: &CommitToken,
+    targets: &[(VolumeId, Option<LSN>)],
+) -> Result<ProtoResponse<CommitTransactionResponse>, ApiErr> {
+    let mut snapshots: Vec<Snapshot> = Vec::with_capacity(targets.len());
+    for (vid, snapshot_lsn) in targets {
+        let latest_lsn = state
+            .updater
+            .snapshot(&state.store, &state.catalog, vid, None)
+            .await
+            .or_into_ctx()?
+            .map(|s| s.lsn());
+        // the transaction's commit is the first commit after the client's
+        // snapshot which isn't a rewrite
+        let snapshot = next_commit_since(state, vid, *snapshot_lsn, latest_lsn).await?;
+        let Some(snapshot) = snapshot.filter(|s| s.token() == *token) else {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::SnapshotMissing,
+                format!(
+                    "transaction {token} has no commit to volume {vid} after lsn {snapshot_lsn:?}"
+                ),
+            )
+            .into());
+        };
+        snapshots.push(snapshot.into_snapshot());
+    }
+    Ok(ProtoResponse::new(CommitTransactionResponse { snapshots }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::http::StatusCode;
+    use axum_test::TestServer;
+    use graft_core::{PageSize, SegmentId};
+    use graft_proto::{
+        common::v1::SegmentInfo,
+        metastore::v1::{CommitRequest, SnapshotRequest, SnapshotResponse},
+    };
+    use object_store::memory::InMemory;
+    use prost::Message;
+    use splinter_rs::Splinter;
+
+    use crate::{
+        api::{
+            extractors::CONTENT_TYPE_PROTOBUF, metastore::metastore_routes, routes::build_router,
+        },
+        metrics::registry::Registry,
+        volume::{
+            catalog::VolumeCatalog, commit::CommitBuilder, store::VolumeStore,
+            updater::VolumeCatalogUpdater,
+        },
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_commit_transaction() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let state = Arc::new(MetastoreApiState::new(
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8).with_transaction_timeout(Duration::from_secs(10)),
+        ));
+        let router = build_router(Registry::default(), None, state, metastore_routes());
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .build(router)
+            .unwrap();
+
+        let vids = [VolumeId::random(), VolumeId::random()];
+        let cid = ClientId::random();
+        let commit = |vid: &VolumeId, snapshot_lsn: Option<u64>, page_count: u32| {
+            let graft = Splinter::from_slice(&[page_count]).serialize_to_bytes();
+            CommitRequest::new(
+                vid,
+                &cid,
+                snapshot_lsn.map(LSN::new),
+                PageCount::new(page_count),
+                PageSize::DEFAULT,
+                vec![SegmentInfo::new(&SegmentId::random(), graft)],
+            )
+        };
+        let latest = async |vid: &VolumeId| {
+            let req = SnapshotRequest {
+                vid: vid.copy_to_bytes(),
+                lsn: None,
+                timestamp: None,
+            };
+            let resp = server
+                .post("/metastore/v1/snapshot")
+                .bytes(req.encode_to_vec().into())
+                .await;
+            SnapshotResponse::decode(resp.into_bytes())
+                .unwrap()
+                .snapshot
+                .unwrap()
+        };
+
+        // commit to both volumes at once
+        let token = CommitToken::random();
+        let txn = CommitTransactionRequest {
+            commits: vids
+                .iter()
+                .map(|vid| commit(vid, None, 1).with_commit_token(token))
+                .collect(),
+        };
+        let resp = server
+            .post("/metastore/v1/commit_transaction")
+            .bytes(txn.encode_to_vec().into())
+            .await;
+        resp.assert_status_ok();
+        let snapshots = CommitTransactionResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshots;
+        assert_eq!(snapshots.len(), 2);
+        for (snapshot, vid) in snapshots.iter().zip(&vids) {
+            assert_eq!(snapshot.vid().unwrap(), vid);
+            assert_eq!(snapshot.lsn().unwrap(), 1);
+            assert_eq!(snapshot.pages(), 1);
+            assert_eq!(&latest(vid).await, snapshot);
+        }
+
+        // retrying the transaction returns the same snapshots
+        let resp = server
+            .post("/metastore/v1/commit_transaction")
+            .bytes(txn.encode_to_vec().into())
+            .await;
+        resp.assert_status_ok();
+        let retried = CommitTransactionResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshots;
+        assert_eq!(retried, snapshots);
+
+        // commits must share a token
+        let mixed = CommitTransactionRequest {
+            commits: vec![
+                commit(&vids[0], Some(1), 2).with_commit_token(CommitToken::random()),
+                commit(&vids[1], Some(1), 2).with_commit_token(CommitToken::random()),
+            ],
+        };
+        let resp = server
+            .post("/metastore/v1/commit_transaction")
+            .bytes(mixed.encode_to_vec().into())
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::BAD_REQUEST);
+
+        // a conflict on one volume rejects the whole transaction
+        let token = CommitToken::random();
+        let conflict = CommitTransactionRequest {
+            commits: vec![
+                commit(&vids[0], Some(1), 2).with_commit_token(token),
+                commit(&vids[1], None, 2).with_commit_token(token),
+            ],
+        };
+        let resp = server
+            .post("/metastore/v1/commit_transaction")
+            .bytes(conflict.encode_to_vec().into())
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::CONFLICT);
+        assert_eq!(latest(&vids[0]).await.lsn().unwrap(), 1);
+
+        // simulate a coordinator which wrote its commit to the first volume
+        // and then failed before deciding the transaction
+        let write_pending = async |vid: &VolumeId, lsn: u64, token: CommitToken, age: Duration| {
+            let meta = CommitMeta::new(
+                vid.clone(),
+                cid.clone(),
+                LSN::new(lsn),
+                LSN::FIRST,
+                PageCount::new(2),
+                PageSize::DEFAULT,
+                SystemTime::now() - age,
+            )
+            .with_token(token)
+            .with_transaction(PageCount::new(1));
+            let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+            commit.write_graft(
+                SegmentId::random(),
+                Splinter::from_slice(&[2]).serialize_to_bytes(),
+            );
+            store.commit(commit.build()).await.unwrap();
+        };
+
+        // pending transactions are invisible until they are decided
+        let pending = CommitToken::random();
+        write_pending(&vids[0], 2, pending, Duration::ZERO).await;
+        assert_eq!(latest(&vids[0]).await.lsn().unwrap(), 1);
+
+        // once aborted, the commit leaves the volume unchanged
+        store
+            .decide_transaction(&pending, TransactionOutcome::Aborted)
+            .await
+            .unwrap();
+        let snapshot = latest(&vids[0]).await;
+        assert_eq!(snapshot.lsn().unwrap(), 2);
+        assert_eq!(snapshot.pages(), 1);
+
+        // expired transactions are aborted by readers
+        let expired = CommitToken::random();
+        write_pending(&vids[1], 2, expired, Duration::from_secs(60)).await;
+        let snapshot = latest(&vids[1]).await;
+        assert_eq!(snapshot.lsn().unwrap(), 2);
+        assert_eq!(snapshot.pages(), 1);
+        assert_eq!(
+            store.transaction_outcome(&expired).await.unwrap(),
+            Some(TransactionOutcome::Aborted)
+        );
+
+        // a retry of an undecided transaction reuses the commits written by
+        // the earlier attempt
+        let retried = CommitToken::random();
+        write_pending(&vids[0], 3, retried, Duration::ZERO).await;
+        let txn = CommitTransactionRequest {
+            commits: vids
+                .iter()
+                .map(|vid| commit(vid, Some(2), 2).with_commit_token(retried))
+                .collect(),
+        };
+        let resp = server
+            .post("/metastore/v1/commit_transaction")
+            .bytes(txn.encode_to_vec().into())
+            .await;
+        resp.assert_status_ok();
+        let snapshots = CommitTransactionResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshots;
+        for (snapshot, vid) in snapshots.iter().zip(&vids) {
+            assert_eq!(snapshot.lsn().unwrap(), 3);
+            assert_eq!(snapshot.pages(), 2);
+            assert_eq!(&latest(vid).await, snapshot);
+        }
+        assert_eq!(
+            store.transaction_outcome(&retried).await.unwrap(),
+            Some(TransactionOutcome::Committed)
+        );
+    }
+}
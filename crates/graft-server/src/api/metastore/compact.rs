@@ -0,0 +1,252 @@
+use std::{sync::Arc, time::SystemTime};
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use graft_core::{VolumeId, lsn::LSN};
+use graft_proto::metastore::v1::{CompactRequest, CompactResponse};
+use splinter_rs::{
+    Splinter,
+    ops::{Intersection, Merge, Union},
+};
+
+use crate::{
+    api::{
+        auth::RequireService,
+        error::{ApiErr, ApiErrCtx},
+        extractors::Protobuf,
+        response::ProtoResponse,
+    },
+    volume::commit::{CommitBuilder, CommitMeta},
+};
+
+use super::MetastoreApiState;
+
+/// Appends the output of a segment compaction to a Volume. The compacted
+/// commit stores the latest version of every page changed since the Volume's
+/// last checkpoint, so reads of later snapshots can skip the many small
+/// segments written by the commits it covers. Since the compacted commit never
+/// changes the contents of the Volume, existing commits are left untouched and
+/// reads of earlier snapshots are unaffected.
+///
+/// The metastore can't verify the contents of the compacted segments, so
+/// compactions are only accepted from other Graft services.
+#[tracing::instrument(name = "metastore/v1/compact", skip(_service, state, req))]
+pub async fn handler(
+    _service: RequireService,
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<CompactRequest>,
+) -> Result<ProtoResponse<CompactResponse>, ApiErr> {
+    let vid: VolumeId = req.vid.try_into()?;
+    let lsn = LSN::try_from(req.lsn).or_into_ctx()?;
+
+    tracing::info!(?vid, ?lsn, num_segments = req.segments.len());
+
+    let latest = state.rewrite_base(&vid, lsn).await?;
+
+    // collect the pages changed since the checkpoint
+    let Some(start) = latest.checkpoint().next().filter(|&start| start <= lsn) else {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::InvalidCompaction,
+            format!("volume {vid} has no commits since its checkpoint"),
+        )
+        .into());
+    };
+    let lsns = start..=lsn;
+    state
+        .updater
+        .update_catalog_from_store_in_range(&state.store, &state.catalog, &vid, &lsns)
+        .await
+        .or_into_ctx()?;
+    let changed = state
+        .catalog
+        .scan_segments(&vid, &lsns)
+        .try_fold(Splinter::default(), |acc, kv| {
+            kv.map(|(_, g)| acc.union(&g))
+        })
+        .or_into_ctx()?;
+
+    // pages past the end of the volume don't need to be compacted
+    let page_count = latest.page_count();
+    let changed = Splinter::from_iter(
+        changed
+            .iter()
+            .take_while(|&pageidx| pageidx <= page_count.to_u32()),
+    );
+
+    let meta = CommitMeta::rewrite(
+        &latest,
+        lsn.saturating_next(),
+        latest.checkpoint(),
+        SystemTime::now(),
+    );
+    let mut commit = CommitBuilder::new_with_capacity(meta, req.segments.len());
+    let mut compacted_pages = Splinter::default();
+    for segment in req.segments {
+        let sid = segment.sid().or_into_ctx()?;
+        let source_vid = segment.source_vid().or_into_ctx()?.unwrap_or(&vid);
+        let graft = segment.graft().or_into_ctx()?;
+        if !compacted_pages.intersection(&graft).is_empty() {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::InvalidCompaction,
+                format!("compaction of volume {vid} contains duplicate page idxs"),
+            )
+            .into());
+        }
+        compacted_pages.merge(&graft);
+        commit.write_graft_from(sid.clone(), source_vid.clone(), graft.into_inner());
+    }
+
+    if compacted_pages != changed {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::InvalidCompaction,
+            format!(
+                "compaction of volume {vid} does not contain the pages changed since lsn {start}"
+            ),
+        )
+        .into());
+    }
+
+    let commit = commit.build();
+    state.append_rewrite(commit.clone()).await?;
+
+    tracing::debug!("compacted volume {vid} at lsn {}", commit.meta().lsn());
+
+    Ok(ProtoResponse::new(CompactResponse {
+        snapshot: Some(commit.into_snapshot()),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{handler::Handler, http::StatusCode};
+    use axum_test::TestServer;
+    use graft_core::{PageSize, SegmentId, gid::ClientId, page_count::PageCount};
+    use graft_proto::common::v1::SegmentInfo;
+    use object_store::memory::InMemory;
+    use prost::Message;
+
+    use crate::{
+        api::extractors::CONTENT_TYPE_PROTOBUF,
+        volume::{catalog::VolumeCatalog, store::VolumeStore, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_compact_sanity() {
+        let store = Arc::new(InMemory::default());
+        let store = Arc::new(VolumeStore::new(store));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let state = Arc::new(MetastoreApiState::new(
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8),
+        ));
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+
+        // the first commit is the checkpoint, and the next two commits each
+        // write pages to their own segments
+        let commits: [&[&[u32]]; 3] = [&[&[1, 2], &[3]], &[&[1], &[2]], &[&[2, 4]]];
+        for (i, grafts) in commits.into_iter().enumerate() {
+            let lsn = LSN::new(i as u64 + 1);
+            let mut commit = CommitBuilder::new_with_capacity(
+                CommitMeta::new(
+                    vid.clone(),
+                    cid.clone(),
+                    lsn,
+                    LSN::FIRST,
+                    PageCount::new(3),
+                    PageSize::DEFAULT,
+                    SystemTime::now(),
+                ),
+                grafts.len(),
+            );
+            for graft in grafts {
+                commit.write_graft(
+                    SegmentId::random(),
+                    Splinter::from_iter(graft.iter().copied()).serialize_to_bytes(),
+                );
+            }
+            store.commit(commit.build()).await.unwrap();
+        }
+
+        let compact = |lsn: u64, grafts: &[&[u32]]| CompactRequest {
+            vid: vid.copy_to_bytes(),
+            lsn,
+            segments: grafts
+                .iter()
+                .map(|graft| {
+                    SegmentInfo::new(
+                        &SegmentId::random(),
+                        Splinter::from_iter(graft.iter().copied()).serialize_to_bytes(),
+                    )
+                })
+                .collect(),
+        };
+
+        // a compaction of an older snapshot is rejected
+        let resp = server
+            .post("/")
+            .expect_failure()
+            .bytes(compact(2, &[&[1, 2]]).encode_to_vec().into())
+            .await;
+        resp.assert_status(StatusCode::CONFLICT);
+
+        // a compaction which loses a page is rejected
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(compact(3, &[&[1]]).encode_to_vec().into())
+            .await;
+
+        // a compaction which duplicates a page is rejected
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(compact(3, &[&[1, 2], &[2]]).encode_to_vec().into())
+            .await;
+
+        // a valid compaction appends a commit containing the pages changed
+        // since the checkpoint; page 4 is past the end of the volume
+        let req = compact(3, &[&[1, 2]]);
+        let sid = req.segments[0].sid().unwrap().clone();
+        let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
+        let resp = CompactResponse::decode(resp.into_bytes()).unwrap();
+        let snapshot = resp.snapshot.unwrap();
+        assert_eq!(snapshot.lsn().unwrap(), LSN::new(4));
+        assert_eq!(snapshot.checkpoint().unwrap(), LSN::FIRST);
+        assert_eq!(snapshot.pages(), PageCount::new(3));
+
+        // check the catalog
+        let segments = catalog
+            .scan_segments(&vid, &(LSN::new(4)..=LSN::new(4)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].0.sid(), &sid);
+        assert_eq!(segments[0].1.cardinality(), 2);
+        let meta = catalog.snapshot(vid.clone(), LSN::new(4)).unwrap().unwrap();
+        assert!(meta.is_rewrite());
+
+        // the compacted commits are unchanged
+        let commit = store.get_commit(vid.clone(), LSN::new(2)).await.unwrap();
+        assert_eq!(commit.iter_grafts().count(), 2);
+
+        // the compaction can't be appended twice
+        let resp = server
+            .post("/")
+            .expect_failure()
+            .bytes(req.encode_to_vec().into())
+            .await;
+        resp.assert_status(StatusCode::CONFLICT);
+    }
+}
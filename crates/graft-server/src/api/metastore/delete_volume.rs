@@ -0,0 +1,234 @@
+use std::{sync::Arc, time::SystemTime};
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use graft_core::{VolumeId, gid::ClientId};
+use graft_proto::metastore::v1::{DeleteVolumeRequest, DeleteVolumeResponse};
+
+use crate::{
+    api::{
+        error::{ApiErr, ApiErrCtx},
+        extractors::Protobuf,
+        response::ProtoResponse,
+    },
+    volume::{
+        commit::{CommitBuilder, CommitMeta},
+        store::VolumeStoreErr,
+    },
+};
+
+use super::MetastoreApiState;
+
+#[tracing::instrument(name = "metastore/v1/delete_volume", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<DeleteVolumeRequest>,
+) -> Result<ProtoResponse<DeleteVolumeResponse>, ApiErr> {
+    let vid = VolumeId::try_from(req.vid).or_into_culprit("failed to parse VolumeId")?;
+    let cid = ClientId::try_from(req.cid).or_into_culprit("failed to parse ClientId")?;
+
+    tracing::info!(?vid, ?cid);
+
+    loop {
+        let latest = state
+            .updater
+            .snapshot(&state.store, &state.catalog, &vid, None)
+            .await
+            .or_into_ctx()?;
+
+        let Some(latest) = latest else {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::SnapshotMissing,
+                format!("volume {vid} does not exist"),
+            )
+            .into());
+        };
+
+        // deleting a deleted volume succeeds
+        if latest.is_tombstone() {
+            return Ok(ProtoResponse::new(DeleteVolumeResponse {
+                snapshot: Some(latest.into_snapshot()),
+            }));
+        }
+
+        let lsn = latest.lsn().saturating_next();
+        let meta = CommitMeta::tombstone(
+            vid.clone(),
+            cid.clone(),
+            lsn,
+            latest.page_size(),
+            SystemTime::now(),
+        );
+        let commit = CommitBuilder::new_with_capacity(meta, 0).build();
+
+        // if another commit lands first, retry against the new latest commit
+        match state.store.commit(commit.clone()).await {
+            Ok(()) => {}
+            Err(err) if matches!(err.ctx(), VolumeStoreErr::CommitAlreadyExists) => {
+                tracing::debug!("volume {vid} changed while deleting it; retrying");
+                continue;
+            }
+            Err(err) => return Err(err.map_ctx(ApiErrCtx::from).into()),
+        }
+
+        let mut batch = state.catalog.batch_insert();
+        batch.insert_commit(&commit).or_into_ctx()?;
+        batch.commit().or_into_ctx()?;
+        state.notify_commit();
+
+        tracing::info!("deleted volume {vid} at lsn {lsn}");
+
+        return Ok(ProtoResponse::new(DeleteVolumeResponse {
+            snapshot: Some(commit.into_snapshot()),
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use axum_test::TestServer;
+    use bytes::Bytes;
+    use graft_core::{PageSize, SegmentId, lsn::LSN, page_count::PageCount};
+    use graft_proto::metastore::v1::{CommitRequest, SnapshotRequest};
+    use object_store::memory::InMemory;
+    use prost::Message;
+    use splinter_rs::Splinter;
+
+    use crate::{
+        api::{
+            extractors::CONTENT_TYPE_PROTOBUF, metastore::metastore_routes, routes::build_router,
+        },
+        metrics::registry::Registry,
+        volume::{catalog::VolumeCatalog, store::VolumeStore, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_delete_volume() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let state = Arc::new(MetastoreApiState::new(
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8),
+        ));
+        let router = build_router(Registry::default(), None, state, metastore_routes());
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .build(router)
+            .unwrap();
+
+        // a second metastore sharing the store, with its own catalog
+        let other = Arc::new(MetastoreApiState::new(
+            store.clone(),
+            VolumeCatalog::open_temporary().unwrap(),
+            VolumeCatalogUpdater::new(8),
+        ));
+        let router = build_router(Registry::default(), None, other, metastore_routes());
+        let other = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .build(router)
+            .unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+        let delete = DeleteVolumeRequest {
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+        };
+
+        // deleting a volume which doesn't exist fails
+        let resp = server
+            .post("/metastore/v1/delete_volume")
+            .bytes(delete.encode_to_vec().into())
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
+
+        let meta = CommitMeta::new(
+            vid.clone(),
+            cid.clone(),
+            LSN::FIRST,
+            LSN::FIRST,
+            PageCount::new(1),
+            PageSize::DEFAULT,
+            SystemTime::now(),
+        );
+        let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+        commit.write_graft(
+            SegmentId::random(),
+            Splinter::from_slice(&[1]).serialize_to_bytes(),
+        );
+        store.commit(commit.build()).await.unwrap();
+
+        // load the volume into the other metastore's catalog
+        let snapshot_req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: Some(1),
+            timestamp: None,
+        };
+        other
+            .post("/metastore/v1/snapshot")
+            .bytes(snapshot_req.encode_to_vec().into())
+            .await
+            .assert_status_ok();
+
+        // the tombstone is written after the latest commit
+        let resp = server
+            .post("/metastore/v1/delete_volume")
+            .bytes(delete.encode_to_vec().into())
+            .await;
+        resp.assert_status_ok();
+        let tombstone = DeleteVolumeResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshot
+            .unwrap();
+        assert_eq!(tombstone.lsn().unwrap(), 2);
+        assert_eq!(tombstone.checkpoint().unwrap(), 2);
+        assert_eq!(tombstone.pages(), 0);
+
+        // deletes are idempotent
+        let resp = server
+            .post("/metastore/v1/delete_volume")
+            .bytes(delete.encode_to_vec().into())
+            .await;
+        resp.assert_status_ok();
+        let snapshot = DeleteVolumeResponse::decode(resp.into_bytes())
+            .unwrap()
+            .snapshot
+            .unwrap();
+        assert_eq!(snapshot, tombstone);
+
+        // reads are rejected
+        let resp = server
+            .post("/metastore/v1/snapshot")
+            .bytes(snapshot_req.encode_to_vec().into())
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::GONE);
+
+        // including by metastores whose catalog predates the delete
+        let resp = other
+            .post("/metastore/v1/snapshot")
+            .bytes(snapshot_req.encode_to_vec().into())
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::GONE);
+
+        // as are commits
+        let req = CommitRequest {
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+            snapshot_lsn: Some(2),
+            page_count: 1,
+            segments: vec![],
+            page_size: 0,
+            commit_token: Bytes::new(),
+        };
+        let resp = server
+            .post("/metastore/v1/commit")
+            .bytes(req.encode_to_vec().into())
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::GONE);
+    }
+}
@@ -0,0 +1,374 @@
+use std::{collections::BTreeMap, sync::Arc, time::SystemTime};
+
+use axum::extract::State;
+use bytes::Bytes;
+use culprit::{Culprit, ResultExt};
+use graft_core::{SegmentId, VolumeId, gid::ClientId, lsn::LSN};
+use graft_proto::metastore::v1::{ForkRequest, ForkResponse};
+use splinter_rs::{
+    Splinter, SplinterRef,
+    ops::{Cut, Merge},
+};
+
+use crate::{
+    api::{
+        error::{ApiErr, ApiErrCtx},
+        extractors::Protobuf,
+        response::ProtoResponse,
+    },
+    volume::{
+        catalog::VolumeCatalogErr,
+        commit::{CommitBuilder, CommitMeta},
+        kv::SegmentKey,
+    },
+};
+
+use super::MetastoreApiState;
+
+/// Creates a new Volume which starts as a copy of the source Volume at the
+/// requested LSN (or the latest LSN if not specified). No pages are copied;
+/// instead the new Volume's first commit is a checkpoint whose grafts
+/// reference the source Volume's segments. Subsequent writes to either Volume
+/// are not visible to the other.
+///
+/// Pages written to the source Volume since its checkpoint may be stored as
+/// deltas against the source checkpoint, so the first commit also references
+/// the source checkpoint's version of those pages using delta base grafts.
+#[tracing::instrument(name = "metastore/v1/fork", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<ForkRequest>,
+) -> Result<ProtoResponse<ForkResponse>, ApiErr> {
+    let source_vid =
+        VolumeId::try_from(req.source_vid).or_into_culprit("failed to parse source VolumeId")?;
+    let vid = VolumeId::try_from(req.vid).or_into_culprit("failed to parse VolumeId")?;
+    let cid = ClientId::try_from(req.cid).or_into_culprit("failed to parse ClientId")?;
+    let lsn: Option<LSN> = req.lsn.map(LSN::try_from).transpose().or_into_ctx()?;
+
+    tracing::info!(?source_vid, ?vid, ?cid, ?lsn);
+
+    if vid == source_vid {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::InvalidRequestBody,
+            "a volume can not be forked into itself",
+        )
+        .into());
+    }
+
+    let source = state
+        .updater
+        .snapshot(&state.store, &state.catalog, &source_vid, lsn)
+        .await
+        .or_into_ctx()?;
+    state.check_deleted(&source_vid).await?;
+    let Some(source) = source else {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::SnapshotMissing,
+            format!("volume {source_vid} is missing snapshot at {lsn:?}"),
+        )
+        .into());
+    };
+
+    // the target volume must not exist yet
+    // deleted volumes can't be recreated
+    let existing = state.live_snapshot(&vid).await?;
+    if existing.is_some() {
+        let note = format!("fork rejected: volume {vid} already exists");
+        tracing::debug!(%note);
+        return Err(Culprit::new_with_note(ApiErrCtx::RejectedCommit, note).into());
+    }
+
+    // load the source checkpoint, which the source pages may be stored as
+    // deltas against
+    let checkpoint = if source.checkpoint() == source.lsn() {
+        source.clone()
+    } else {
+        let checkpoint = state
+            .updater
+            .snapshot(
+                &state.store,
+                &state.catalog,
+                &source_vid,
+                Some(source.checkpoint()),
+            )
+            .await
+            .or_into_ctx()?;
+        checkpoint.ok_or_else(|| {
+            Culprit::new_with_note(
+                ApiErrCtx::SnapshotMissing,
+                format!(
+                    "volume {source_vid} is missing checkpoint {}",
+                    source.checkpoint()
+                ),
+            )
+        })?
+    };
+    let checkpoint_lsns = checkpoint.lsn()..=checkpoint.lsn();
+
+    // collect the newest version of every page visible in the source snapshot,
+    // starting with the pages written since the checkpoint
+    let page_count = source.page_count().to_u32();
+    let mut claimed = Splinter::default();
+    let mut grafts = Grafts::new();
+    if let Some(next) = checkpoint.lsn().next().filter(|&next| next <= source.lsn()) {
+        collect_grafts(
+            &mut grafts,
+            &mut claimed,
+            state
+                .catalog
+                .scan_segments(&source_vid, &(next..=source.lsn())),
+            |pageidx| pageidx <= page_count,
+        )
+        .or_into_ctx()?;
+    }
+    let changed = claimed.clone();
+    collect_grafts(
+        &mut grafts,
+        &mut claimed,
+        state.catalog.scan_segments(&source_vid, &checkpoint_lsns),
+        |pageidx| pageidx <= page_count,
+    )
+    .or_into_ctx()?;
+
+    // deltas written since the checkpoint are based on the checkpoint's
+    // version of each page, while deltas stored in the checkpoint were
+    // inherited by the source volume and are based on its delta bases
+    let checkpoint_page_count = checkpoint.page_count().to_u32();
+    let mut claimed = Splinter::default();
+    let mut delta_bases = Grafts::new();
+    collect_grafts(
+        &mut delta_bases,
+        &mut claimed,
+        state.catalog.scan_segments(&source_vid, &checkpoint_lsns),
+        |pageidx| pageidx <= checkpoint_page_count && changed.contains(pageidx),
+    )
+    .or_into_ctx()?;
+    collect_grafts(
+        &mut delta_bases,
+        &mut claimed,
+        state
+            .catalog
+            .scan_delta_bases(&source_vid, &checkpoint_lsns),
+        |pageidx| pageidx <= page_count && !changed.contains(pageidx),
+    )
+    .or_into_ctx()?;
+
+    // the first commit of the fork contains every page, which makes it a
+    // checkpoint
+    let meta = CommitMeta::new(
+        vid.clone(),
+        cid,
+        LSN::FIRST,
+        LSN::FIRST,
+        source.page_count(),
+        source.page_size(),
+        SystemTime::now(),
+    );
+    let mut commit = CommitBuilder::new_with_capacity(meta, grafts.len() + delta_bases.len());
+    for ((sid, source_vid), graft) in grafts {
+        commit.write_graft_from(sid, source_vid, graft.serialize_to_bytes());
+    }
+    for ((sid, source_vid), graft) in delta_bases {
+        commit.write_delta_base(sid, source_vid, graft.serialize_to_bytes());
+    }
+    let commit = commit.build();
+
+    state.store.commit(commit.clone()).await.or_into_ctx()?;
+
+    let mut batch = state.catalog.batch_insert();
+    batch.insert_commit(&commit).or_into_ctx()?;
+    batch.commit().or_into_ctx()?;
+    state.notify_commit();
+
+    tracing::info!(
+        "forked volume {source_vid} at lsn {} into volume {vid}",
+        source.lsn()
+    );
+
+    Ok(ProtoResponse::new(ForkResponse {
+        snapshot: Some(commit.into_snapshot()),
+    }))
+}
+
+/// Pages grouped by the segment and volume they are stored under
+type Grafts = BTreeMap<(SegmentId, VolumeId), Splinter>;
+
+/// Collect the newest version of every page which hasn't been claimed yet and
+/// is accepted by `keep`, claiming the collected pages
+fn collect_grafts(
+    grafts: &mut Grafts,
+    claimed: &mut Splinter,
+    scan: impl Iterator<Item = Result<(SegmentKey, SplinterRef<Bytes>), Culprit<VolumeCatalogErr>>>,
+    keep: impl Fn(u32) -> bool,
+) -> Result<(), Culprit<VolumeCatalogErr>> {
+    for kv in scan {
+        let (key, graft) = kv?;
+        let mut pages = Splinter::from_iter(graft.iter().filter(|&pageidx| keep(pageidx)));
+        pages.cut(claimed);
+        if pages.is_empty() {
+            continue;
+        }
+        claimed.merge(&pages);
+        grafts
+            .entry((key.sid().clone(), key.source_vid().clone()))
+            .or_default()
+            .merge(&pages);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::handler::Handler;
+    use axum_test::TestServer;
+    use graft_core::{PageSize, page_count::PageCount};
+    use object_store::memory::InMemory;
+    use prost::Message;
+
+    use crate::{
+        api::extractors::CONTENT_TYPE_PROTOBUF,
+        volume::{catalog::VolumeCatalog, store::VolumeStore, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    /// Collect the pages referenced by each segment, asserting that they are
+    /// stored under the source volume
+    fn found(
+        scan: impl Iterator<Item = Result<(SegmentKey, SplinterRef<Bytes>), Culprit<VolumeCatalogErr>>>,
+        source_vid: &VolumeId,
+    ) -> Vec<(SegmentId, Vec<u32>)> {
+        let mut found = scan
+            .map(|kv| {
+                let (key, graft) = kv.unwrap();
+                assert_eq!(key.source_vid(), source_vid);
+                (key.sid().clone(), graft.iter().collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+        found.sort();
+        found
+    }
+
+    #[graft_test::test]
+    async fn test_fork_sanity() {
+        let store = Arc::new(InMemory::default());
+        let store = Arc::new(VolumeStore::new(store));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let state = Arc::new(MetastoreApiState::new(
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8),
+        ));
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        let source_vid = VolumeId::random();
+        let cid = ClientId::random();
+
+        // page 2 is overwritten by the second commit, and page 4 is truncated
+        // by the third commit
+        let [sid1, sid2, sid3] = [(); 3].map(|_| SegmentId::random());
+        let commits = [
+            (LSN::new(1), PageCount::new(4), &sid1, vec![1u32, 2, 4]),
+            (LSN::new(2), PageCount::new(4), &sid2, vec![2, 3]),
+            (LSN::new(3), PageCount::new(3), &sid3, vec![]),
+        ];
+        for (lsn, page_count, sid, pages) in commits {
+            let meta = CommitMeta::new(
+                source_vid.clone(),
+                cid.clone(),
+                lsn,
+                LSN::FIRST,
+                page_count,
+                PageSize::DEFAULT,
+                SystemTime::now(),
+            );
+            let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+            if !pages.is_empty() {
+                let graft = Splinter::from_iter(pages);
+                commit.write_graft(sid.clone(), graft.serialize_to_bytes());
+            }
+            store.commit(commit.build()).await.unwrap();
+        }
+
+        let fork = |vid: &VolumeId, lsn: Option<u64>| ForkRequest {
+            source_vid: source_vid.copy_to_bytes(),
+            lsn,
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+        };
+
+        // a volume can't be forked into itself
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(fork(&source_vid, None).encode_to_vec().into())
+            .await;
+
+        // fork the latest snapshot
+        let vid = VolumeId::random();
+        let resp = server
+            .post("/")
+            .bytes(fork(&vid, None).encode_to_vec().into())
+            .await
+            .into_bytes();
+        let snapshot = ForkResponse::decode(resp).unwrap().snapshot.unwrap();
+        assert_eq!(snapshot.vid().unwrap(), &vid);
+        assert_eq!(snapshot.lsn().unwrap(), LSN::FIRST);
+        assert_eq!(snapshot.checkpoint().unwrap(), LSN::FIRST);
+        assert_eq!(snapshot.pages(), PageCount::new(3));
+
+        // the fork references the source segments under the source volume
+        let lsns = LSN::FIRST..=LSN::FIRST;
+        let mut expected = vec![(sid1.clone(), vec![1]), (sid2.clone(), vec![2, 3])];
+        expected.sort();
+        assert_eq!(
+            found(catalog.scan_segments(&vid, &lsns), &source_vid),
+            expected
+        );
+
+        // page 2 was overwritten since the source checkpoint, so the fork
+        // references the checkpoint version as the base of a potential delta
+        assert_eq!(
+            found(catalog.scan_delta_bases(&vid, &lsns), &source_vid),
+            vec![(sid1.clone(), vec![2])]
+        );
+
+        // the fork must not already exist
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(fork(&vid, None).encode_to_vec().into())
+            .await;
+
+        // fork an older snapshot
+        let vid = VolumeId::random();
+        let resp = server
+            .post("/")
+            .bytes(fork(&vid, Some(1)).encode_to_vec().into())
+            .await
+            .into_bytes();
+        let snapshot = ForkResponse::decode(resp).unwrap().snapshot.unwrap();
+        assert_eq!(snapshot.pages(), PageCount::new(4));
+        let segments = catalog
+            .scan_segments(&vid, &(LSN::FIRST..=LSN::FIRST))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].0.sid(), &sid1);
+        assert_eq!(segments[0].1.cardinality(), 3);
+        assert_eq!(catalog.scan_delta_bases(&vid, &(LSN::FIRST..)).count(), 0);
+
+        // forking a missing snapshot fails
+        server
+            .post("/")
+            .expect_failure()
+            .bytes(fork(&VolumeId::random(), Some(10)).encode_to_vec().into())
+            .await;
+    }
+}
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use bytes::Bytes;
+use culprit::{Culprit, ResultExt};
+use futures::future::try_join_all;
+use graft_core::VolumeId;
+use graft_proto::metastore::v1::{ListVolumesRequest, ListVolumesResponse, VolumeInfo};
+
+use crate::api::{
+    error::{ApiErr, ApiErrCtx},
+    extractors::Protobuf,
+    response::ProtoResponse,
+};
+
+use super::MetastoreApiState;
+
+/// The maximum number of volumes returned by a single request
+const MAX_LIMIT: usize = 1000;
+
+#[tracing::instrument(name = "metastore/v1/list_volumes", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<ListVolumesRequest>,
+) -> Result<ProtoResponse<ListVolumesResponse>, ApiErr> {
+    let cursor: Option<VolumeId> = if req.cursor.is_empty() {
+        None
+    } else {
+        Some(req.cursor.try_into()?)
+    };
+    if req.limit == 0 {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::InvalidRequestBody,
+            "list_volumes limit must be non-zero",
+        )
+        .into());
+    }
+    let limit = (req.limit as usize).min(MAX_LIMIT);
+
+    tracing::info!(?cursor, limit);
+
+    // list one extra volume to determine if there is another page
+    let mut listed = state
+        .store
+        .list_volumes_after(cursor.as_ref(), limit + 1)
+        .await
+        .or_into_ctx()?;
+    let next_cursor = if listed.len() > limit {
+        listed.truncate(limit);
+        listed[limit - 1].vid.copy_to_bytes()
+    } else {
+        Bytes::new()
+    };
+
+    // load the latest snapshot of each volume concurrently; the updater
+    // limits the number of concurrent updates
+    let snapshots = try_join_all(listed.iter().map(|volume| {
+        state
+            .updater
+            .snapshot(&state.store, &state.catalog, &volume.vid, None)
+    }))
+    .await
+    .or_into_ctx()?;
+
+    // volumes are only listed if they have at least one commit, but the
+    // commits may be deleted concurrently. Deleted volumes are skipped.
+    let volumes = listed
+        .into_iter()
+        .zip(snapshots)
+        .filter_map(|(volume, snapshot)| {
+            let snapshot = snapshot.filter(|s| !s.is_tombstone())?;
+            Some(VolumeInfo {
+                size: snapshot.page_count().size(snapshot.page_size()).as_u64(),
+                created_at: Some(volume.created_at.into()),
+                snapshot: Some(snapshot.into_snapshot()),
+            })
+        })
+        .collect();
+
+    Ok(ProtoResponse::new(ListVolumesResponse {
+        volumes,
+        next_cursor,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use axum::handler::Handler;
+    use axum_test::TestServer;
+    use graft_core::{PageSize, SegmentId, gid::ClientId, lsn::LSN, page_count::PageCount};
+    use object_store::memory::InMemory;
+    use prost::Message;
+    use splinter_rs::Splinter;
+
+    use crate::{
+        api::extractors::CONTENT_TYPE_PROTOBUF,
+        volume::{
+            catalog::VolumeCatalog,
+            commit::{CommitBuilder, CommitMeta},
+            store::VolumeStore,
+            updater::VolumeCatalogUpdater,
+        },
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_list_volumes() {
+        let objects = Arc::new(InMemory::default());
+        let store = Arc::new(VolumeStore::new(objects.clone()));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let state = Arc::new(MetastoreApiState::new(
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8),
+        ));
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        let list = async |cursor: Bytes, limit: u32| {
+            let req = ListVolumesRequest { cursor, limit };
+            let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
+            ListVolumesResponse::decode(resp.into_bytes()).unwrap()
+        };
+
+        // an empty store has no volumes
+        let resp = list(Bytes::new(), 10).await;
+        assert!(resp.volumes.is_empty());
+        assert!(resp.next_cursor.is_empty());
+
+        // write five volumes, where volume i has i+1 commits
+        let cid = ClientId::random();
+        let mut vids = vec![];
+        for i in 0..5 {
+            let vid = VolumeId::random();
+            for lsn in 1..=(i + 1) {
+                let meta = CommitMeta::new(
+                    vid.clone(),
+                    cid.clone(),
+                    LSN::new(lsn),
+                    LSN::FIRST,
+                    PageCount::new(1),
+                    PageSize::DEFAULT,
+                    SystemTime::now(),
+                );
+                let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+                commit.write_graft(
+                    SegmentId::random(),
+                    Splinter::from_slice(&[1]).serialize_to_bytes(),
+                );
+                store.commit(commit.build()).await.unwrap();
+            }
+            vids.push((vid, LSN::new(i + 1)));
+        }
+        vids.sort_by_key(|(vid, _)| vid.pretty());
+
+        // page through the volumes two at a time
+        let mut listed = vec![];
+        let mut cursor = Bytes::new();
+        let mut pages = 0;
+        loop {
+            let resp = list(cursor, 2).await;
+            assert!(resp.volumes.len() <= 2);
+            pages += 1;
+            for volume in resp.volumes {
+                assert_eq!(volume.size, PageSize::DEFAULT.as_usize() as u64);
+                assert!(volume.created_at.is_some());
+                let snapshot = volume.snapshot.unwrap();
+                listed.push((snapshot.vid().unwrap().clone(), snapshot.lsn().unwrap()));
+            }
+            if resp.next_cursor.is_empty() {
+                break;
+            }
+            cursor = resp.next_cursor;
+        }
+        assert_eq!(pages, 3);
+
+        // every volume is listed once, ordered by its key in the store, along
+        // with its latest lsn
+        assert_eq!(listed, vids);
+
+        // stores which list keys in an arbitrary order list the same volumes
+        let unordered = VolumeStore::new(objects).with_unordered_listing();
+        let page = unordered
+            .list_volumes_after(Some(&vids[0].0), 2)
+            .await
+            .unwrap();
+        let page: Vec<_> = page.into_iter().map(|v| v.vid).collect();
+        assert_eq!(page, vec![vids[1].0.clone(), vids[2].0.clone()]);
+    }
+}
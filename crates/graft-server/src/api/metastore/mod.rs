@@ -1,20 +1,54 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use axum::routing::post;
+use culprit::{Culprit, ResultExt};
+use graft_core::{VolumeId, lsn::LSN};
+use tokio::sync::watch::Sender;
+use tokio_util::sync::CancellationToken;
 
-use crate::volume::{catalog::VolumeCatalog, store::VolumeStore, updater::VolumeCatalogUpdater};
+use crate::{
+    bytes_vec::BytesVec,
+    volume::{
+        catalog::VolumeCatalog,
+        commit::{Commit, CommitMeta},
+        store::{VolumeStore, VolumeStoreErr},
+        updater::VolumeCatalogUpdater,
+    },
+};
 
-use super::routes::Routes;
+use super::{
+    error::{ApiErr, ApiErrCtx},
+    routes::Routes,
+};
 
+mod checkpoint;
 mod commit;
+mod commit_transaction;
+mod compact;
+mod delete_volume;
+mod fork;
+mod list_volumes;
 mod pull_commits;
 mod pull_graft;
 mod snapshot;
+mod watch;
+
+/// How often watch requests check the store for commits made through other
+/// Metastores
+const DEFAULT_WATCH_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
 
 pub struct MetastoreApiState {
     store: Arc<VolumeStore>,
     catalog: VolumeCatalog,
     updater: VolumeCatalogUpdater,
+
+    /// notified whenever this Metastore adds a commit to the catalog
+    commits: Sender<()>,
+    watch_refresh_interval: Duration,
+
+    /// cancelled when the Metastore shuts down to release pending watch
+    /// requests
+    shutdown: CancellationToken,
 }
 
 impl MetastoreApiState {
@@ -23,7 +57,24 @@ impl MetastoreApiState {
         catalog: VolumeCatalog,
         updater: VolumeCatalogUpdater,
     ) -> Self {
-        Self { store, catalog, updater }
+        Self {
+            store,
+            catalog,
+            updater,
+            commits: Sender::new(()),
+            watch_refresh_interval: DEFAULT_WATCH_REFRESH_INTERVAL,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    pub fn with_watch_refresh_interval(self, watch_refresh_interval: Duration) -> Self {
+        Self { watch_refresh_interval, ..self }
+    }
+
+    /// Pending watch requests return early once `shutdown` is cancelled,
+    /// allowing the API server to shutdown gracefully.
+    pub fn with_shutdown(self, shutdown: CancellationToken) -> Self {
+        Self { shutdown, ..self }
     }
 
     pub fn store(&self) -> &VolumeStore {
@@ -37,6 +88,76 @@ impl MetastoreApiState {
     pub fn updater(&self) -> &VolumeCatalogUpdater {
         &self.updater
     }
+
+    /// Wake up pending watch requests. Call this after adding a commit to the
+    /// catalog.
+    fn notify_commit(&self) {
+        self.commits.send_replace(());
+    }
+
+    /// Load the latest snapshot of a volume from the store. Returns a
+    /// `VolumeDeleted` error if the latest commit is a tombstone.
+    async fn live_snapshot(&self, vid: &VolumeId) -> Result<Option<CommitMeta>, ApiErr> {
+        let latest = self
+            .updater
+            .snapshot(&self.store, &self.catalog, vid, None)
+            .await
+            .or_into_ctx()?;
+        if latest.as_ref().is_some_and(|s| s.is_tombstone()) {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::VolumeDeleted,
+                format!("volume {vid} has been deleted"),
+            )
+            .into());
+        }
+        Ok(latest)
+    }
+
+    /// Returns a `VolumeDeleted` error if the volume has been deleted. The
+    /// volume may have been deleted through another Metastore, so this
+    /// refreshes the catalog from the store rather than trusting it.
+    async fn check_deleted(&self, vid: &VolumeId) -> Result<(), ApiErr> {
+        self.live_snapshot(vid).await.map(|_| ())
+    }
+
+    /// Compactions and checkpoints are computed against a specific snapshot of
+    /// a volume, and may only be appended if that snapshot is still the
+    /// volume's latest snapshot. Returns the latest snapshot, or a
+    /// `RewriteRejected` error if the volume has changed.
+    async fn rewrite_base(&self, vid: &VolumeId, lsn: LSN) -> Result<CommitMeta, ApiErr> {
+        let latest = self.live_snapshot(vid).await?;
+        match latest {
+            Some(latest) if latest.lsn() == lsn => Ok(latest),
+            latest => Err(Culprit::new_with_note(
+                ApiErrCtx::RewriteRejected,
+                format!(
+                    "volume {vid} changed since lsn {lsn}; latest lsn is {:?}",
+                    latest.map(|s| s.lsn())
+                ),
+            )
+            .into()),
+        }
+    }
+
+    /// Append a rewrite created by a compaction or checkpoint to its volume
+    async fn append_rewrite(&self, commit: Commit<BytesVec>) -> Result<(), ApiErr> {
+        debug_assert!(commit.meta().is_rewrite(), "commit must be a rewrite");
+
+        // another commit landed since the rewrite's base was checked
+        self.store
+            .commit(commit.clone())
+            .await
+            .or_ctx(|err| match err {
+                VolumeStoreErr::CommitAlreadyExists => ApiErrCtx::RewriteRejected,
+                err => err.into(),
+            })?;
+
+        let mut batch = self.catalog.batch_insert();
+        batch.insert_commit(&commit).or_into_ctx()?;
+        batch.commit().or_into_ctx()?;
+        self.notify_commit();
+        Ok(())
+    }
 }
 
 pub fn metastore_routes() -> Routes<Arc<MetastoreApiState>> {
@@ -45,5 +166,15 @@ pub fn metastore_routes() -> Routes<Arc<MetastoreApiState>> {
         ("/metastore/v1/pull_graft", post(pull_graft::handler)),
         ("/metastore/v1/pull_commits", post(pull_commits::handler)),
         ("/metastore/v1/commit", post(commit::handler)),
+        (
+            "/metastore/v1/commit_transaction",
+            post(commit_transaction::handler),
+        ),
+        ("/metastore/v1/compact", post(compact::handler)),
+        ("/metastore/v1/checkpoint", post(checkpoint::handler)),
+        ("/metastore/v1/fork", post(fork::handler)),
+        ("/metastore/v1/list_volumes", post(list_volumes::handler)),
+        ("/metastore/v1/delete_volume", post(delete_volume::handler)),
+        ("/metastore/v1/watch", post(watch::handler)),
     ]
 }
@@ -40,6 +40,7 @@ pub async fn handler(
         .snapshot(&state.store, &state.catalog, &vid, end_lsn)
         .await
         .or_into_ctx()?;
+    state.check_deleted(&vid).await?;
 
     let Some(snapshot) = snapshot else {
         return Err(Culprit::new_with_note(
@@ -74,10 +75,14 @@ pub async fn handler(
     while let Some((meta, mut segments)) = scan.try_next().or_into_ctx()? {
         let mut segment_infos = Vec::default();
         while let Some((key, splinter)) = segments.try_next().or_into_ctx()? {
-            segment_infos.push(SegmentInfo {
-                sid: key.sid().copy_to_bytes(),
-                graft: splinter.into_inner(),
-            });
+            let mut info = SegmentInfo::new(key.sid(), splinter.into_inner());
+            if key.source_vid() != &vid {
+                info = info.with_source_vid(key.source_vid());
+            }
+            if key.is_delta_base() {
+                info = info.with_delta_base();
+            }
+            segment_infos.push(info);
         }
 
         result.commits.push(Commit {
@@ -95,7 +100,7 @@ mod tests {
 
     use axum::{handler::Handler, http::StatusCode};
     use axum_test::TestServer;
-    use graft_core::{SegmentId, gid::ClientId, lsn::LSN, page_count::PageCount};
+    use graft_core::{PageSize, SegmentId, gid::ClientId, lsn::LSN, page_count::PageCount};
     use graft_proto::common::v1::LsnRange;
     use object_store::memory::InMemory;
     use prost::Message;
@@ -152,6 +157,7 @@ mod tests {
                 LSN::new(lsn),
                 LSN::FIRST,
                 PageCount::new(1),
+                PageSize::DEFAULT,
                 SystemTime::now(),
             );
             let mut commit = CommitBuilder::new_with_capacity(meta, 1);
@@ -42,6 +42,7 @@ pub async fn handler(
         .snapshot(&state.store, &state.catalog, &vid, end_lsn)
         .await
         .or_into_ctx()?;
+    state.check_deleted(&vid).await?;
 
     let Some(snapshot) = snapshot else {
         return Err(Culprit::new_with_note(
@@ -53,6 +54,7 @@ pub async fn handler(
 
     // resolve the start of the range, defaulting to the last checkpoint
     let checkpoint = snapshot.checkpoint();
+    let since_checkpoint = lsns.is_none();
     let start_lsn = match lsns {
         Some(l) => l.start().or_into_ctx()?,
         None => checkpoint,
@@ -82,11 +84,21 @@ pub async fn handler(
         .await
         .or_into_ctx()?;
 
-    // read the segments, and merge into a single splinter
-    let mut iter = state.catalog.scan_segments(&vid, &lsns);
+    // read the segments, and merge into a single splinter. Rewrites don't
+    // change any pages, so they are skipped unless the request covers every
+    // page since the checkpoint, which may itself be a rewrite.
+    let mut scan = state.catalog.scan_volume(&vid, &lsns);
     let mut graft = Splinter::default();
-    while let Some((_, segment_graft)) = iter.try_next().or_into_ctx()? {
-        graft.merge(&segment_graft);
+    while let Some((meta, mut segments)) = scan.try_next().or_into_ctx()? {
+        if meta.is_rewrite() && !since_checkpoint {
+            continue;
+        }
+        while let Some((key, segment_graft)) = segments.try_next().or_into_ctx()? {
+            // delta bases don't contain pages visible in the volume
+            if !key.is_delta_base() {
+                graft.merge(&segment_graft);
+            }
+        }
     }
 
     Ok(ProtoResponse::new(PullGraftResponse {
@@ -102,7 +114,7 @@ mod tests {
 
     use axum::{handler::Handler, http::StatusCode};
     use axum_test::TestServer;
-    use graft_core::{SegmentId, gid::ClientId, lsn::LSN, page_count::PageCount};
+    use graft_core::{PageSize, SegmentId, gid::ClientId, lsn::LSN, page_count::PageCount};
     use object_store::memory::InMemory;
     use prost::Message;
 
@@ -157,6 +169,7 @@ mod tests {
                 LSN::new(lsn),
                 LSN::FIRST,
                 PageCount::new(1),
+                PageSize::DEFAULT,
                 SystemTime::now(),
             );
             let mut commit = CommitBuilder::new_with_capacity(meta, 1);
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::SystemTime};
 
 use axum::extract::State;
 use culprit::{Culprit, ResultExt};
@@ -20,14 +20,41 @@ pub async fn handler(
 ) -> Result<ProtoResponse<SnapshotResponse>, ApiErr> {
     let vid: VolumeId = req.vid.try_into()?;
     let lsn: Option<LSN> = req.lsn.map(LSN::try_from).transpose().or_into_ctx()?;
+    let timestamp: Option<SystemTime> = req
+        .timestamp
+        .map(SystemTime::try_from)
+        .transpose()
+        .or_ctx(|_| ApiErrCtx::InvalidRequestBody)?;
 
-    tracing::info!(?vid, ?lsn);
+    tracing::info!(?vid, ?lsn, ?timestamp);
 
-    let snapshot = state
-        .updater
-        .snapshot(&state.store, &state.catalog, &vid, lsn)
-        .await
-        .or_into_ctx()?;
+    let snapshot = if let Some(timestamp) = timestamp {
+        if lsn.is_some() {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::InvalidRequestBody,
+                "snapshot request may not specify both an lsn and a timestamp",
+            )
+            .into());
+        }
+
+        // ensure the catalog contains every commit before searching it
+        state
+            .updater
+            .update_catalog_from_store(&state.store, &state.catalog, &vid, None)
+            .await
+            .or_into_ctx()?;
+        state
+            .catalog
+            .snapshot_at_time(&vid, timestamp)
+            .or_into_ctx()?
+    } else {
+        state
+            .updater
+            .snapshot(&state.store, &state.catalog, &vid, lsn)
+            .await
+            .or_into_ctx()?
+    };
+    state.check_deleted(&vid).await?;
 
     if let Some(snapshot) = snapshot {
         Ok(ProtoResponse::new(SnapshotResponse {
@@ -36,7 +63,7 @@ pub async fn handler(
     } else {
         return Err(Culprit::new_with_note(
             ApiErrCtx::SnapshotMissing,
-            format!("volume {vid} is missing snapshot at {lsn:?}"),
+            format!("volume {vid} is missing snapshot at {lsn:?} (timestamp {timestamp:?})"),
         )
         .into());
     }
@@ -44,11 +71,11 @@ pub async fn handler(
 
 #[cfg(test)]
 mod tests {
-    use std::time::SystemTime;
+    use std::time::Duration;
 
     use axum::{handler::Handler, http::StatusCode};
     use axum_test::TestServer;
-    use graft_core::{SegmentId, gid::ClientId, page_count::PageCount};
+    use graft_core::{PageSize, SegmentId, gid::ClientId, page_count::PageCount};
     use object_store::memory::InMemory;
     use prost::Message;
     use splinter_rs::Splinter;
@@ -89,7 +116,11 @@ mod tests {
         // case 1: catalog and store are empty
 
         // request latest
-        let req = SnapshotRequest { vid: vid.copy_to_bytes(), lsn: None };
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: None,
+            timestamp: None,
+        };
         let resp = server
             .post("/")
             .bytes(req.encode_to_vec().into())
@@ -98,7 +129,11 @@ mod tests {
         assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
 
         // request specific
-        let req = SnapshotRequest { vid: vid.copy_to_bytes(), lsn: Some(10) };
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: Some(10),
+            timestamp: None,
+        };
         let resp = server
             .post("/")
             .bytes(req.encode_to_vec().into())
@@ -113,6 +148,7 @@ mod tests {
             LSN::FIRST,
             LSN::FIRST,
             PageCount::new(1),
+            PageSize::DEFAULT,
             SystemTime::now(),
         );
         let mut commit = CommitBuilder::new_with_capacity(meta, 1);
@@ -123,7 +159,11 @@ mod tests {
         store.commit(commit.build()).await.unwrap();
 
         // request latest
-        let req = SnapshotRequest { vid: vid.copy_to_bytes(), lsn: None };
+        let req = SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: None,
+            timestamp: None,
+        };
         let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
         let resp = SnapshotResponse::decode(resp.into_bytes()).unwrap();
         let snapshot = resp.snapshot.unwrap();
@@ -132,4 +172,92 @@ mod tests {
         assert_eq!(snapshot.pages(), 1);
         assert!(snapshot.timestamp.is_some());
     }
+
+    #[graft_test::test]
+    async fn test_snapshot_at_time() {
+        let store = Arc::new(InMemory::default());
+        let store = Arc::new(VolumeStore::new(store));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let state = Arc::new(MetastoreApiState::new(
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8),
+        ));
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+
+        // write three commits, one minute apart
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(60 * 60);
+        let minutes = |m: u64| start + Duration::from_secs(60 * m);
+        for (lsn, minute) in [(1, 0), (2, 1), (3, 2)] {
+            let meta = CommitMeta::new(
+                vid.clone(),
+                cid.clone(),
+                LSN::new(lsn),
+                LSN::FIRST,
+                PageCount::new(1),
+                PageSize::DEFAULT,
+                minutes(minute),
+            );
+            let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+            commit.write_graft(
+                SegmentId::random(),
+                Splinter::from_slice(&[1]).serialize_to_bytes(),
+            );
+            store.commit(commit.build()).await.unwrap();
+        }
+
+        // checkpoint the volume, which doesn't change its contents
+        let latest = store.get_commit(vid.clone(), LSN::new(3)).await.unwrap();
+        let meta = CommitMeta::rewrite(latest.meta(), LSN::new(4), LSN::new(4), minutes(10));
+        let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+        commit.write_graft(
+            SegmentId::random(),
+            Splinter::from_slice(&[1]).serialize_to_bytes(),
+        );
+        store.commit(commit.build()).await.unwrap();
+
+        let request = |time: SystemTime| SnapshotRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: None,
+            timestamp: Some(time.into()),
+        };
+
+        for (time, expected) in [
+            (minutes(0), 1),
+            (minutes(1) + Duration::from_secs(30), 2),
+            (minutes(2), 3),
+            (minutes(5), 3),
+            // the checkpoint is timestamped when it was created, so restoring
+            // to a time after it returns the last commit
+            (minutes(60), 3),
+        ] {
+            let resp = server
+                .post("/")
+                .bytes(request(time).encode_to_vec().into())
+                .await;
+            let resp = SnapshotResponse::decode(resp.into_bytes()).unwrap();
+            assert_eq!(resp.snapshot.unwrap().lsn().unwrap(), expected);
+        }
+
+        // no snapshot exists before the first commit
+        let resp = server
+            .post("/")
+            .bytes(
+                request(start - Duration::from_secs(1))
+                    .encode_to_vec()
+                    .into(),
+            )
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::NOT_FOUND);
+    }
 }
@@ -0,0 +1,235 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::extract::State;
+use culprit::{Culprit, ResultExt};
+use futures::future::try_join_all;
+use graft_core::{VolumeId, lsn::LSN};
+use graft_proto::{
+    Snapshot,
+    metastore::v1::{WatchRequest, WatchResponse},
+};
+use tokio::time::{Instant, sleep_until};
+
+use crate::api::{
+    error::{ApiErr, ApiErrCtx},
+    extractors::Protobuf,
+    response::ProtoResponse,
+};
+
+use super::MetastoreApiState;
+
+/// The maximum amount of time a watch request may wait for a change
+const MAX_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The maximum number of volumes a single watch request may watch
+const MAX_VOLUMES: usize = 256;
+
+#[tracing::instrument(name = "metastore/v1/watch", skip(state, req))]
+pub async fn handler(
+    State(state): State<Arc<MetastoreApiState>>,
+    Protobuf(req): Protobuf<WatchRequest>,
+) -> Result<ProtoResponse<WatchResponse>, ApiErr> {
+    if req.volumes.is_empty() || req.volumes.len() > MAX_VOLUMES {
+        return Err(Culprit::new_with_note(
+            ApiErrCtx::InvalidRequestBody,
+            format!("watch requests must contain between 1 and {MAX_VOLUMES} volumes"),
+        )
+        .into());
+    }
+    let timeout = Duration::from_millis(req.timeout_ms.into()).min(MAX_TIMEOUT);
+    let mut volumes = Vec::with_capacity(req.volumes.len());
+    for volume in req.volumes {
+        let vid: VolumeId = volume.vid.try_into()?;
+        let lsn: Option<LSN> = volume.lsn.map(LSN::try_from).transpose().or_into_ctx()?;
+        volumes.push((vid, lsn));
+    }
+
+    tracing::info!(num_volumes = volumes.len(), ?timeout);
+
+    // subscribe before checking the catalog so we don't miss any commits
+    let mut commits = state.commits.subscribe();
+
+    let deadline = Instant::now() + timeout;
+    let mut next_refresh = Instant::now();
+    loop {
+        // commits made through this Metastore are in the catalog as soon as
+        // we are notified, but we periodically check the store to discover
+        // commits made through other Metastores
+        let refresh = Instant::now() >= next_refresh;
+        if refresh {
+            next_refresh = Instant::now() + state.watch_refresh_interval;
+        }
+
+        let changed = changed_volumes(&state, &volumes, refresh).await?;
+        if !changed.is_empty() || Instant::now() >= deadline || state.shutdown.is_cancelled() {
+            tracing::debug!(num_changed = changed.len(), "watch complete");
+            return Ok(ProtoResponse::new(WatchResponse { volumes: changed }));
+        }
+
+        tokio::select! {
+            _ = commits.changed() => {}
+            _ = sleep_until(next_refresh.min(deadline)) => {}
+            _ = state.shutdown.cancelled() => {}
+        }
+    }
+}
+
+/// Returns the latest snapshot of every volume which has a commit newer than
+/// the watched LSN
+async fn changed_volumes(
+    state: &MetastoreApiState,
+    volumes: &[(VolumeId, Option<LSN>)],
+    refresh: bool,
+) -> Result<Vec<Snapshot>, ApiErr> {
+    let latest = if refresh {
+        // refresh the volumes concurrently; the updater limits the number of
+        // concurrent updates
+        try_join_all(volumes.iter().map(|(vid, _)| {
+            state
+                .updater
+                .snapshot(&state.store, &state.catalog, vid, None)
+        }))
+        .await
+        .or_into_ctx()?
+    } else {
+        volumes
+            .iter()
+            .map(|(vid, _)| state.catalog.latest_snapshot(vid))
+            .collect::<Result<Vec<_>, _>>()
+            .or_into_ctx()?
+    };
+
+    let changed = volumes
+        .iter()
+        .zip(latest)
+        .filter_map(|((_, lsn), latest)| latest.filter(|s| Some(s.lsn()) > *lsn))
+        .map(|latest| latest.into_snapshot())
+        .collect();
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use axum::http::StatusCode;
+    use axum_test::TestServer;
+    use bytes::Bytes;
+    use graft_core::{PageSize, gid::ClientId, page_count::PageCount};
+    use graft_proto::metastore::v1::{CommitRequest, WatchedVolume};
+    use object_store::memory::InMemory;
+    use prost::Message;
+
+    use crate::{
+        api::{
+            extractors::CONTENT_TYPE_PROTOBUF, metastore::metastore_routes, routes::build_router,
+        },
+        metrics::registry::Registry,
+        volume::{
+            catalog::VolumeCatalog,
+            commit::{CommitBuilder, CommitMeta},
+            store::VolumeStore,
+            updater::VolumeCatalogUpdater,
+        },
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_watch() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let state = Arc::new(
+            MetastoreApiState::new(store.clone(), catalog.clone(), VolumeCatalogUpdater::new(8))
+                .with_watch_refresh_interval(Duration::from_millis(100)),
+        );
+        let router = build_router(Registry::default(), None, state, metastore_routes());
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(router)
+            .unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+        let watch = |lsn: Option<u64>, timeout_ms: u32| WatchRequest {
+            volumes: vec![WatchedVolume { vid: vid.copy_to_bytes(), lsn }],
+            timeout_ms,
+        };
+
+        // watching a volume without commits times out
+        let resp = server
+            .post("/metastore/v1/watch")
+            .bytes(watch(None, 100).encode_to_vec().into())
+            .await;
+        let resp = WatchResponse::decode(resp.into_bytes()).unwrap();
+        assert!(resp.volumes.is_empty());
+
+        // a commit through the metastore wakes up a pending watch
+        let commit = CommitRequest {
+            vid: vid.copy_to_bytes(),
+            cid: cid.copy_to_bytes(),
+            snapshot_lsn: None,
+            page_count: 0,
+            segments: vec![],
+            page_size: 0,
+            commit_token: Bytes::new(),
+        };
+        let (resp, _) = tokio::join!(
+            server
+                .post("/metastore/v1/watch")
+                .bytes(watch(None, 10_000).encode_to_vec().into())
+                .into_future(),
+            async {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                server
+                    .post("/metastore/v1/commit")
+                    .bytes(commit.encode_to_vec().into())
+                    .await
+            }
+        );
+        let resp = WatchResponse::decode(resp.into_bytes()).unwrap();
+        assert_eq!(resp.volumes.len(), 1);
+        assert_eq!(resp.volumes[0].lsn().unwrap(), 1);
+
+        // commits which bypass this metastore are found by refreshing from
+        // the store
+        let meta = CommitMeta::new(
+            vid.clone(),
+            cid.clone(),
+            LSN::new(2),
+            LSN::FIRST,
+            PageCount::ZERO,
+            PageSize::DEFAULT,
+            SystemTime::now(),
+        );
+        store
+            .commit(CommitBuilder::new_with_capacity(meta, 0).build())
+            .await
+            .unwrap();
+        let resp = server
+            .post("/metastore/v1/watch")
+            .bytes(watch(Some(1), 10_000).encode_to_vec().into())
+            .await;
+        let resp = WatchResponse::decode(resp.into_bytes()).unwrap();
+        assert_eq!(resp.volumes[0].lsn().unwrap(), 2);
+
+        // requests may watch a limited number of volumes
+        let req = WatchRequest {
+            volumes: (0..=MAX_VOLUMES)
+                .map(|_| WatchedVolume {
+                    vid: VolumeId::random().copy_to_bytes(),
+                    lsn: None,
+                })
+                .collect(),
+            timeout_ms: 0,
+        };
+        let resp = server
+            .post("/metastore/v1/watch")
+            .bytes(req.encode_to_vec().into())
+            .expect_failure()
+            .await;
+        assert_eq!(resp.status_code(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -1,24 +1,40 @@
 use graft_client::MetastoreClient;
-use graft_core::{PageIdx, VolumeId, page::Page};
-use std::sync::Arc;
-use tokio::sync::{mpsc, oneshot};
+use graft_core::{PageIdx, PageSize, VolumeId, byte_unit::ByteUnit, lsn::LSN, page::Page};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::spawn_blocking,
+};
 
-use axum::routing::post;
+use axum::{extract::DefaultBodyLimit, routing::post};
+use culprit::{Culprit, ResultExt};
+use futures::future::try_join_all;
 
 use crate::{
     limiter::Limiter,
     segment::{
         cache::Cache,
+        compactor::CompactionTrigger,
         loader::SegmentLoader,
+        uploader::StoreSegmentMsg,
         writer::{WritePagesRequest, WritePagesResponse},
     },
-    volume::{catalog::VolumeCatalog, updater::VolumeCatalogUpdater},
+    tenant::{Tenant, TenantLimiter, TenantLimitsConfig},
+    volume::{
+        catalog::VolumeCatalog, deletion::VolumeDeletionChecker, updater::VolumeCatalogUpdater,
+    },
 };
 
-use super::routes::Routes;
+use super::{
+    error::{ApiErr, ApiErrCtx},
+    routes::Routes,
+};
 
 mod read_pages;
+mod read_pages_stream;
+mod replicate_segment;
 mod write_pages;
+mod write_pages_stream;
 
 pub struct PagestoreApiState<C> {
     page_tx: mpsc::Sender<WritePagesRequest>,
@@ -27,6 +43,13 @@ pub struct PagestoreApiState<C> {
     metastore: MetastoreClient,
     updater: VolumeCatalogUpdater,
     volume_write_limiter: Limiter<VolumeId>,
+    compaction: CompactionTrigger,
+    page_deltas: bool,
+    prefetch_budget: ByteUnit,
+    replica_tx: Option<mpsc::Sender<StoreSegmentMsg>>,
+    tenant_limiter: TenantLimiter,
+    stream_idle_timeout: Option<Duration>,
+    deletion_checker: Option<VolumeDeletionChecker>,
 }
 
 impl<C> PagestoreApiState<C> {
@@ -45,20 +68,153 @@ impl<C> PagestoreApiState<C> {
             metastore,
             updater,
             volume_write_limiter: Limiter::new(write_concurrency),
+            compaction: CompactionTrigger::disabled(),
+            page_deltas: false,
+            prefetch_budget: ByteUnit::ZERO,
+            replica_tx: None,
+            tenant_limiter: TenantLimiter::default(),
+            stream_idle_timeout: None,
+            deletion_checker: None,
+        }
+    }
+
+    /// Trigger compactions and checkpoints of the volumes this pagestore
+    /// serves
+    pub fn with_compaction(self, compaction: CompactionTrigger) -> Self {
+        Self { compaction, ..self }
+    }
+
+    /// Allow pages which mostly match their checkpoint version to be stored
+    /// as deltas
+    pub fn with_page_deltas(self, page_deltas: bool) -> Self {
+        Self { page_deltas, ..self }
+    }
+
+    /// Allow read requests to prefetch up to `budget` bytes of additional
+    /// pages
+    pub fn with_prefetch_budget(self, budget: ByteUnit) -> Self {
+        Self { prefetch_budget: budget, ..self }
+    }
+
+    /// Accept segments replicated from peers, sending them to the segment
+    /// uploader
+    pub fn with_replica_tx(self, replica_tx: mpsc::Sender<StoreSegmentMsg>) -> Self {
+        Self { replica_tx: Some(replica_tx), ..self }
+    }
+
+    /// Limit the rate at which each tenant may write pages, along with the
+    /// size of the volumes they write
+    pub fn with_tenant_limits(self, config: TenantLimitsConfig) -> Self {
+        Self {
+            tenant_limiter: TenantLimiter::new(config),
+            ..self
+        }
+    }
+
+    /// Abort streaming writes which don't receive any data for `timeout`
+    pub fn with_stream_idle_timeout(self, timeout: Duration) -> Self {
+        Self {
+            stream_idle_timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Reject reads of volumes which have been deleted, asking the metastore
+    /// about each volume at most once per `interval`
+    pub fn with_deletion_check_interval(self, interval: Duration) -> Self {
+        Self {
+            deletion_checker: Some(VolumeDeletionChecker::new(interval)),
+            ..self
         }
     }
 
+    /// Returns a `VolumeDeleted` error if deletion checks are enabled and the
+    /// metastore reports that the volume has been deleted
+    pub async fn check_deleted(&self, vid: &VolumeId) -> Result<(), ApiErr> {
+        if let Some(checker) = &self.deletion_checker {
+            if checker.is_deleted(&self.metastore, vid).await {
+                return Err(Culprit::new_with_note(
+                    ApiErrCtx::VolumeDeleted,
+                    format!("volume {vid} has been deleted"),
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Compute the size of each volume written by the tenant from the latest
+    /// snapshot of the volume in the metastore. Deleted volumes are forgotten.
+    pub async fn tenant_storage(
+        &self,
+        tenant: &Tenant,
+    ) -> Result<HashMap<VolumeId, ByteUnit>, ApiErr> {
+        let vids = self
+            .catalog
+            .scan_tenant_volumes(tenant.subject())
+            .collect::<Result<Vec<_>, _>>()
+            .or_into_ctx()?;
+
+        // TODO: switch this to an async client once one exists
+        let snapshots = try_join_all(vids.into_iter().map(|vid| {
+            let client = self.metastore.clone();
+            async move {
+                let result = {
+                    let vid = vid.clone();
+                    spawn_blocking(move || client.snapshot(&vid, None))
+                        .await
+                        .expect("spawn_blocking failed")
+                };
+                match result {
+                    Ok(snapshot) => Ok((vid, Some(snapshot))),
+                    Err(err) if err.ctx().is_volume_deleted() => Ok((vid, None)),
+                    Err(err) => Err(err),
+                }
+            }
+        }))
+        .await
+        .or_into_ctx()?;
+
+        let mut volumes = HashMap::new();
+        for (vid, snapshot) in snapshots {
+            match snapshot {
+                Some(Some(snapshot)) => {
+                    let page_size = snapshot.page_size().or_into_ctx()?;
+                    let size = snapshot.pages().to_u32() as u64 * page_size.as_usize() as u64;
+                    volumes.insert(vid, ByteUnit::new(size));
+                }
+                // the volume hasn't been committed to yet
+                Some(None) => {}
+                None => self
+                    .catalog
+                    .remove_tenant_volume(tenant.subject(), &vid)
+                    .or_into_ctx()?,
+            }
+        }
+        Ok(volumes)
+    }
+
     pub async fn write_pages(
         &self,
         vid: VolumeId,
+        page_size: PageSize,
         pages: Vec<(PageIdx, Page)>,
-    ) -> WritePagesResponse {
+        delta_bases: Option<(LSN, HashMap<PageIdx, Page>)>,
+    ) -> Result<WritePagesResponse, ApiErr> {
         let (tx, rx) = oneshot::channel();
+        let mut req = WritePagesRequest::new(vid, page_size, pages, tx);
+        if let Some((checkpoint, bases)) = delta_bases {
+            req = req.with_delta_bases(checkpoint, bases);
+        }
+        // the segment writer stops accepting writes once it starts shutting
+        // down
         self.page_tx
-            .send(WritePagesRequest::new(vid, pages, tx))
+            .send(req)
+            .await
+            .map_err(|_| Culprit::new(ApiErrCtx::ShuttingDown))?;
+        Ok(rx
             .await
-            .unwrap();
-        rx.await.expect("write pages response channel closed")
+            .map_err(|_| Culprit::new(ApiErrCtx::ShuttingDown))?)
     }
 
     pub fn catalog(&self) -> &VolumeCatalog {
@@ -77,9 +233,39 @@ impl<C> PagestoreApiState<C> {
         &self.updater
     }
 
+    pub fn stream_idle_timeout(&self) -> Option<Duration> {
+        self.stream_idle_timeout
+    }
+
     pub fn volume_write_limiter(&self) -> &Limiter<VolumeId> {
         &self.volume_write_limiter
     }
+
+    pub fn compaction(&self) -> &CompactionTrigger {
+        &self.compaction
+    }
+
+    /// returns true if pages may be stored as deltas against their
+    /// checkpoint version
+    pub fn page_deltas(&self) -> bool {
+        self.page_deltas
+    }
+
+    /// the maximum size of the additional pages returned by a read request
+    /// with prefetching enabled
+    pub fn prefetch_budget(&self) -> ByteUnit {
+        self.prefetch_budget
+    }
+
+    /// the channel used to send segments replicated from peers to the
+    /// segment uploader, if this pagestore accepts replicas
+    pub fn replica_tx(&self) -> Option<&mpsc::Sender<StoreSegmentMsg>> {
+        self.replica_tx.as_ref()
+    }
+
+    pub fn tenant_limiter(&self) -> &TenantLimiter {
+        &self.tenant_limiter
+    }
 }
 
 pub fn pagestore_routes<C>() -> Routes<Arc<PagestoreApiState<C>>>
@@ -88,6 +274,19 @@ where
 {
     vec![
         ("/pagestore/v1/read_pages", post(read_pages::handler)),
+        (
+            "/pagestore/v1/read_pages_stream",
+            post(read_pages_stream::handler),
+        ),
         ("/pagestore/v1/write_pages", post(write_pages::handler)),
+        (
+            "/pagestore/v1/replicate_segment",
+            post(replicate_segment::handler)
+                .layer(DefaultBodyLimit::max(replicate_segment::MAX_BODY_SIZE)),
+        ),
+        (
+            "/pagestore/v1/write_pages_stream",
+            post(write_pages_stream::handler),
+        ),
     ]
 }
@@ -2,14 +2,18 @@ use std::sync::Arc;
 
 use axum::{extract::State, response::IntoResponse};
 use culprit::ResultExt;
-use futures::{FutureExt, TryStreamExt, stream::FuturesUnordered};
-use graft_core::{VolumeId, lsn::LSN};
+use futures::future::try_join_all;
+use graft_core::{PageIdx, SegmentId, VolumeId, lsn::LSN};
 use graft_proto::pagestore::v1::{PageAtIdx, ReadPagesRequest, ReadPagesResponse};
-use splinter_rs::{Splinter, ops::Cut};
+use splinter_rs::{
+    Splinter,
+    ops::{Cut, Merge},
+};
 
 use crate::api::error::ApiErrCtx;
 use crate::segment::cache::Cache;
-use crate::segment::closed::ClosedSegment;
+use crate::segment::delta::{SegmentPage, resolve_deltas};
+use crate::volume::commit::CommitMeta;
 
 use crate::api::{error::ApiErr, extractors::Protobuf, response::ProtoResponse};
 
@@ -26,7 +30,7 @@ pub async fn handler<C: Cache>(
 ) -> Result<impl IntoResponse, ApiErr> {
     let vid: VolumeId = req.vid.try_into()?;
     let lsn = LSN::try_from(req.lsn).or_into_ctx()?;
-    let mut graft = Splinter::from_bytes(req.graft).or_into_ctx()?;
+    let graft = Splinter::from_bytes(req.graft).or_into_ctx()?;
     let num_pages = graft.cardinality();
 
     tracing::info!(?vid, ?lsn, num_pages);
@@ -38,10 +42,36 @@ pub async fn handler<C: Cache>(
         return Err(ApiErrCtx::ZeroPageIdx.into());
     }
 
+    let snapshot = prepare_read(&state, &vid, lsn).await?;
+
+    // the number of additional pages which may be returned, limited such that
+    // the response stays within MAX_PAGES
+    let prefetch = if req.prefetch {
+        let budget = state.prefetch_budget().as_u64() as usize / snapshot.page_size().as_usize();
+        budget.min(MAX_PAGES - num_pages)
+    } else {
+        0
+    };
+
+    let pages = read_graft(&state, &vid, &snapshot, graft, prefetch).await?;
+
+    Ok(ProtoResponse::new(ReadPagesResponse { pages }))
+}
+
+/// Update the catalog in preparation for reading the volume at the specified
+/// LSN, returning the snapshot at that LSN. Reads of deleted volumes are
+/// rejected.
+pub(super) async fn prepare_read<C: Cache>(
+    state: &PagestoreApiState<C>,
+    vid: &VolumeId,
+    lsn: LSN,
+) -> Result<CommitMeta, ApiErr> {
+    state.check_deleted(vid).await?;
+
     // ensure we've replayed the catalog up to the requested LSN
     state
         .updater()
-        .update_catalog_from_metastore(state.metastore_client(), state.catalog(), &vid, lsn)
+        .update_catalog_from_metastore(state.metastore_client(), state.catalog(), vid, lsn)
         .await
         .or_into_ctx()?;
 
@@ -51,45 +81,164 @@ pub async fn handler<C: Cache>(
         .snapshot(vid.clone(), lsn)
         .or_into_ctx()?
         .expect("missing snapshot after update");
+
+    Ok(snapshot)
+}
+
+/// Read the pages in the graft from a snapshot prepared by `prepare_read`.
+/// Up to `prefetch` additional pages may be returned from the segments which
+/// are loaded to read the graft.
+pub(super) async fn read_graft<C: Cache>(
+    state: &PagestoreApiState<C>,
+    vid: &VolumeId,
+    snapshot: &CommitMeta,
+    graft: Splinter,
+    prefetch: usize,
+) -> Result<Vec<PageAtIdx>, ApiErr> {
+    let reads = plan_read(state, vid, snapshot, graft, prefetch)?;
     let checkpoint = snapshot.checkpoint();
+    let loaded = try_join_all(
+        reads
+            .into_iter()
+            .map(|read| read.load(state, vid, checkpoint)),
+    )
+    .await?;
+    Ok(loaded.into_iter().flatten().collect())
+}
+
+/// The pages to read from a single segment
+pub(super) struct SegmentRead {
+    lsn: LSN,
+    sid: SegmentId,
+    source_vid: VolumeId,
+    pageidxs: Vec<PageIdx>,
+}
+
+impl SegmentRead {
+    /// Split the read into reads of at most `max_pages` pages
+    pub(super) fn split(self, max_pages: usize) -> Vec<SegmentRead> {
+        self.pageidxs
+            .chunks(max_pages)
+            .map(|pageidxs| SegmentRead {
+                lsn: self.lsn,
+                sid: self.sid.clone(),
+                source_vid: self.source_vid.clone(),
+                pageidxs: pageidxs.to_vec(),
+            })
+            .collect()
+    }
+
+    /// Load the pages from the segment, reconstructing pages stored as deltas
+    /// against the volume's checkpoint
+    pub(super) async fn load<C: Cache>(
+        self,
+        state: &PagestoreApiState<C>,
+        vid: &VolumeId,
+        checkpoint: LSN,
+    ) -> Result<Vec<PageAtIdx>, ApiErr> {
+        let loaded = state
+            .loader()
+            .load_pages(self.sid, &self.source_vid, self.pageidxs)
+            .await
+            .or_into_ctx()?;
+
+        let mut pages = Vec::with_capacity(loaded.len());
+        let mut deltas = vec![];
+        for (pageidx, page) in loaded {
+            match page {
+                SegmentPage::Full(page) => pages.push(PageAtIdx::new(pageidx, page)),
+                SegmentPage::Delta(delta) => deltas.push((self.lsn, pageidx, delta)),
+            }
+        }
 
-    let mut loading = FuturesUnordered::new();
+        let resolved = resolve_deltas(state.catalog(), state.loader(), vid, checkpoint, deltas)
+            .await
+            .or_into_ctx()?;
+        for (pageidx, page) in resolved {
+            pages.push(PageAtIdx::new(pageidx, page));
+        }
+        Ok(pages)
+    }
+}
 
-    let segments = state.catalog().scan_segments(&vid, &(checkpoint..=lsn));
+/// Scan the segments of a snapshot prepared by `prepare_read` to determine
+/// which segment each page in the graft should be read from. Up to `prefetch`
+/// additional pages may be read from the segments which contain the graft.
+pub(super) fn plan_read<C: Cache>(
+    state: &PagestoreApiState<C>,
+    vid: &VolumeId,
+    snapshot: &CommitMeta,
+    mut graft: Splinter,
+    mut prefetch: usize,
+) -> Result<Vec<SegmentRead>, ApiErr> {
+    let checkpoint = snapshot.checkpoint();
+    let page_count = snapshot.page_count();
+    let mut reads = vec![];
+    let mut scanned_segments = 0;
+
+    // pages contained by segments which have already been scanned. Since
+    // segments are scanned from newest to oldest, a page may only be
+    // prefetched from a segment if no newer segment contains it.
+    let mut scanned_pages = Splinter::default();
+
+    // segments older than the newest rewrite have already been compacted, so
+    // they don't count towards requesting compaction
+    let rewrite_lsn = state
+        .catalog()
+        .latest_rewrite(vid, &(checkpoint..=snapshot.lsn()))
+        .or_into_ctx()?
+        .map(|rewrite| rewrite.lsn());
+
+    let segments = state
+        .catalog()
+        .scan_segments(vid, &(checkpoint..=snapshot.lsn()));
     for result in segments {
         let (key, splinter) = result.or_into_ctx()?;
+        if rewrite_lsn.is_none_or(|lsn| key.lsn() >= lsn) {
+            scanned_segments += 1;
+        }
 
         let cut = graft.cut(&splinter);
         if !cut.is_empty() {
-            let sid = key.sid().clone();
-            loading.push(
-                state
-                    .loader()
-                    .load_segment(sid)
-                    .map(move |result| result.map(|segment| (segment, cut)))
-                    .boxed(),
-            );
+            let mut pageidxs = cut
+                .iter()
+                .map(PageIdx::try_from)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // only prefetch from segments which are loaded to read the graft
+            if prefetch > 0 && !pageidxs.is_empty() {
+                let extra = splinter
+                    .iter()
+                    .take_while(|&pageidx| pageidx <= page_count.to_u32())
+                    .filter(|&pageidx| !cut.contains(pageidx) && !scanned_pages.contains(pageidx))
+                    .take(prefetch)
+                    .map(PageIdx::try_from)
+                    .collect::<Result<Vec<_>, _>>()?;
+                prefetch -= extra.len();
+                pageidxs.extend(extra);
+            }
+
+            reads.push(SegmentRead {
+                lsn: key.lsn(),
+                sid: key.sid().clone(),
+                source_vid: key.source_vid().clone(),
+                pageidxs,
+            });
         }
 
         if graft.is_empty() {
             // all pages have been found
             break;
         }
-    }
-
-    let mut result = ReadPagesResponse { pages: Vec::with_capacity(num_pages) };
-    while let Some((segment, cut)) = loading.try_next().await.or_into_ctx()? {
-        let segment = ClosedSegment::from_bytes(&segment).or_into_ctx()?;
-
-        for pageidx in cut.iter() {
-            let page = segment
-                .find_page(&vid, pageidx.try_into()?)
-                .expect("bug: failed to find expected pageidx in segment; index out of sync");
-            result.pages.push(PageAtIdx { pageidx, data: page.into() });
+        if prefetch > 0 {
+            scanned_pages.merge(&splinter);
         }
     }
 
-    Ok(ProtoResponse::new(result))
+    // request compaction if this read had to scan too many segments
+    state.compaction().maybe_compact(vid, scanned_segments);
+
+    Ok(reads)
 }
 
 #[cfg(test)]
@@ -101,7 +250,8 @@ mod tests {
     use bytes::Bytes;
     use graft_client::{MetastoreClient, NetClient};
     use graft_core::{
-        PageIdx,
+        PageIdx, PageSize,
+        byte_unit::ByteUnit,
         gid::{ClientId, SegmentId},
         page::Page,
         page_count::PageCount,
@@ -115,7 +265,10 @@ mod tests {
     use crate::{
         api::extractors::CONTENT_TYPE_PROTOBUF,
         bytes_vec::BytesVec,
-        segment::{cache::mem::MemCache, loader::SegmentLoader, open::OpenSegment},
+        segment::{
+            cache::mem::MemCache, compression::SegmentCompression, loader::SegmentLoader,
+            open::OpenSegment,
+        },
         volume::{catalog::VolumeCatalog, commit::CommitMeta, updater::VolumeCatalogUpdater},
     };
 
@@ -128,7 +281,7 @@ mod tests {
             segment.insert(vid.clone(), off, page).unwrap();
             graft.insert(off.to_u32());
         }
-        let (sid, buf) = segment.serialize();
+        let (sid, buf) = segment.serialize(SegmentCompression::None);
         (sid, buf, graft)
     }
 
@@ -202,17 +355,12 @@ mod tests {
                     lsn,
                     LSN::FIRST,
                     PageCount::new(5),
+                    PageSize::DEFAULT,
                     SystemTime::now(),
                 ),
                 vec![
-                    SegmentInfo {
-                        sid: sid1.copy_to_bytes(),
-                        graft: graft1.serialize_to_bytes(),
-                    },
-                    SegmentInfo {
-                        sid: sid2.copy_to_bytes(),
-                        graft: graft2.serialize_to_bytes(),
-                    },
+                    SegmentInfo::new(&sid1, graft1.serialize_to_bytes()),
+                    SegmentInfo::new(&sid2, graft2.serialize_to_bytes()),
                 ],
             )
             .unwrap();
@@ -223,6 +371,7 @@ mod tests {
             vid: vid.copy_to_bytes(),
             lsn: lsn.into(),
             graft: (1u32..=5).collect::<Splinter>().serialize_to_bytes(),
+            prefetch: false,
         };
         let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
         if resp.status_code() != 200 {
@@ -245,4 +394,104 @@ mod tests {
             );
         }
     }
+
+    #[graft_test::test]
+    async fn test_read_pages_prefetch() {
+        let store = Arc::new(InMemory::default());
+        let cache = Arc::new(MemCache::default());
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+        let loader = SegmentLoader::new(store.clone(), cache.clone(), 8);
+
+        let (page_tx, _) = mpsc::channel(128);
+
+        let client = NetClient::new(None);
+        let metastore_uri = "http://localhost:3000".parse().unwrap();
+
+        let state = Arc::new(
+            PagestoreApiState::new(
+                page_tx,
+                catalog.clone(),
+                loader,
+                MetastoreClient::new(metastore_uri, client),
+                VolumeCatalogUpdater::new(10),
+                10,
+            )
+            .with_prefetch_budget(ByteUnit::from_kb(64)),
+        );
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+
+        // the first commit writes pages 1-4, and the second overwrites page 2
+        // and truncates the volume to 3 pages
+        let (sid1, segment1, graft1) = mksegment(
+            &vid,
+            (1u32..=4)
+                .map(|i| (PageIdx::try_from(i).unwrap(), Page::test_filled(i as u8)))
+                .collect(),
+        );
+        let (sid2, segment2, graft2) = mksegment(&vid, vec![(pageidx!(2), Page::test_filled(20))]);
+        cache.put(&sid1, segment1).await.unwrap();
+        cache.put(&sid2, segment2).await.unwrap();
+
+        let mut batch = catalog.batch_insert();
+        for (lsn, pages, sid, graft) in [
+            (LSN::new(1), 4, sid1, graft1),
+            (LSN::new(2), 3, sid2, graft2),
+        ] {
+            batch
+                .insert_snapshot(
+                    vid.clone(),
+                    CommitMeta::new(
+                        vid.clone(),
+                        cid.clone(),
+                        lsn,
+                        LSN::FIRST,
+                        PageCount::new(pages),
+                        PageSize::DEFAULT,
+                        SystemTime::now(),
+                    ),
+                    vec![SegmentInfo::new(&sid, graft.serialize_to_bytes())],
+                )
+                .unwrap();
+        }
+        batch.commit().unwrap();
+
+        let read = async |graft: Splinter, prefetch: bool| {
+            let req = ReadPagesRequest {
+                vid: vid.copy_to_bytes(),
+                lsn: LSN::new(2).into(),
+                graft: graft.serialize_to_bytes(),
+                prefetch,
+            };
+            let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
+            let mut resp = ReadPagesResponse::decode(resp.into_bytes()).unwrap();
+            resp.pages.sort_by_key(|p| p.pageidx);
+            resp.pages
+                .into_iter()
+                .map(|p| (p.pageidx, p.data))
+                .collect::<Vec<_>>()
+        };
+
+        // without prefetching only the requested page is returned
+        let pages = read(Splinter::from_iter([1u32]), false).await;
+        assert_eq!(pages, vec![(1, Bytes::from(Page::test_filled(1)))]);
+
+        // prefetching skips page 2 as a newer version exists in a segment
+        // which wasn't loaded, and page 4 which was truncated
+        let pages = read(Splinter::from_iter([1u32]), true).await;
+        assert_eq!(
+            pages,
+            vec![
+                (1, Bytes::from(Page::test_filled(1))),
+                (3, Bytes::from(Page::test_filled(3))),
+            ]
+        );
+    }
 }
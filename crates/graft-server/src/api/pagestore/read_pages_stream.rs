@@ -0,0 +1,203 @@
+use std::{io, sync::Arc};
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use bytes::Bytes;
+use culprit::ResultExt;
+use futures::{StreamExt, stream};
+use graft_core::{VolumeId, lsn::LSN};
+use graft_proto::pagestore::v1::{ReadPagesRequest, ReadPagesResponse};
+use prost::Message;
+use splinter_rs::Splinter;
+
+use crate::api::{
+    error::{ApiErr, ApiErrCtx},
+    extractors::{CONTENT_TYPE_PROTOBUF, Protobuf},
+};
+use crate::segment::cache::Cache;
+
+use super::{
+    PagestoreApiState,
+    read_pages::{MAX_PAGES, plan_read, prepare_read},
+};
+
+/// The number of segments which are read concurrently
+const READ_CONCURRENCY: usize = 8;
+
+/// Read an arbitrarily large graft from a volume. The segments containing the
+/// graft are scanned once, and then the pages in each segment are read
+/// concurrently. The response is a stream of length delimited
+/// `ReadPagesResponse` frames, each containing at most `MAX_PAGES` pages from
+/// a single segment. Errors which occur after the first frame has been sent
+/// abort the response.
+#[tracing::instrument(name = "pagestore/v1/read_pages_stream", skip(state, req))]
+pub async fn handler<C: Cache + Send + Sync + 'static>(
+    State(state): State<Arc<PagestoreApiState<C>>>,
+    Protobuf(req): Protobuf<ReadPagesRequest>,
+) -> Result<impl IntoResponse, ApiErr> {
+    let vid: VolumeId = req.vid.try_into()?;
+    let lsn = LSN::try_from(req.lsn).or_into_ctx()?;
+    let graft = Splinter::from_bytes(req.graft).or_into_ctx()?;
+
+    tracing::info!(?vid, ?lsn, num_pages = graft.cardinality());
+
+    if graft.contains(0) {
+        return Err(ApiErrCtx::ZeroPageIdx.into());
+    }
+
+    let snapshot = prepare_read(&state, &vid, lsn).await?;
+    let checkpoint = snapshot.checkpoint();
+
+    // determine which segment each page is read from
+    let reads = plan_read(&state, &vid, &snapshot, graft, 0)?
+        .into_iter()
+        .flat_map(|read| read.split(MAX_PAGES));
+
+    let frames = stream::iter(reads)
+        .map(move |read| {
+            let state = state.clone();
+            let vid = vid.clone();
+            async move {
+                let pages = read.load(&state, &vid, checkpoint).await.map_err(|err| {
+                    tracing::error!("failed to read pages from volume {vid:?}: {err:?}");
+                    io::Error::other(format!("{err:?}"))
+                })?;
+                let frame = ReadPagesResponse { pages }.encode_length_delimited_to_vec();
+                Ok::<_, io::Error>(Bytes::from(frame))
+            }
+        })
+        .buffer_unordered(READ_CONCURRENCY);
+
+    Ok(Response::builder()
+        .header(header::CONTENT_TYPE, CONTENT_TYPE_PROTOBUF)
+        .body(Body::from_stream(frames))
+        .expect("valid response"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use axum::handler::Handler;
+    use axum_test::TestServer;
+    use bytes::Buf;
+    use graft_client::{MetastoreClient, NetClient};
+    use graft_core::{PageIdx, PageSize, gid::ClientId, page::Page, page_count::PageCount};
+    use graft_proto::common::v1::SegmentInfo;
+    use object_store::{ObjectStore, PutPayload, memory::InMemory, path::Path};
+    use tokio::sync::mpsc;
+
+    use crate::{
+        segment::{
+            cache::mem::MemCache, compression::SegmentCompression, loader::SegmentLoader,
+            open::OpenSegment,
+        },
+        volume::{catalog::VolumeCatalog, commit::CommitMeta, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_read_pages_stream() {
+        let store = Arc::new(InMemory::default());
+        let cache = Arc::new(MemCache::default());
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+        let loader = SegmentLoader::new(store.clone(), cache.clone(), 8);
+
+        let (page_tx, _) = mpsc::channel(128);
+
+        let client = NetClient::new(None);
+        let metastore_uri = "http://localhost:3000".parse().unwrap();
+
+        let state = Arc::new(PagestoreApiState::new(
+            page_tx,
+            catalog.clone(),
+            loader,
+            MetastoreClient::new(metastore_uri, client),
+            VolumeCatalogUpdater::new(10),
+            10,
+        ));
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .expect_success_by_default()
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        // write a volume containing more pages than fit in a single response
+        let lsn = LSN::new(1);
+        let vid = VolumeId::random();
+        let num_pages = 2 * MAX_PAGES as u32 + 100;
+        let mut segments = vec![];
+        // the first segment contains more than MAX_PAGES pages
+        for start in (1..=num_pages).step_by(1500) {
+            let mut segment = OpenSegment::default();
+            let mut graft = Splinter::default();
+            for pageidx in start..(start + 1500).min(num_pages + 1) {
+                let page = Page::test_filled(pageidx as u8);
+                segment
+                    .insert(vid.clone(), PageIdx::new(pageidx), page)
+                    .unwrap();
+                graft.insert(pageidx);
+            }
+            let (sid, buf) = segment.serialize(SegmentCompression::None);
+            store
+                .put(
+                    &Path::from(sid.pretty()),
+                    PutPayload::from_iter(buf.iter().cloned()),
+                )
+                .await
+                .unwrap();
+            segments.push(SegmentInfo::new(&sid, graft.serialize_to_bytes()));
+        }
+
+        let mut batch = catalog.batch_insert();
+        batch
+            .insert_snapshot(
+                vid.clone(),
+                CommitMeta::new(
+                    vid.clone(),
+                    ClientId::random(),
+                    lsn,
+                    LSN::FIRST,
+                    PageCount::new(num_pages),
+                    PageSize::DEFAULT,
+                    SystemTime::now(),
+                ),
+                segments,
+            )
+            .unwrap();
+        batch.commit().unwrap();
+
+        let req = ReadPagesRequest {
+            vid: vid.copy_to_bytes(),
+            lsn: lsn.into(),
+            graft: (1..=num_pages).collect::<Splinter>().serialize_to_bytes(),
+            prefetch: false,
+        };
+        let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
+        let mut body = resp.into_bytes();
+
+        // every page is returned in a frame per segment of at most MAX_PAGES
+        // pages
+        let mut pages = vec![];
+        let mut frames = 0;
+        while body.has_remaining() {
+            let frame = ReadPagesResponse::decode_length_delimited(&mut body).unwrap();
+            assert!(frame.pages.len() <= MAX_PAGES);
+            pages.extend(frame.pages);
+            frames += 1;
+        }
+        assert_eq!(frames, 3);
+        assert_eq!(pages.len(), num_pages as usize);
+        pages.sort_by_key(|p| p.pageidx);
+        for (page, expected) in pages.into_iter().zip(1..) {
+            assert_eq!(page.pageidx, expected);
+            assert_eq!(page.data, Bytes::from(Page::test_filled(expected as u8)));
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use axum::{extract::State, response::IntoResponse};
+use culprit::{Culprit, ResultExt};
+use event_listener::Event;
+use graft_proto::pagestore::v1::{ReplicateSegmentRequest, ReplicateSegmentResponse};
+
+use crate::api::{
+    auth::RequireService,
+    error::{ApiErr, ApiErrCtx},
+    extractors::Protobuf,
+    response::ProtoResponse,
+};
+use crate::segment::{
+    cache::Cache,
+    closed::{ClosedSegment, SEGMENT_MAX_SIZE},
+    uploader::StoreSegmentMsg,
+};
+
+use super::PagestoreApiState;
+
+/// Replicated segments may be larger than the default request body limit, so
+/// the limit is raised to fit the largest possible segment
+pub(super) const MAX_BODY_SIZE: usize = SEGMENT_MAX_SIZE.as_usize() + 1024;
+
+/// Accept a copy of a segment from a peer pagestore. Responds once this
+/// pagestore has pinned a copy of the segment in its cache, which it then
+/// uploads to object storage independently of the peer. Only accepted from
+/// other Graft services, as replicas are uploaded as is.
+#[tracing::instrument(name = "pagestore/v1/replicate_segment", skip(_service, state, req))]
+pub async fn handler<C: Cache>(
+    State(state): State<Arc<PagestoreApiState<C>>>,
+    _service: RequireService,
+    Protobuf(req): Protobuf<ReplicateSegmentRequest>,
+) -> Result<impl IntoResponse, ApiErr> {
+    let Some(replica_tx) = state.replica_tx() else {
+        return Err(Culprit::new(ApiErrCtx::ReplicationDisabled).into());
+    };
+
+    // validate the segment before accepting it, as it will be uploaded as is
+    let segment = ClosedSegment::from_bytes(&req.segment).or_into_ctx()?;
+    segment.verify_checksum().or_into_ctx()?;
+    let sid = segment.sid().clone();
+
+    tracing::info!(?sid, pages = segment.pages().to_usize());
+
+    let event = Event::with_tag();
+    let listener = event.listen();
+    replica_tx
+        .send(StoreSegmentMsg::replica(sid, req.segment, event))
+        .await
+        .map_err(|_| {
+            Culprit::new_with_note(
+                ApiErrCtx::SegmentUploadErr,
+                "segment uploader has shut down",
+            )
+        })?;
+    listener.await?;
+
+    Ok(ProtoResponse::new(ReplicateSegmentResponse {}))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::handler::Handler;
+    use axum_test::TestServer;
+    use graft_client::{MetastoreClient, NetClient};
+    use graft_core::{VolumeId, page::Page, pageidx};
+    use object_store::{memory::InMemory, path::Path};
+    use prost::Message;
+    use tokio::sync::mpsc;
+
+    use crate::{
+        api::extractors::CONTENT_TYPE_PROTOBUF,
+        segment::{
+            cache::mem::MemCache,
+            compression::SegmentCompression,
+            loader::SegmentLoader,
+            open::OpenSegment,
+            uploader::{SegmentUploaderTask, testonly_wait_for_upload},
+        },
+        supervisor::SupervisedTask,
+        volume::{catalog::VolumeCatalog, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_replicate_segment() {
+        let store = Arc::new(InMemory::default());
+        let cache = Arc::new(MemCache::default());
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+        let loader = SegmentLoader::new(store.clone(), cache.clone(), 8);
+
+        let (page_tx, _) = mpsc::channel(128);
+        let (_store_tx, store_rx) = mpsc::channel(8);
+        let (replica_tx, replica_rx) = mpsc::channel(8);
+
+        SegmentUploaderTask::new(
+            Default::default(),
+            store_rx,
+            store.clone(),
+            cache.clone(),
+            SegmentCompression::None,
+        )
+        .with_replicas(replica_rx)
+        .testonly_spawn();
+
+        let client = NetClient::new(None);
+        let metastore_uri = "http://localhost:3000".parse().unwrap();
+
+        let state = Arc::new(
+            PagestoreApiState::new(
+                page_tx,
+                catalog,
+                loader,
+                MetastoreClient::new(metastore_uri, client),
+                VolumeCatalogUpdater::new(10),
+                10,
+            )
+            .with_replica_tx(replica_tx),
+        );
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        let mut segment = OpenSegment::default();
+        segment
+            .insert(VolumeId::random(), pageidx!(1), Page::test_filled(1))
+            .unwrap();
+        let (sid, segment) = segment.serialize(SegmentCompression::Zstd);
+        let segment = segment.into_bytes();
+
+        // the replica is cached before the peer is acknowledged
+        let req = ReplicateSegmentRequest { segment: segment.clone() };
+        let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
+        resp.assert_status_ok();
+        assert_eq!(&*cache.get(&sid).await.unwrap().unwrap(), &segment[..]);
+
+        // and then uploaded in the background
+        let path = Path::from(sid.pretty());
+        let uploaded = testonly_wait_for_upload(&*store, &path).await;
+        assert_eq!(uploaded, segment);
+
+        // corrupt segments are rejected
+        let mut corrupt = segment.to_vec();
+        corrupt[0] ^= 0xFF;
+        let req = ReplicateSegmentRequest { segment: corrupt.into() };
+        let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
+        resp.assert_status_failure();
+    }
+}
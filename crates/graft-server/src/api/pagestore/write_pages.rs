@@ -1,43 +1,140 @@
 use std::sync::Arc;
 
-use axum::{extract::State, response::IntoResponse};
+use axum::{Extension, extract::State, response::IntoResponse};
 use culprit::{Culprit, ResultExt};
 use futures::future::try_join_all;
-use graft_core::{VolumeId, page::Page};
+use graft_core::{
+    PageIdx, PageSize, VolumeId,
+    byte_unit::ByteUnit,
+    lsn::LSN,
+    page::{Page, PageSizeErr},
+};
 use graft_proto::{
     common::v1::SegmentInfo,
-    pagestore::v1::{WritePagesRequest, WritePagesResponse},
+    pagestore::v1::{PageAtIdx, WritePagesRequest, WritePagesResponse},
 };
-use hashbrown::HashSet;
+use splinter_rs::Splinter;
 
 use crate::api::{error::ApiErrCtx, response::ProtoResponse};
+use crate::segment::{
+    cache::Cache, closed::segment_max_pages, delta::load_delta_bases,
+    uploader::SegmentUploadListener,
+};
+use crate::tenant::{Tenant, TenantWrite};
 
 use crate::api::{error::ApiErr, extractors::Protobuf};
 
 use super::PagestoreApiState;
 
 #[tracing::instrument(name = "pagestore/v1/write_pages", skip(state, req))]
-pub async fn handler<C>(
+pub async fn handler<C: Cache>(
     State(state): State<Arc<PagestoreApiState<C>>>,
+    tenant: Option<Extension<Tenant>>,
     Protobuf(req): Protobuf<WritePagesRequest>,
 ) -> Result<impl IntoResponse, ApiErr> {
     let vid: VolumeId = req.vid.try_into()?;
-    let expected_pages = req.pages.len();
+    let snapshot_lsn: Option<LSN> = req
+        .snapshot_lsn
+        .map(LSN::try_from)
+        .transpose()
+        .or_into_ctx()?;
 
     // acquire a permit to write to the volume.
     // This permit is critical as it ensures that no other write_pages handler
     // can concurrently write pages into this volume.
     let _permit = state.volume_write_limiter().acquire(&vid).await;
 
-    tracing::info!(?vid, expected_pages);
+    tracing::info!(?vid, ?snapshot_lsn, expected_pages = req.pages.len());
 
-    let mut seen = HashSet::with_capacity(req.pages.len());
-    let mut pages = Vec::with_capacity(req.pages.len());
+    let tenant = tenant.map(|Extension(tenant)| tenant).unwrap_or_default();
+    let mut writer = VolumeWriter::new(&state, tenant, vid, snapshot_lsn);
     for page in req.pages {
+        writer.push(page)?;
+    }
+    let segments = writer.finish().await?;
+
+    Ok(ProtoResponse::new(WritePagesResponse { segments }))
+}
+
+/// Validates the pages written to a volume and sends them to the segment
+/// writer in batches, collecting the segments they are written to. The
+/// tenant's limits are enforced before the first batch is sent, and the
+/// remaining batches are charged against them without being rejected.
+pub(super) struct VolumeWriter<'a, C> {
+    state: &'a PagestoreApiState<C>,
+    tenant: Tenant,
+    /// set once the first batch has been admitted
+    admitted: bool,
+    vid: VolumeId,
+    snapshot_lsn: Option<LSN>,
+
+    /// the checkpoint pages may be stored as deltas against, resolved on the
+    /// first flush
+    checkpoint: Option<Option<LSN>>,
+
+    page_size: Option<PageSize>,
+    /// set once the page size has been validated against the volume
+    page_size_checked: bool,
+    /// the number of pages in the volume, including the pages written so far
+    volume_pages: u32,
+    seen: Splinter,
+    pages: Vec<(PageIdx, Page)>,
+
+    expected_pages: usize,
+    received_pages: usize,
+    segments: Vec<SegmentInfo>,
+    events: Vec<SegmentUploadListener>,
+}
+
+impl<'a, C: Cache> VolumeWriter<'a, C> {
+    pub(super) fn new(
+        state: &'a PagestoreApiState<C>,
+        tenant: Tenant,
+        vid: VolumeId,
+        snapshot_lsn: Option<LSN>,
+    ) -> Self {
+        Self {
+            state,
+            tenant,
+            admitted: false,
+            vid,
+            snapshot_lsn,
+            checkpoint: None,
+            page_size: None,
+            page_size_checked: false,
+            volume_pages: 0,
+            seen: Splinter::default(),
+            pages: Vec::new(),
+            expected_pages: 0,
+            received_pages: 0,
+            segments: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+
+    pub(super) fn vid(&self) -> &VolumeId {
+        &self.vid
+    }
+
+    /// Add a page to the current batch
+    pub(super) fn push(&mut self, page: PageAtIdx) -> Result<(), ApiErr> {
         let pageidx = page.pageidx().or_into_ctx()?;
         let page: Page = Page::try_from(page.data).or_into_ctx()?;
 
-        if !seen.insert(pageidx) {
+        // every page written to a volume must be the same size
+        let expected = *self.page_size.get_or_insert(page.size());
+        if page.size() != expected {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::PageSizeErr(PageSizeErr),
+                format!(
+                    "page {pageidx} has size {}; expected {expected}",
+                    page.size()
+                ),
+            )
+            .into());
+        }
+
+        if !self.seen.insert(pageidx.to_u32()) {
             return Err(Culprit::new_with_note(
                 ApiErrCtx::DuplicatePageIdx,
                 format!("page index: {pageidx}"),
@@ -45,43 +142,229 @@ pub async fn handler<C>(
             .into());
         }
 
-        pages.push((pageidx, page));
+        self.expected_pages += 1;
+        self.volume_pages = self.volume_pages.max(pageidx.to_u32());
+        self.pages.push((pageidx, page));
+        Ok(())
     }
 
-    // send pages to the writer and process results
-    let response = state.write_pages(vid.clone(), pages).await;
-    let mut segments: Vec<SegmentInfo> = Vec::with_capacity(response.len());
-    let mut events = Vec::with_capacity(response.len());
-    let mut received_pages = 0;
-
-    for (sid, graft, event) in response {
-        tracing::trace!("write_pages handler received segment {sid} for volume {vid}",);
-        let sid = sid.copy_to_bytes();
-        received_pages += graft.cardinality();
-        let graft = graft.serialize_to_bytes();
-        segments.push(SegmentInfo { sid, graft });
-        events.push(event);
+    /// Returns true once the current batch can fill an entire segment
+    pub(super) fn batch_full(&self) -> bool {
+        self.page_size
+            .is_some_and(|page_size| self.pages.len() >= segment_max_pages(page_size).to_usize())
     }
 
-    // wait for all segments to be written
-    try_join_all(events).await?;
+    /// Send the current batch of pages to the segment writer
+    pub(super) async fn flush(&mut self) -> Result<(), ApiErr> {
+        // the page size is set by the first page pushed to the writer
+        let Some(page_size) = self.page_size else {
+            return Ok(());
+        };
+        if self.pages.is_empty() {
+            return Ok(());
+        }
+        self.check_page_size(page_size).await?;
+        self.admit(page_size).await?;
+        let pages = std::mem::take(&mut self.pages);
+
+        // load the checkpoint version of each page, allowing the writer to
+        // store pages which mostly match it as deltas
+        let delta_bases = match self.checkpoint().await? {
+            Some(checkpoint) => {
+                let graft = Splinter::from_iter(pages.iter().map(|(idx, _)| idx.to_u32()));
+                let bases = load_delta_bases(
+                    self.state.catalog(),
+                    self.state.loader(),
+                    &self.vid,
+                    checkpoint,
+                    graft,
+                )
+                .await
+                .or_into_ctx()?;
+                Some((checkpoint, bases))
+            }
+            None => None,
+        };
 
-    assert_eq!(
-        received_pages, expected_pages,
-        "expected {expected_pages} pages, but got {received_pages}"
-    );
+        // send pages to the writer and process results
+        let response = self
+            .state
+            .write_pages(self.vid.clone(), page_size, pages, delta_bases)
+            .await?;
+        let delta_checkpoint = response.delta_checkpoint().map(u64::from);
+        for (sid, graft, event) in response {
+            tracing::trace!(
+                "write_pages handler received segment {sid} for volume {:?}",
+                self.vid
+            );
+            self.received_pages += graft.cardinality();
+            self.segments.push(SegmentInfo {
+                sid: sid.copy_to_bytes(),
+                graft: graft.serialize_to_bytes(),
+                delta_checkpoint,
+                ..Default::default()
+            });
+            self.events.push(event);
+        }
+        Ok(())
+    }
 
-    Ok(ProtoResponse::new(WritePagesResponse { segments }))
+    /// Flush any remaining pages and wait for every segment to be written
+    pub(super) async fn finish(mut self) -> Result<Vec<SegmentInfo>, ApiErr> {
+        self.flush().await?;
+
+        // wait for all segments to be written
+        try_join_all(self.events).await?;
+
+        assert_eq!(
+            self.received_pages, self.expected_pages,
+            "expected {} pages, but got {}",
+            self.expected_pages, self.received_pages
+        );
+
+        // request a checkpoint once the volume has accumulated too many
+        // commits. Only the local catalog is consulted to keep writes fast.
+        let snapshot = match self.snapshot_lsn {
+            Some(lsn) => self
+                .state
+                .catalog()
+                .snapshot(self.vid.clone(), lsn)
+                .or_into_ctx()?,
+            None => None,
+        };
+        if let Some(snapshot) = snapshot {
+            self.state
+                .compaction()
+                .maybe_checkpoint(&self.vid, &snapshot);
+        }
+
+        Ok(self.segments)
+    }
+
+    /// Check the current batch against the tenant's limits. Only the first
+    /// batch may be rejected, so that a write isn't rejected after some of
+    /// its pages have been sent to the segment writer.
+    async fn admit(&mut self, page_size: PageSize) -> Result<(), ApiErr> {
+        let page_bytes = page_size.as_usize() as u64;
+        let write = TenantWrite {
+            vid: &self.vid,
+            pages: self.pages.len(),
+            bytes: ByteUnit::new(self.pages.len() as u64 * page_bytes),
+            volume_size: ByteUnit::new(self.volume_pages as u64 * page_bytes),
+        };
+        let limiter = self.state.tenant_limiter();
+        if self.admitted {
+            limiter.charge(&self.tenant, &write);
+        } else {
+            if limiter.needs_storage(&self.tenant) {
+                let storage = self.state.tenant_storage(&self.tenant).await?;
+                limiter.set_storage(&self.tenant, storage);
+            }
+            limiter.admit(&self.tenant, &write).or_into_ctx()?;
+            if limiter.has_storage_quota(&self.tenant) {
+                // the volume counts towards the tenant's usage from now on
+                self.state
+                    .catalog()
+                    .add_tenant_volume(self.tenant.subject(), &self.vid)
+                    .or_into_ctx()?;
+            }
+            self.admitted = true;
+        }
+        Ok(())
+    }
+
+    /// Ensure that pages match the page size of the volume they are written
+    /// to. The page size of a volume never changes, so any snapshot of the
+    /// volume can be used. New volumes may use any page size.
+    async fn check_page_size(&mut self, page_size: PageSize) -> Result<(), ApiErr> {
+        if self.page_size_checked {
+            return Ok(());
+        }
+        let state = self.state;
+        let vid = &self.vid;
+        let mut snapshot = state.catalog().latest_snapshot(vid).or_into_ctx()?;
+        if snapshot.is_none() {
+            if let Some(lsn) = self.snapshot_lsn {
+                state
+                    .updater()
+                    .update_catalog_from_metastore(
+                        state.metastore_client(),
+                        state.catalog(),
+                        vid,
+                        lsn,
+                    )
+                    .await
+                    .or_into_ctx()?;
+                snapshot = state.catalog().latest_snapshot(vid).or_into_ctx()?;
+            }
+        }
+        if let Some(snapshot) = snapshot {
+            // the tenant is charged for the existing pages in the volume
+            self.volume_pages = self.volume_pages.max(snapshot.page_count().to_u32());
+            if snapshot.page_size() != page_size {
+                return Err(Culprit::new_with_note(
+                    ApiErrCtx::PageSizeErr(PageSizeErr),
+                    format!(
+                        "volume {vid} has page size {}; got pages of size {page_size}",
+                        snapshot.page_size()
+                    ),
+                )
+                .into());
+            }
+        }
+        self.page_size_checked = true;
+        Ok(())
+    }
+
+    /// Resolve the checkpoint of the snapshot the pages will be committed on
+    /// top of, if pages may be stored as deltas
+    async fn checkpoint(&mut self) -> Result<Option<LSN>, ApiErr> {
+        if let Some(checkpoint) = self.checkpoint {
+            return Ok(checkpoint);
+        }
+        let checkpoint = match self.snapshot_lsn {
+            Some(lsn) if self.state.page_deltas() => {
+                let state = self.state;
+                let vid = &self.vid;
+                state
+                    .updater()
+                    .update_catalog_from_metastore(
+                        state.metastore_client(),
+                        state.catalog(),
+                        vid,
+                        lsn,
+                    )
+                    .await
+                    .or_into_ctx()?;
+                let snapshot = state.catalog().snapshot(vid.clone(), lsn).or_into_ctx()?;
+                let Some(snapshot) = snapshot else {
+                    return Err(Culprit::new_with_note(
+                        ApiErrCtx::SnapshotMissing,
+                        format!("volume {vid} is missing snapshot at {lsn}"),
+                    )
+                    .into());
+                };
+                Some(snapshot.checkpoint())
+            }
+            _ => None,
+        };
+        self.checkpoint = Some(checkpoint);
+        Ok(checkpoint)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{future::IntoFuture, time::Duration};
+    use std::{
+        future::IntoFuture,
+        time::{Duration, SystemTime},
+    };
 
     use axum::handler::Handler;
     use axum_test::TestServer;
     use bytes::Bytes;
     use graft_client::{MetastoreClient, NetClient};
+    use graft_core::{gid::ClientId, page_count::PageCount};
     use graft_proto::pagestore::v1::PageAtIdx;
     use object_store::memory::InMemory;
     use prost::Message;
@@ -91,11 +374,12 @@ mod tests {
     use crate::{
         api::extractors::CONTENT_TYPE_PROTOBUF,
         segment::{
-            cache::mem::MemCache, loader::SegmentLoader, uploader::SegmentUploaderTask,
-            writer::SegmentWriterTask,
+            cache::mem::MemCache, compression::SegmentCompression, loader::SegmentLoader,
+            uploader::SegmentUploaderTask, writer::SegmentWriterTask,
         },
         supervisor::SupervisedTask,
-        volume::{catalog::VolumeCatalog, updater::VolumeCatalogUpdater},
+        tenant::{TenantLimits, TenantLimitsConfig},
+        volume::{catalog::VolumeCatalog, commit::CommitMeta, updater::VolumeCatalogUpdater},
     };
 
     use super::*;
@@ -118,8 +402,14 @@ mod tests {
         )
         .testonly_spawn();
 
-        SegmentUploaderTask::new(Default::default(), store_rx, store.clone(), cache.clone())
-            .testonly_spawn();
+        SegmentUploaderTask::new(
+            Default::default(),
+            store_rx,
+            store.clone(),
+            cache.clone(),
+            SegmentCompression::None,
+        )
+        .testonly_spawn();
 
         let client = NetClient::new(None);
         let metastore_uri = "http://localhost:3000".parse().unwrap();
@@ -145,6 +435,7 @@ mod tests {
         let req1 = WritePagesRequest {
             vid: VolumeId::random().copy_to_bytes(),
             pages: vec![PageAtIdx { pageidx: 1, data: page.clone() }],
+            snapshot_lsn: None,
         };
 
         let req2 = WritePagesRequest {
@@ -153,6 +444,7 @@ mod tests {
                 PageAtIdx { pageidx: 1, data: page.clone() },
                 PageAtIdx { pageidx: 2, data: page.clone() },
             ],
+            snapshot_lsn: None,
         };
 
         let local = tokio::task::LocalSet::new();
@@ -186,4 +478,113 @@ mod tests {
         assert!(graft.contains(1));
         assert!(graft.contains(2));
     }
+
+    #[graft_test::test]
+    async fn test_write_pages_tenant_limits() {
+        let store = Arc::new(InMemory::default());
+        let cache = Arc::new(MemCache::default());
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+        let loader = SegmentLoader::new(store, cache, 8);
+        let (page_tx, _) = mpsc::channel(128);
+
+        let client = NetClient::new(None);
+        let metastore_uri = "http://localhost:3000".parse().unwrap();
+
+        let state = Arc::new(
+            PagestoreApiState::new(
+                page_tx,
+                catalog,
+                loader,
+                MetastoreClient::new(metastore_uri, client),
+                VolumeCatalogUpdater::new(10),
+                10,
+            )
+            .with_tenant_limits(TenantLimitsConfig {
+                default: TenantLimits {
+                    max_storage: Some(ByteUnit::from_kb(4)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        );
+
+        // the auth layer identifies the tenant making the request
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .build(
+                handler
+                    .layer(Extension(Tenant::new("tenant")))
+                    .with_state(state)
+                    .into_make_service(),
+            )
+            .unwrap();
+
+        // writing the second page would grow the tenant beyond its quota
+        let page: Bytes = Page::test_filled(1).into();
+        let req = WritePagesRequest {
+            vid: VolumeId::random().copy_to_bytes(),
+            pages: vec![PageAtIdx { pageidx: 2, data: page }],
+            snapshot_lsn: None,
+        };
+        let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
+        resp.assert_status(axum::http::StatusCode::TOO_MANY_REQUESTS);
+        let err = graft_proto::common::v1::GraftErr::decode(resp.into_bytes()).unwrap();
+        assert_eq!(
+            err.code(),
+            graft_proto::common::v1::GraftErrCode::LimitExceeded
+        );
+    }
+
+    #[graft_test::test]
+    async fn test_write_pages_page_size() {
+        let store = Arc::new(InMemory::default());
+        let cache = Arc::new(MemCache::default());
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+        let loader = SegmentLoader::new(store, cache, 8);
+        let (page_tx, _) = mpsc::channel(128);
+
+        let client = NetClient::new(None);
+        let metastore_uri = "http://localhost:3000".parse().unwrap();
+
+        // the volume already exists with the default page size
+        let vid = VolumeId::random();
+        let snapshot = CommitMeta::new(
+            vid.clone(),
+            ClientId::random(),
+            LSN::FIRST,
+            LSN::FIRST,
+            PageCount::new(1),
+            PageSize::DEFAULT,
+            SystemTime::now(),
+        );
+        let mut batch = catalog.batch_insert();
+        batch
+            .insert_snapshot(vid.clone(), snapshot, vec![])
+            .unwrap();
+        batch.commit().unwrap();
+
+        let state = Arc::new(PagestoreApiState::new(
+            page_tx,
+            catalog,
+            loader,
+            MetastoreClient::new(metastore_uri, client),
+            VolumeCatalogUpdater::new(10),
+            10,
+        ));
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        // pages of a different size are rejected
+        let page: Bytes = Page::test_filled_sized(1, PageSize::MAX).into();
+        let req = WritePagesRequest {
+            vid: vid.copy_to_bytes(),
+            pages: vec![PageAtIdx { pageidx: 1, data: page }],
+            snapshot_lsn: Some(LSN::FIRST.into()),
+        };
+        let resp = server.post("/").bytes(req.encode_to_vec().into()).await;
+        resp.assert_status(axum::http::StatusCode::BAD_REQUEST);
+    }
 }
@@ -0,0 +1,328 @@
+use std::{future::Future, sync::Arc, time::Duration};
+
+use axum::{
+    body::BodyDataStream,
+    extract::{Request, State},
+    response::IntoResponse,
+};
+use bytes::{Buf, BytesMut};
+use culprit::{Culprit, ResultExt};
+use futures::StreamExt;
+use graft_core::{VolumeId, byte_unit::ByteUnit, lsn::LSN};
+use graft_proto::pagestore::v1::{WritePagesRequest, WritePagesResponse};
+use prost::Message;
+
+use crate::api::{
+    error::{ApiErr, ApiErrCtx},
+    extractors::expect_protobuf,
+    response::ProtoResponse,
+};
+use crate::segment::cache::Cache;
+use crate::tenant::Tenant;
+
+use super::{PagestoreApiState, write_pages::VolumeWriter};
+
+/// The maximum size of a single frame in a streaming write
+const MAX_FRAME_SIZE: ByteUnit = ByteUnit::from_mb(8);
+
+/// The maximum length of a varint encoded frame length
+const MAX_DELIMITER_LEN: usize = 10;
+
+/// Write pages to a volume from a stream of length delimited
+/// `WritePagesRequest` frames. Every frame must have the same vid and
+/// snapshot LSN. Pages are sent to the segment writer as they arrive, so the
+/// size of a push is not limited by the size of a single request. The volume
+/// write permit is only held while a batch is flushed, so a slow client
+/// doesn't block other writers to the volume.
+#[tracing::instrument(name = "pagestore/v1/write_pages_stream", skip(state, req))]
+pub async fn handler<C: Cache>(
+    State(state): State<Arc<PagestoreApiState<C>>>,
+    req: Request,
+) -> Result<impl IntoResponse, ApiErr> {
+    expect_protobuf(req.headers())?;
+    let tenant = req
+        .extensions()
+        .get::<Tenant>()
+        .cloned()
+        .unwrap_or_default();
+    let mut frames = FrameReader::new(
+        req.into_body().into_data_stream(),
+        state.stream_idle_timeout(),
+    );
+
+    let Some(first) = frames.next::<WritePagesRequest>().await? else {
+        return Err(Culprit::new_with_note(ApiErrCtx::InvalidRequestBody, "empty body").into());
+    };
+    let (first_vid, first_snapshot_lsn) = (first.vid.clone(), first.snapshot_lsn);
+    let vid: VolumeId = first.vid.clone().try_into()?;
+    let snapshot_lsn: Option<LSN> = first
+        .snapshot_lsn
+        .map(LSN::try_from)
+        .transpose()
+        .or_into_ctx()?;
+
+    tracing::info!(?vid, ?snapshot_lsn);
+
+    let mut writer = VolumeWriter::new(&state, tenant, vid, snapshot_lsn);
+    let mut next = Some(first);
+    while let Some(frame) = next {
+        if frame.vid != first_vid || frame.snapshot_lsn != first_snapshot_lsn {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::InvalidRequestBody,
+                "every frame must have the same vid and snapshot_lsn",
+            )
+            .into());
+        }
+
+        for page in frame.pages {
+            writer.push(page)?;
+            if writer.batch_full() {
+                flush(&state, &mut writer).await?;
+            }
+        }
+        next = frames.next().await?;
+    }
+    flush(&state, &mut writer).await?;
+    let segments = writer.finish().await?;
+
+    Ok(ProtoResponse::new(WritePagesResponse { segments }))
+}
+
+/// Send the current batch of pages to the segment writer
+async fn flush<C: Cache>(
+    state: &PagestoreApiState<C>,
+    writer: &mut VolumeWriter<'_, C>,
+) -> Result<(), ApiErr> {
+    // acquire a permit to write to the volume.
+    // This permit is critical as it ensures that no other write_pages handler
+    // can concurrently write pages into this volume.
+    let _permit = state.volume_write_limiter().acquire(writer.vid()).await;
+    writer.flush().await
+}
+
+/// Decodes length delimited protobuf messages from a request body
+struct FrameReader {
+    stream: BodyDataStream,
+    buf: BytesMut,
+    /// abort if no data is received for this long
+    idle_timeout: Option<Duration>,
+}
+
+impl FrameReader {
+    fn new(stream: BodyDataStream, idle_timeout: Option<Duration>) -> Self {
+        Self {
+            stream,
+            buf: BytesMut::new(),
+            idle_timeout,
+        }
+    }
+
+    /// Returns the next message, or None once the body is exhausted
+    async fn next<M: Message + Default>(&mut self) -> Result<Option<M>, ApiErr> {
+        loop {
+            if let Some(frame) = self.split_frame()? {
+                let msg = M::decode(frame).map_err(|err| {
+                    Culprit::new_with_note(ApiErrCtx::InvalidRequestBody, err.to_string())
+                })?;
+                return Ok(Some(msg));
+            }
+
+            match with_timeout(self.idle_timeout, self.stream.next()).await? {
+                Some(chunk) => {
+                    let chunk = chunk.map_err(|err| {
+                        Culprit::new_with_note(ApiErrCtx::InvalidRequestBody, err.to_string())
+                    })?;
+                    self.buf.extend_from_slice(&chunk);
+                }
+                None if self.buf.is_empty() => return Ok(None),
+                None => {
+                    return Err(Culprit::new_with_note(
+                        ApiErrCtx::InvalidRequestBody,
+                        "body ends with a partial frame",
+                    )
+                    .into());
+                }
+            }
+        }
+    }
+
+    /// Splits the next complete frame off the front of the buffer
+    fn split_frame(&mut self) -> Result<Option<BytesMut>, ApiErr> {
+        if self.buf.is_empty() {
+            return Ok(None);
+        }
+        let mut remaining = &self.buf[..];
+        let len = match prost::decode_length_delimiter(&mut remaining) {
+            Ok(len) => len,
+            // the delimiter may not have been fully received yet
+            Err(_) if self.buf.len() < MAX_DELIMITER_LEN => return Ok(None),
+            Err(err) => {
+                return Err(
+                    Culprit::new_with_note(ApiErrCtx::InvalidRequestBody, err.to_string()).into(),
+                );
+            }
+        };
+        if len as u64 > MAX_FRAME_SIZE.as_u64() {
+            return Err(Culprit::new_with_note(
+                ApiErrCtx::InvalidRequestBody,
+                format!(
+                    "frame of size {} exceeds {MAX_FRAME_SIZE}",
+                    ByteUnit::new(len as u64)
+                ),
+            )
+            .into());
+        }
+        if remaining.len() < len {
+            return Ok(None);
+        }
+        let delimiter_len = self.buf.len() - remaining.len();
+        self.buf.advance(delimiter_len);
+        Ok(Some(self.buf.split_to(len)))
+    }
+}
+
+/// Await `fut`, failing the request if it doesn't complete within `timeout`
+async fn with_timeout<F: Future>(timeout: Option<Duration>, fut: F) -> Result<F::Output, ApiErr> {
+    let Some(timeout) = timeout else {
+        return Ok(fut.await);
+    };
+    tokio::time::timeout(timeout, fut).await.map_err(|_| {
+        Culprit::new_with_note(
+            ApiErrCtx::InvalidRequestBody,
+            format!("no data received for {timeout:?}"),
+        )
+        .into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::handler::Handler;
+    use axum_test::TestServer;
+    use bytes::Bytes;
+    use graft_client::{MetastoreClient, NetClient};
+    use graft_core::page::Page;
+    use graft_proto::pagestore::v1::PageAtIdx;
+    use object_store::memory::InMemory;
+    use splinter_rs::{Splinter, SplinterRef, ops::Merge};
+    use tokio::sync::mpsc;
+
+    use crate::{
+        api::extractors::CONTENT_TYPE_PROTOBUF,
+        segment::{
+            cache::mem::MemCache, compression::SegmentCompression, loader::SegmentLoader,
+            uploader::SegmentUploaderTask, writer::SegmentWriterTask,
+        },
+        supervisor::SupervisedTask,
+        volume::{catalog::VolumeCatalog, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_write_pages_stream() {
+        let store = Arc::new(InMemory::default());
+        let cache = Arc::new(MemCache::default());
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+        let loader = SegmentLoader::new(store.clone(), cache.clone(), 8);
+
+        let (page_tx, page_rx) = mpsc::channel(128);
+        let (store_tx, store_rx) = mpsc::channel(8);
+
+        SegmentWriterTask::new(
+            Default::default(),
+            page_rx,
+            store_tx,
+            Duration::from_secs(1),
+        )
+        .testonly_spawn();
+
+        SegmentUploaderTask::new(
+            Default::default(),
+            store_rx,
+            store.clone(),
+            cache.clone(),
+            SegmentCompression::None,
+        )
+        .testonly_spawn();
+
+        let client = NetClient::new(None);
+        let metastore_uri = "http://localhost:3000".parse().unwrap();
+
+        let state = Arc::new(PagestoreApiState::new(
+            page_tx,
+            catalog,
+            loader,
+            MetastoreClient::new(metastore_uri, client),
+            VolumeCatalogUpdater::new(10),
+            10,
+        ));
+
+        let server = TestServer::builder()
+            .default_content_type(CONTENT_TYPE_PROTOBUF.to_str().unwrap())
+            .build(handler.with_state(state).into_make_service())
+            .unwrap();
+
+        let vid = VolumeId::random();
+        let page: Bytes = Page::test_filled(1).into();
+        let frame = |pageidxs: std::ops::Range<u32>| {
+            WritePagesRequest {
+                vid: vid.copy_to_bytes(),
+                pages: pageidxs
+                    .map(|pageidx| PageAtIdx { pageidx, data: page.clone() })
+                    .collect(),
+                snapshot_lsn: None,
+            }
+            .encode_length_delimited_to_vec()
+        };
+
+        // write more pages than fit in a single segment across many frames
+        let body: Vec<u8> = (0..20)
+            .flat_map(|i| frame(1 + i * 256..1 + (i + 1) * 256))
+            .collect();
+        let resp = server.post("/").bytes(body.into()).await;
+        resp.assert_status_ok();
+
+        let resp = WritePagesResponse::decode(resp.into_bytes()).unwrap();
+        assert!(resp.segments.len() > 1, "expected multiple segments");
+        let mut pages = Splinter::default();
+        for segment in &resp.segments {
+            pages.merge(&SplinterRef::from_bytes(segment.graft.clone()).unwrap());
+        }
+        assert_eq!(pages.cardinality(), 20 * 256);
+        assert!(pages.contains(1) && pages.contains(20 * 256));
+
+        // pages may not be repeated across frames
+        let body = [frame(1..3), frame(2..4)].concat();
+        let resp = server.post("/").bytes(body.into()).await;
+        resp.assert_status_bad_request();
+
+        // the body may not end with a partial frame
+        let mut body = frame(1..3);
+        body.truncate(body.len() - 1);
+        let resp = server.post("/").bytes(body.into()).await;
+        resp.assert_status_bad_request();
+    }
+
+    #[graft_test::test]
+    async fn test_frame_reader_idle_timeout() {
+        let frame = WritePagesRequest {
+            vid: VolumeId::random().copy_to_bytes(),
+            pages: vec![],
+            snapshot_lsn: None,
+        }
+        .encode_length_delimited_to_vec();
+
+        // a stream which stalls after the first frame
+        let chunks = futures::stream::iter([Ok::<_, std::io::Error>(Bytes::from(frame))])
+            .chain(futures::stream::pending());
+        let body = axum::body::Body::from_stream(chunks);
+        let mut frames = FrameReader::new(body.into_data_stream(), Some(Duration::from_millis(50)));
+
+        assert!(frames.next::<WritePagesRequest>().await.unwrap().is_some());
+        let err = frames.next::<WritePagesRequest>().await.unwrap_err();
+        assert!(format!("{err:?}").contains("no data received"), "{err:?}");
+    }
+}
@@ -11,10 +11,12 @@ use graft_server::{
     },
     metrics::registry::Registry,
     object_store_util::ObjectStoreConfig,
+    segment::gc::{SegmentGcConfig, SegmentGcTask},
     supervisor::Supervisor,
     volume::{
         catalog::{VolumeCatalog, VolumeCatalogConfig},
         store::VolumeStore,
+        transaction::DEFAULT_TRANSACTION_TIMEOUT,
         updater::VolumeCatalogUpdater,
     },
 };
@@ -29,10 +31,19 @@ use tokio::{net::TcpListener, select, signal::ctrl_c};
 struct MetastoreConfig {
     catalog: VolumeCatalogConfig,
     objectstore: ObjectStoreConfig,
+    gc: SegmentGcConfig,
     auth: Option<AuthState>,
 
     port: u16,
     catalog_update_concurrency: usize,
+
+    /// how often watch requests check the object store for commits made
+    /// through other metastores
+    watch_refresh_interval_secs: u64,
+
+    /// how long a transaction may remain undecided before it's aborted. Must
+    /// exceed the maximum clock skew between metastores.
+    transaction_timeout_secs: u64,
 }
 
 impl Default for MetastoreConfig {
@@ -40,8 +51,11 @@ impl Default for MetastoreConfig {
         Self {
             catalog: Default::default(),
             objectstore: Default::default(),
+            gc: Default::default(),
             port: 3001,
             catalog_update_concurrency: 16,
+            watch_refresh_interval_secs: 5,
+            transaction_timeout_secs: DEFAULT_TRANSACTION_TIMEOUT.as_secs(),
             auth: None,
         }
     }
@@ -84,23 +98,52 @@ async fn main() {
     let toml_config = toml::to_string_pretty(&config).expect("failed to serialize config");
     tracing::info!("loaded configuration:\n{toml_config}");
 
+    let ordered_listing = config.objectstore.lists_in_order();
     let store = config
         .objectstore
         .build()
         .expect("failed to build object store");
-    let store = Arc::new(VolumeStore::new(store));
+    let mut store = VolumeStore::new(store);
+    if !ordered_listing {
+        store = store.with_unordered_listing();
+    }
+    let store = Arc::new(store);
     let catalog =
         VolumeCatalog::open_config(config.catalog).expect("failed to open volume catalog");
-    let updater = VolumeCatalogUpdater::new(config.catalog_update_concurrency);
+    let transaction_timeout = Duration::from_secs(config.transaction_timeout_secs);
+    let updater = VolumeCatalogUpdater::new(config.catalog_update_concurrency)
+        .with_transaction_timeout(transaction_timeout);
+
+    let mut registry = Registry::default();
+    let mut supervisor = Supervisor::default();
+
+    if let Some(segments) = config.gc.segments {
+        let segments = segments
+            .build()
+            .expect("failed to build segment object store");
+        supervisor.spawn(SegmentGcTask::new(
+            registry.segment_gc(),
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(config.catalog_update_concurrency)
+                .with_transaction_timeout(transaction_timeout),
+            segments,
+            Duration::from_secs(config.gc.interval_secs),
+            Duration::from_secs(config.gc.grace_period_secs),
+        ));
+    }
 
     let auth = config.auth.map(|c| c.into());
-    let state = Arc::new(MetastoreApiState::new(store, catalog, updater));
-    let router = build_router(Registry::default(), auth, state, metastore_routes());
+    let state = Arc::new(
+        MetastoreApiState::new(store, catalog, updater)
+            .with_watch_refresh_interval(Duration::from_secs(config.watch_refresh_interval_secs))
+            .with_shutdown(supervisor.shutdown_token()),
+    );
+    let router = build_router(registry, auth, state, metastore_routes());
 
     let addr = format!("0.0.0.0:{}", config.port);
     tracing::info!("listening on {}", addr);
 
-    let mut supervisor = Supervisor::default();
     supervisor.spawn(ApiServerTask::new(
         "metastore-api-server",
         TcpListener::bind(addr).await.unwrap(),
@@ -14,12 +14,19 @@ use graft_server::{
     metrics::registry::Registry,
     object_store_util::ObjectStoreConfig,
     segment::{
-        cache::disk::{DiskCache, DiskCacheConfig},
-        loader::SegmentLoader,
+        cache::{
+            disk::{DiskCache, DiskCacheConfig},
+            tiered::{TieredCache, TieredCacheConfig},
+        },
+        compactor::{CompactionTrigger, SegmentCompactorConfig, SegmentCompactorTask},
+        compression::SegmentCompression,
+        loader::{HedgeConfig, SegmentLoader},
+        replicator::{ReplicationConfig, SegmentReplicator},
         uploader::SegmentUploaderTask,
         writer::SegmentWriterTask,
     },
     supervisor::Supervisor,
+    tenant::TenantLimitsConfig,
     volume::{
         catalog::{VolumeCatalog, VolumeCatalogConfig},
         updater::VolumeCatalogUpdater,
@@ -38,9 +45,20 @@ use url::Url;
 struct PagestoreConfig {
     catalog: VolumeCatalogConfig,
     cache: DiskCacheConfig,
+    memory_cache: TieredCacheConfig,
     objectstore: ObjectStoreConfig,
+    compaction: SegmentCompactorConfig,
+    hedging: HedgeConfig,
     auth: Option<AuthState>,
 
+    /// replicate segments to peer pagestores, acknowledging writes once every
+    /// peer has a copy rather than once the segment has been uploaded
+    replication: Option<ReplicationConfig>,
+
+    /// rate limits and quotas applied to each tenant, identified by the
+    /// subject of their API token
+    tenant_limits: TenantLimitsConfig,
+
     port: u16,
     metastore: Url,
     token: Option<String>,
@@ -48,6 +66,28 @@ struct PagestoreConfig {
     catalog_update_concurrency: usize,
     download_concurrency: usize,
     write_concurrency: usize,
+
+    /// how pages are compressed in newly written segments
+    segment_compression: SegmentCompression,
+
+    /// store pages which mostly match their checkpoint version as deltas
+    page_deltas: bool,
+
+    /// read pages from uncached segments with range requests rather than
+    /// downloading the whole segment
+    range_reads: bool,
+
+    /// the maximum size of the additional pages returned by read requests
+    /// which enable prefetching
+    prefetch_budget: ByteUnit,
+
+    /// abort streaming writes which don't receive any data for this many
+    /// milliseconds
+    stream_idle_timeout_ms: Option<u64>,
+
+    /// reject reads of deleted volumes, asking the metastore whether each
+    /// volume was deleted at most once every this many milliseconds
+    deletion_check_interval_ms: Option<u64>,
 }
 
 impl Default for PagestoreConfig {
@@ -62,8 +102,13 @@ impl Default for PagestoreConfig {
                     .0 as usize
                     / 2,
             },
+            memory_cache: Default::default(),
             objectstore: Default::default(),
+            compaction: Default::default(),
+            hedging: Default::default(),
             auth: None,
+            replication: None,
+            tenant_limits: Default::default(),
 
             port: 3000,
             metastore: "http://localhost:3001".parse().unwrap(),
@@ -72,6 +117,13 @@ impl Default for PagestoreConfig {
             catalog_update_concurrency: 16,
             download_concurrency: 16,
             write_concurrency: 16,
+
+            segment_compression: SegmentCompression::None,
+            page_deltas: false,
+            range_reads: false,
+            prefetch_budget: ByteUnit::from_mb(1),
+            stream_idle_timeout_ms: Some(30_000),
+            deletion_check_interval_ms: Some(10_000),
         }
     }
 }
@@ -126,41 +178,103 @@ async fn main() {
 
     let mut supervisor = Supervisor::default();
 
-    let cache = Arc::new(DiskCache::new(config.cache).expect("failed to create disk cache"));
+    let disk_cache = DiskCache::new(registry.disk_cache(), config.cache)
+        .await
+        .expect("failed to create disk cache");
+    let cache = Arc::new(TieredCache::new(
+        registry.tiered_cache(),
+        config.memory_cache,
+        disk_cache,
+    ));
     let catalog =
         VolumeCatalog::open_config(config.catalog).expect("failed to open volume catalog");
-    let loader = SegmentLoader::new(store.clone(), cache.clone(), config.download_concurrency);
+    let loader = SegmentLoader::new(store.clone(), cache.clone(), config.download_concurrency)
+        .with_metrics(registry.segment_loader())
+        .with_hedging(config.hedging)
+        .with_range_reads(config.range_reads);
     let updater = VolumeCatalogUpdater::new(config.catalog_update_concurrency);
 
     let (page_tx, page_rx) = mpsc::channel(128);
     let (store_tx, store_rx) = mpsc::channel(8);
+    let (compact_tx, compact_rx) = mpsc::channel(128);
 
     let client = NetClient::new(config.token);
-    let metastore = MetastoreClient::new(config.metastore, client);
+    let metastore = MetastoreClient::new(config.metastore, client.clone());
+
+    let replication = config.replication.unwrap_or_default();
+    assert!(
+        !replication.accept_replicas || config.auth.is_some(),
+        "auth must be configured to accept replicas"
+    );
 
+    // flush segments more often when they are replicated, as writes no
+    // longer wait on object storage
+    let flush_interval = if replication.peers.is_empty() {
+        Duration::from_secs(1)
+    } else {
+        replication.flush_interval()
+    };
+    // the writer holds the only sender to the uploader, so the uploader can
+    // drain the segments flushed by the writer when shutting down
     supervisor.spawn(SegmentWriterTask::new(
         registry.segment_writer(),
         page_rx,
         store_tx,
-        Duration::from_secs(1),
+        flush_interval,
     ));
 
-    supervisor.spawn(SegmentUploaderTask::new(
+    let mut uploader = SegmentUploaderTask::new(
         registry.segment_uploader(),
         store_rx,
+        store.clone(),
+        cache.clone(),
+        config.segment_compression,
+    );
+    if !replication.peers.is_empty() {
+        uploader = uploader.with_replicator(SegmentReplicator::new(&replication, client.clone()));
+    }
+    let mut replica_tx = None;
+    if replication.accept_replicas {
+        let (tx, rx) = mpsc::channel(8);
+        uploader = uploader.with_replicas(rx);
+        replica_tx = Some(tx);
+    }
+    supervisor.spawn(uploader);
+
+    let compaction = CompactionTrigger::new(compact_tx, &config.compaction);
+    supervisor.spawn(SegmentCompactorTask::new(
+        registry.segment_compactor(),
+        config.compaction,
+        compact_rx,
         store,
         cache,
+        catalog.clone(),
+        metastore.clone(),
     ));
 
     let auth = config.auth.map(|c| c.into());
-    let state = Arc::new(PagestoreApiState::new(
+    let mut state = PagestoreApiState::new(
         page_tx,
         catalog,
         loader,
         metastore,
         updater,
         config.write_concurrency,
-    ));
+    )
+    .with_compaction(compaction)
+    .with_page_deltas(config.page_deltas)
+    .with_prefetch_budget(config.prefetch_budget)
+    .with_tenant_limits(config.tenant_limits);
+    if let Some(replica_tx) = replica_tx {
+        state = state.with_replica_tx(replica_tx);
+    }
+    if let Some(timeout) = config.stream_idle_timeout_ms {
+        state = state.with_stream_idle_timeout(Duration::from_millis(timeout));
+    }
+    if let Some(interval) = config.deletion_check_interval_ms {
+        state = state.with_deletion_check_interval(Duration::from_millis(interval));
+    }
+    let state = Arc::new(state);
     let router = build_router(registry, auth, state, pagestore_routes());
 
     let addr = format!("0.0.0.0:{}", config.port);
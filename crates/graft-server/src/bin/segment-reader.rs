@@ -2,11 +2,11 @@ use std::{fmt::Debug, io, path::PathBuf, process::exit};
 
 use clap::{Parser, Subcommand, arg};
 use culprit::{Culprit, ResultExt};
-use graft_core::{
-    PageIdx, VolumeId,
-    page::{PAGESIZE, Page},
+use graft_core::{PageIdx, VolumeId};
+use graft_server::segment::{
+    closed::{ClosedSegment, SegmentValidationErr},
+    delta::SegmentPage,
 };
-use graft_server::segment::closed::{ClosedSegment, SegmentValidationErr};
 use thiserror::Error;
 
 #[derive(Parser)]
@@ -54,7 +54,7 @@ impl From<io::Error> for SegmentReaderErr {
     }
 }
 
-fn print_page(page: Page, max: usize) {
+fn print_page(page: &[u8], max: usize) {
     for &byte in page.iter().take(max) {
         // if byte is a printable ascii character
         if byte.is_ascii_alphanumeric() || byte.is_ascii_punctuation() || byte.is_ascii_whitespace()
@@ -65,9 +65,13 @@ fn print_page(page: Page, max: usize) {
     println!();
 }
 
-fn print_segment(segment: &ClosedSegment) {
+fn print_segment(segment: &ClosedSegment) -> Result<(), Culprit<SegmentReaderErr>> {
     println!("Segment ID: {}", segment.sid());
+    println!("Version: {}", segment.version());
     println!("Pages: {}", segment.pages());
+    println!("Page size: {}", segment.page_size());
+    println!("Compression: {:?}", segment.compression());
+    println!("Deltas: {}", segment.deltas());
 
     // print table headers: Volume id, page, length, page_prefix
     println!(
@@ -75,15 +79,31 @@ fn print_segment(segment: &ClosedSegment) {
         "Volume ID", "PageIdx", "Empty"
     );
 
-    for (vid, pageidx, page) in segment.iter() {
-        print!(
-            "{:<10} {:<10} {:<10} ",
-            vid.short(),
-            pageidx,
-            page.is_empty()
-        );
-        print_page(page, 10);
+    for item in segment.iter() {
+        let (vid, pageidx, page) = item.or_into_ctx()?;
+        match page {
+            SegmentPage::Full(page) => {
+                print!(
+                    "{:<10} {:<10} {:<10} ",
+                    vid.short(),
+                    pageidx,
+                    page.is_empty()
+                );
+                print_page(&page, 10);
+            }
+            SegmentPage::Delta(delta) => {
+                println!(
+                    "{:<10} {:<10} {:<10} {} byte delta",
+                    vid.short(),
+                    pageidx,
+                    "-",
+                    delta.len()
+                );
+            }
+        }
     }
+
+    Ok(())
 }
 
 fn main() {
@@ -103,15 +123,15 @@ fn main_inner() -> Result<(), Culprit<SegmentReaderErr>> {
     match cli.command {
         Commands::Print => {
             println!("Segment size: {}", data.len());
-            print_segment(&segment)
+            print_segment(&segment)?
         }
-        Commands::Read { vid, pageidx } => {
-            if let Some(page) = segment.find_page(&vid, pageidx) {
-                print_page(page, PAGESIZE.as_usize())
-            } else {
-                return Err(Culprit::new(SegmentReaderErr::PageNotFound));
+        Commands::Read { vid, pageidx } => match segment.find_page(&vid, pageidx).or_into_ctx()? {
+            Some(SegmentPage::Full(page)) => print_page(&page, segment.page_size().as_usize()),
+            Some(SegmentPage::Delta(delta)) => {
+                println!("page is stored as a {} byte delta", delta.len())
             }
-        }
+            None => return Err(Culprit::new(SegmentReaderErr::PageNotFound)),
+        },
     }
 
     Ok(())
@@ -1,9 +1,14 @@
 pub mod segment {
     pub mod cache;
     pub mod closed;
+    pub mod compactor;
+    pub mod compression;
+    pub mod delta;
+    pub mod gc;
     pub mod index;
     pub mod loader;
     pub mod open;
+    pub mod replicator;
     pub mod uploader;
     pub mod writer;
 }
@@ -24,8 +29,10 @@ pub mod api {
 pub mod volume {
     pub mod catalog;
     pub mod commit;
+    pub mod deletion;
     pub mod kv;
     pub mod store;
+    pub mod transaction;
     pub mod updater;
 }
 
@@ -40,6 +47,7 @@ pub mod limiter;
 pub mod object_store_util;
 pub mod resource_pool;
 pub mod supervisor;
+pub mod tenant;
 
 #[cfg(test)]
 pub mod testutil;
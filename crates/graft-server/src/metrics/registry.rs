@@ -2,7 +2,14 @@ use std::{result::Result, sync::Arc};
 
 use measured::MetricGroup;
 
-use crate::segment::{uploader::SegmentUploaderMetrics, writer::SegmentWriterMetrics};
+use crate::segment::{
+    cache::{disk::DiskCacheMetrics, tiered::TieredCacheMetrics},
+    compactor::SegmentCompactorMetrics,
+    gc::SegmentGcMetrics,
+    loader::SegmentLoaderMetrics,
+    uploader::SegmentUploaderMetrics,
+    writer::SegmentWriterMetrics,
+};
 
 #[derive(Default, MetricGroup)]
 pub struct Registry {
@@ -11,6 +18,21 @@ pub struct Registry {
 
     #[metric(namespace = "segment_uploader")]
     segment_uploader: Option<Arc<SegmentUploaderMetrics>>,
+
+    #[metric(namespace = "segment_compactor")]
+    segment_compactor: Option<Arc<SegmentCompactorMetrics>>,
+
+    #[metric(namespace = "segment_gc")]
+    segment_gc: Option<Arc<SegmentGcMetrics>>,
+
+    #[metric(namespace = "segment_loader")]
+    segment_loader: Option<Arc<SegmentLoaderMetrics>>,
+
+    #[metric(namespace = "disk_cache")]
+    disk_cache: Option<Arc<DiskCacheMetrics>>,
+
+    #[metric(namespace = "tiered_cache")]
+    tiered_cache: Option<Arc<TieredCacheMetrics>>,
 }
 
 impl Registry {
@@ -25,4 +47,34 @@ impl Registry {
             .get_or_insert_with(|| Arc::new(SegmentUploaderMetrics::default()))
             .clone()
     }
+
+    pub fn segment_compactor(&mut self) -> Arc<SegmentCompactorMetrics> {
+        self.segment_compactor
+            .get_or_insert_with(|| Arc::new(SegmentCompactorMetrics::default()))
+            .clone()
+    }
+
+    pub fn segment_gc(&mut self) -> Arc<SegmentGcMetrics> {
+        self.segment_gc
+            .get_or_insert_with(|| Arc::new(SegmentGcMetrics::default()))
+            .clone()
+    }
+
+    pub fn segment_loader(&mut self) -> Arc<SegmentLoaderMetrics> {
+        self.segment_loader
+            .get_or_insert_with(|| Arc::new(SegmentLoaderMetrics::default()))
+            .clone()
+    }
+
+    pub fn disk_cache(&mut self) -> Arc<DiskCacheMetrics> {
+        self.disk_cache
+            .get_or_insert_with(|| Arc::new(DiskCacheMetrics::default()))
+            .clone()
+    }
+
+    pub fn tiered_cache(&mut self) -> Arc<TieredCacheMetrics> {
+        self.tiered_cache
+            .get_or_insert_with(|| Arc::new(TieredCacheMetrics::default()))
+            .clone()
+    }
 }
@@ -26,6 +26,11 @@ pub enum ObjectStoreConfig {
 }
 
 impl ObjectStoreConfig {
+    /// Returns true if the object store lists keys in lexicographic order
+    pub fn lists_in_order(&self) -> bool {
+        !matches!(self, ObjectStoreConfig::Fs { .. })
+    }
+
     pub fn build(self) -> object_store::Result<Arc<dyn ObjectStore>> {
         match self {
             ObjectStoreConfig::Memory => Ok(Arc::new(InMemory::new())),
@@ -9,6 +9,10 @@ use tokio::{
     task::spawn_blocking,
 };
 
+/// Temporary files are created next to their destination with this prefix, so
+/// that partially written files can be identified and removed after a crash.
+pub const TEMP_FILE_PREFIX: &str = ".tmp-";
+
 pub async fn write_file_atomic<P, T: Buf + Send + 'static>(path: P, mut data: T) -> io::Result<()>
 where
     P: AsRef<Path>,
@@ -17,8 +21,12 @@ where
     assert!(path.is_absolute(), "path must be absolute");
 
     spawn_blocking(move || {
-        // open a named temporary file
-        let mut file = tempfile::NamedTempFile::new()?;
+        // open a named temporary file in the same directory as the
+        // destination, ensuring that it can be persisted with a rename
+        let dir = path.parent().expect("path must have a parent");
+        let mut file = tempfile::Builder::new()
+            .prefix(TEMP_FILE_PREFIX)
+            .tempfile_in(dir)?;
 
         while data.has_remaining() {
             let mut buf = [IoSlice::new(&[]); 64];
@@ -1,4 +1,15 @@
-use std::{fs::canonicalize, io, ops::Deref, path::PathBuf};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+    fs::canonicalize,
+    io::{self, Read, Seek, SeekFrom},
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
 
 use bytes::Buf;
 use graft_core::{
@@ -6,18 +17,26 @@ use graft_core::{
     byte_unit::ByteUnit,
     hash_table::{HTEntry, HashTable},
 };
+use measured::{Counter, MetricGroup};
 use serde::{Deserialize, Serialize};
 use tokio::{fs::File, sync::RwLock};
 
-use super::atomic_file::write_file_atomic;
-use crate::resource_pool::{ResourceHandle, ResourcePool, ResourcePoolGuard};
+use super::atomic_file::{TEMP_FILE_PREFIX, write_file_atomic};
+use crate::{
+    resource_pool::{ResourceHandle, ResourcePool, ResourcePoolGuard},
+    segment::closed::{PartialSegment, SegmentValidationErr},
+};
 
 use super::Cache;
 
 struct Segment {
     sid: SegmentId,
-    _size: ByteUnit,
+    size: ByteUnit,
     mmap_handle: ResourceHandle,
+
+    /// the value of the cache's access clock when this segment was last
+    /// accessed
+    last_access: AtomicU64,
 }
 
 impl HTEntry for Segment {
@@ -53,39 +72,254 @@ pub struct DiskCacheConfig {
     pub open_limit: usize,
 }
 
+#[derive(MetricGroup, Default)]
+pub struct DiskCacheMetrics {
+    /// Number of lookups which found the segment in the cache
+    hits: Counter,
+
+    /// Number of lookups which didn't find the segment in the cache
+    misses: Counter,
+
+    /// Number of segments evicted from the cache to stay within its space limit
+    evictions: Counter,
+}
+
+struct SegmentIndex {
+    segments: HashTable<Segment>,
+
+    /// The total size of all cached segments.
+    size: ByteUnit,
+
+    /// Segments which may not be evicted.
+    pinned: HashSet<SegmentId>,
+}
+
 pub struct DiskCache {
+    metrics: Arc<DiskCacheMetrics>,
     dir: PathBuf,
 
     /// The maximum amount of space that the cache can use.
-    _space_limit: ByteUnit,
+    space_limit: ByteUnit,
 
     /// Index of cached segments.
-    segments: RwLock<HashTable<Segment>>,
+    index: RwLock<SegmentIndex>,
+
+    /// Incremented on every access, used to find the least recently used
+    /// segments.
+    clock: AtomicU64,
 
     /// Pool of mmap'ed segments.
     mmap_pool: ResourcePool<memmap2::Mmap>,
 }
 
 impl DiskCache {
-    /// Create a new cache.
+    /// Create a new cache, loading any segments left in the cache directory
+    /// by a previous process.
     ///
     /// **Parameters:**
     /// - `space_limit` The maximum amount of space that the cache can use.
     /// - `open_limit` The maximum number of mmap'ed segments.
-    pub fn new(config: DiskCacheConfig) -> io::Result<Self> {
+    pub async fn new(metrics: Arc<DiskCacheMetrics>, config: DiskCacheConfig) -> io::Result<Self> {
         let dir = if let Some(path) = config.path {
             canonicalize(path)?
         } else {
             tempfile::tempdir()?.into_path()
         };
         tracing::info!("Opening disk cache at {:?}", dir);
+
+        let space_limit = config.space_limit;
+        let load_dir = dir.clone();
+        let (keep, size) =
+            tokio::task::spawn_blocking(move || load_segments(&load_dir, space_limit))
+                .await
+                .map_err(io::Error::other)??;
+
+        // insert the oldest segments first so they are the first to be evicted
+        let clock = AtomicU64::new(0);
+        let mut segments = HashTable::default();
+        for (sid, size) in keep.into_iter().rev() {
+            segments.insert(Segment {
+                sid,
+                size,
+                mmap_handle: Default::default(),
+                last_access: AtomicU64::new(clock.fetch_add(1, Ordering::Relaxed)),
+            });
+        }
+        tracing::info!(
+            "loaded {} segments ({}) from disk cache",
+            segments.len(),
+            size
+        );
+
         Ok(Self {
+            metrics,
             dir,
-            _space_limit: config.space_limit,
-            segments: Default::default(),
+            space_limit,
+            index: RwLock::new(SegmentIndex { segments, size, pinned: HashSet::new() }),
+            clock,
             mmap_pool: ResourcePool::new(config.open_limit),
         })
     }
+
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Remove the least recently used segments from the index until the cache
+    /// fits within its space limit, returning the evicted segments. The
+    /// segment identified by `keep` and pinned segments are never evicted.
+    ///
+    /// The caller is responsible for removing the evicted segments from disk
+    /// once it has released the index lock.
+    fn evict(&self, index: &mut SegmentIndex, keep: &SegmentId) -> Vec<SegmentId> {
+        if index.size <= self.space_limit {
+            return vec![];
+        }
+
+        // heapify the candidates rather than sorting them, as usually only a
+        // few segments need to be evicted
+        let mut candidates: BinaryHeap<_> = index
+            .segments
+            .iter()
+            .filter(|segment| &segment.sid != keep && !index.pinned.contains(&segment.sid))
+            .map(|segment| {
+                Reverse((
+                    segment.last_access.load(Ordering::Relaxed),
+                    segment.sid.clone(),
+                ))
+            })
+            .collect();
+
+        let mut evicted = vec![];
+        while index.size > self.space_limit {
+            let Some(Reverse((_, sid))) = candidates.pop() else {
+                break;
+            };
+            let segment = index
+                .segments
+                .remove(&sid)
+                .expect("segment is in the index");
+            index.size = index.size - segment.size;
+            self.metrics.evictions.inc();
+            evicted.push(sid);
+        }
+        evicted
+    }
+
+    /// Remove evicted segments from disk.
+    ///
+    /// Evicted segments which are currently mapped remain readable until they
+    /// are unmapped, as the kernel only reclaims the space once the last
+    /// mapping is gone.
+    async fn remove_evicted(&self, evicted: Vec<SegmentId>) -> io::Result<()> {
+        for sid in evicted {
+            let path = self.dir.join(sid.pretty());
+            tracing::trace!("evicting segment {:?} from disk at path {:?}", sid, path);
+            match tokio::fs::remove_file(&path).await {
+                Ok(()) => (),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+                Err(e) => {
+                    tracing::error!("failed to evict segment {:?} from disk: {:?}", sid, e);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Find the segments left in the cache directory by a previous process,
+/// returning the most recently written segments which fit within the space
+/// limit along with their total size. Partially written and corrupt files are
+/// removed, as are the segments which don't fit within the space limit.
+///
+/// Only the tail of each segment is read to validate it, as pages are
+/// verified against their checksums when they are read.
+fn load_segments(
+    dir: &Path,
+    space_limit: ByteUnit,
+) -> io::Result<(Vec<(SegmentId, ByteUnit)>, ByteUnit)> {
+    let mut found = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+
+        if name.starts_with(TEMP_FILE_PREFIX) {
+            tracing::debug!("removing partially written file {:?}", path);
+            std::fs::remove_file(&path)?;
+            continue;
+        }
+        let Ok(sid) = name.parse::<SegmentId>() else {
+            tracing::warn!("ignoring unknown file in disk cache {:?}", path);
+            continue;
+        };
+
+        let metadata = entry.metadata()?;
+        match read_segment_sid(&path, metadata.len())? {
+            Ok(found_sid) if found_sid == sid => {
+                found.push((metadata.modified()?, sid, ByteUnit::new(metadata.len())));
+            }
+            Ok(found_sid) => {
+                tracing::warn!(
+                    "removing segment {:?} from disk cache: contains segment {:?}",
+                    sid,
+                    found_sid
+                );
+                std::fs::remove_file(&path)?;
+            }
+            Err(err) => {
+                tracing::warn!(
+                    "removing corrupt segment {:?} from disk cache: {err:?}",
+                    sid
+                );
+                std::fs::remove_file(&path)?;
+            }
+        }
+    }
+
+    // keep the most recently written segments which fit within the limit
+    found.sort_by_key(|(modified, _, _)| std::cmp::Reverse(*modified));
+    let mut keep = vec![];
+    let mut size = ByteUnit::ZERO;
+    for (_, sid, segment_size) in found {
+        if size + segment_size > space_limit {
+            tracing::debug!("removing segment {:?} which exceeds the space limit", sid);
+            std::fs::remove_file(dir.join(sid.pretty()))?;
+            continue;
+        }
+        size = size + segment_size;
+        keep.push((sid, segment_size));
+    }
+    Ok((keep, size))
+}
+
+/// Validate the tail of a segment file, returning the id of the segment
+fn read_segment_sid(
+    path: &Path,
+    size: u64,
+) -> io::Result<culprit::Result<SegmentId, SegmentValidationErr>> {
+    let mut file = std::fs::File::open(path)?;
+    let mut read_tail = |len: usize| -> io::Result<Vec<u8>> {
+        file.seek(SeekFrom::End(-(len as i64)))?;
+        let mut buf = vec![0; len];
+        file.read_exact(&mut buf)?;
+        Ok(buf)
+    };
+
+    let size = usize::try_from(size).unwrap_or(usize::MAX);
+    let footer = read_tail(size.min(PartialSegment::FOOTER_SIZE))?;
+    let tail_size = match PartialSegment::tail_size(&footer) {
+        Ok(tail_size) if tail_size <= size => tail_size,
+        Ok(_) => return Ok(Err(culprit::Culprit::new(SegmentValidationErr::TooSmall))),
+        Err(err) => return Ok(Err(err)),
+    };
+    let tail = read_tail(tail_size)?;
+    Ok(PartialSegment::from_tail(size, &tail).map(|segment| segment.sid().clone()))
 }
 
 impl Cache for DiskCache {
@@ -112,24 +346,33 @@ impl Cache for DiskCache {
             }
         }
 
-        // update the segment index if needed
-        self.segments
-            .write()
-            .await
-            .entry(sid)
-            .or_insert_with(|| Segment {
-                sid: sid.clone(),
-                _size: data_size,
-                mmap_handle: Default::default(),
-            });
+        // update the segment index if needed, and make room for the new
+        // segment
+        let evicted = {
+            let mut index = self.index.write().await;
+            if !index.segments.has(sid) {
+                index.segments.insert(Segment {
+                    sid: sid.clone(),
+                    size: data_size,
+                    mmap_handle: Default::default(),
+                    last_access: AtomicU64::new(self.tick()),
+                });
+                index.size = index.size + data_size;
+            }
+            self.evict(&mut index, sid)
+        };
+        self.remove_evicted(evicted).await?;
 
         Ok(())
     }
 
     async fn get(&self, sid: &SegmentId) -> culprit::Result<Option<Self::Item<'_>>, io::Error> {
-        let segments = self.segments.read().await;
+        let index = self.index.read().await;
+
+        if let Some(segment) = index.segments.find(sid) {
+            self.metrics.hits.inc();
+            segment.last_access.store(self.tick(), Ordering::Relaxed);
 
-        if let Some(segment) = segments.find(sid) {
             let mmap = self
                 .mmap_pool
                 .get(&segment.mmap_handle, || async {
@@ -139,11 +382,209 @@ impl Cache for DiskCache {
                     let mmap = unsafe { memmap2::MmapOptions::new().map(&file) }?;
                     Ok::<_, std::io::Error>(mmap)
                 })
-                .await?;
+                .await;
 
-            Ok(Some(MappedSegment { mmap }))
+            match mmap {
+                Ok(mmap) => Ok(Some(MappedSegment { mmap })),
+                // evicted files are removed after the index is updated, so a
+                // segment which was evicted and then put again concurrently
+                // may be missing until it's put again
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    tracing::debug!("segment {:?} is missing from disk", sid);
+                    Ok(None)
+                }
+                Err(err) => Err(err.into()),
+            }
         } else {
+            self.metrics.misses.inc();
             Ok(None)
         }
     }
+
+    async fn pin(&self, sid: &SegmentId) {
+        self.index.write().await.pinned.insert(sid.clone());
+    }
+
+    async fn unpin(&self, sid: &SegmentId) {
+        self.index.write().await.pinned.remove(sid);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use bytes::Bytes;
+    use graft_core::{VolumeId, page::Page, pageidx};
+
+    use crate::segment::{
+        closed::ClosedSegment, compression::SegmentCompression, open::OpenSegment,
+    };
+
+    use super::*;
+
+    fn count(counter: &Counter) -> u64 {
+        counter.get_metric().count.load(Ordering::Relaxed)
+    }
+
+    #[graft_test::test]
+    async fn test_disk_cache_eviction() {
+        let dir = tempfile::tempdir().unwrap();
+        let metrics = Arc::new(DiskCacheMetrics::default());
+        let cache = DiskCache::new(
+            metrics.clone(),
+            DiskCacheConfig {
+                path: Some(dir.path().to_path_buf()),
+                space_limit: ByteUnit::new(300),
+                open_limit: 8,
+            },
+        )
+        .await
+        .unwrap();
+
+        let sids: Vec<_> = (0..4).map(|_| SegmentId::random()).collect();
+        let data = |i: usize| Bytes::from(vec![i as u8; 100]);
+
+        // fill the cache up to its limit
+        for (i, sid) in sids[..3].iter().enumerate() {
+            cache.put(sid, data(i)).await.unwrap();
+        }
+        assert_eq!(count(&metrics.evictions), 0);
+
+        // access the first segment so that the second is the least recently used
+        assert_eq!(&*cache.get(&sids[0]).await.unwrap().unwrap(), &data(0));
+
+        // inserting another segment evicts the least recently used segment
+        cache.put(&sids[3], data(3)).await.unwrap();
+        assert_eq!(count(&metrics.evictions), 1);
+        assert!(cache.get(&sids[1]).await.unwrap().is_none());
+        assert!(!dir.path().join(sids[1].pretty()).exists());
+        for i in [0, 2, 3] {
+            assert_eq!(&*cache.get(&sids[i]).await.unwrap().unwrap(), &data(i));
+        }
+        assert_eq!(cache.index.read().await.size, ByteUnit::new(300));
+
+        assert_eq!(count(&metrics.hits), 4);
+        assert_eq!(count(&metrics.misses), 1);
+
+        // a segment larger than the limit evicts everything else
+        let large = SegmentId::random();
+        cache.put(&large, Bytes::from(vec![0; 500])).await.unwrap();
+        assert_eq!(count(&metrics.evictions), 4);
+        assert!(cache.get(&large).await.unwrap().is_some());
+        assert!(cache.get(&sids[0]).await.unwrap().is_none());
+
+        // a segment which was removed from disk after being indexed is a miss
+        // until it's put again
+        let sid = SegmentId::random();
+        cache.put(&sid, data(0)).await.unwrap();
+        std::fs::remove_file(dir.path().join(sid.pretty())).unwrap();
+        assert!(cache.get(&sid).await.unwrap().is_none());
+        cache.put(&sid, data(0)).await.unwrap();
+        assert_eq!(&*cache.get(&sid).await.unwrap().unwrap(), &data(0));
+
+        // pinned segments are never evicted, even if they were pinned before
+        // being put
+        let pinned = SegmentId::random();
+        cache.pin(&pinned).await;
+        cache.put(&pinned, data(1)).await.unwrap();
+        for _ in 0..3 {
+            cache.put(&SegmentId::random(), data(2)).await.unwrap();
+        }
+        assert_eq!(&*cache.get(&pinned).await.unwrap().unwrap(), &data(1));
+
+        // and may be evicted once they are unpinned
+        cache.unpin(&pinned).await;
+        for _ in 0..3 {
+            cache.put(&SegmentId::random(), data(2)).await.unwrap();
+        }
+        assert!(cache.get(&pinned).await.unwrap().is_none());
+    }
+
+    #[graft_test::test]
+    async fn test_disk_cache_warm() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = |space_limit| DiskCacheConfig {
+            path: Some(dir.path().to_path_buf()),
+            space_limit,
+            open_limit: 8,
+        };
+
+        let vid = VolumeId::random();
+        let mut segments = vec![];
+        {
+            let cache = DiskCache::new(Default::default(), config(ByteUnit::from_mb(1)))
+                .await
+                .unwrap();
+            for i in 0..3 {
+                let mut segment = OpenSegment::default();
+                segment
+                    .insert(vid.clone(), pageidx!(1), Page::test_filled(i))
+                    .unwrap();
+                let (sid, data) = segment.serialize(SegmentCompression::None);
+                cache.put(&sid, data).await.unwrap();
+                segments.push(sid);
+            }
+        }
+
+        // make the first segment the least recently written
+        let path = |sid: &SegmentId| dir.path().join(sid.pretty());
+        for (i, sid) in segments.iter().enumerate() {
+            let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1000 + i as u64);
+            std::fs::File::options()
+                .write(true)
+                .open(path(sid))
+                .unwrap()
+                .set_modified(modified)
+                .unwrap();
+        }
+
+        // simulate a partial write, a corrupt segment, and an unknown file
+        let partial = dir.path().join(format!("{TEMP_FILE_PREFIX}partial"));
+        std::fs::write(&partial, b"partial").unwrap();
+        let corrupt = SegmentId::random();
+        std::fs::write(path(&corrupt), b"corrupt").unwrap();
+        let unknown = dir.path().join("unknown");
+        std::fs::write(&unknown, b"unknown").unwrap();
+
+        // as well as a segment which is missing some of its pages
+        let mut segment = OpenSegment::default();
+        segment
+            .insert(vid.clone(), pageidx!(1), Page::test_filled(9))
+            .unwrap();
+        let (truncated, data) = segment.serialize(SegmentCompression::None);
+        let data: Vec<u8> = data.into_iter().flatten().collect();
+        std::fs::write(path(&truncated), &data[1..]).unwrap();
+
+        // reopening the cache loads the valid segments
+        let cache = DiskCache::new(Default::default(), config(ByteUnit::from_mb(1)))
+            .await
+            .unwrap();
+        for (i, sid) in segments.iter().enumerate() {
+            let data = cache.get(sid).await.unwrap().unwrap();
+            let segment = ClosedSegment::from_bytes(&data).unwrap();
+            assert_eq!(
+                segment.find_page(&vid, pageidx!(1)).unwrap(),
+                Some(Page::test_filled(i as u8).into())
+            );
+        }
+        assert!(cache.get(&corrupt).await.unwrap().is_none());
+        assert!(!partial.exists());
+        assert!(!path(&corrupt).exists());
+        assert!(cache.get(&truncated).await.unwrap().is_none());
+        assert!(!path(&truncated).exists());
+        assert!(unknown.exists());
+        drop(cache);
+
+        // reopening the cache with a smaller limit removes the least recently
+        // written segments
+        let segment_size = std::fs::metadata(path(&segments[0])).unwrap().len();
+        let cache = DiskCache::new(Default::default(), config(ByteUnit::new(segment_size * 2)))
+            .await
+            .unwrap();
+        assert!(cache.get(&segments[0]).await.unwrap().is_none());
+        assert!(!path(&segments[0]).exists());
+        assert!(cache.get(&segments[1]).await.unwrap().is_some());
+        assert!(cache.get(&segments[2]).await.unwrap().is_some());
+    }
 }
@@ -51,4 +51,9 @@ impl Cache for MemCache {
         let segments = self.segments.read().await;
         Ok(segments.find(sid).map(|s| s.data.clone()))
     }
+
+    // segments are never evicted from the MemCache
+    async fn pin(&self, _sid: &SegmentId) {}
+
+    async fn unpin(&self, _sid: &SegmentId) {}
 }
@@ -11,6 +11,7 @@ use graft_core::SegmentId;
 pub mod atomic_file;
 pub mod disk;
 pub mod mem;
+pub mod tiered;
 
 pub trait Cache: Send + Sync {
     type Item<'a>: Deref<Target = [u8]>
@@ -27,4 +28,11 @@ pub trait Cache: Send + Sync {
         &self,
         sid: &SegmentId,
     ) -> impl Future<Output = culprit::Result<Option<Self::Item<'_>>, io::Error>> + Send;
+
+    /// Prevent a segment from being evicted until it's unpinned. A segment
+    /// may be pinned before it's put into the cache.
+    fn pin(&self, sid: &SegmentId) -> impl Future<Output = ()> + Send;
+
+    /// Allow a pinned segment to be evicted
+    fn unpin(&self, sid: &SegmentId) -> impl Future<Output = ()> + Send;
 }
@@ -0,0 +1,333 @@
+//! A two tier cache which keeps frequently accessed segments in memory in
+//! front of a slower cache, usually the `DiskCache`.
+//!
+//! Every segment is stored in the lower tier. Segments which are read from the
+//! lower tier at least `promote_after` times are copied into the memory tier.
+//! When the memory tier is full, the least frequently accessed segments are
+//! demoted (dropped from memory) to make room, but only if they have been
+//! accessed less frequently than the segment being promoted. Access counts are
+//! periodically halved so that segments which were once hot are eventually
+//! demoted.
+
+use std::{
+    collections::HashMap,
+    io,
+    ops::Deref,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use bytes::{Buf, Bytes};
+use graft_core::{
+    SegmentId,
+    byte_unit::ByteUnit,
+    hash_table::{HTEntry, HashTable},
+};
+use measured::{Counter, MetricGroup};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+
+use super::Cache;
+
+/// The maximum number of lower tier segments whose access counts are tracked
+/// before the counts are aged
+const MAX_TRACKED_SEGMENTS: usize = 4096;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TieredCacheConfig {
+    /// The maximum amount of memory used by the memory tier.
+    pub space_limit: ByteUnit,
+
+    /// The number of reads from the lower tier after which a segment is
+    /// promoted into memory.
+    pub promote_after: u64,
+}
+
+impl Default for TieredCacheConfig {
+    fn default() -> Self {
+        Self {
+            space_limit: ByteUnit::from_mb(256),
+            promote_after: 2,
+        }
+    }
+}
+
+#[derive(MetricGroup, Default)]
+pub struct TieredCacheMetrics {
+    /// Number of lookups served from the memory tier
+    mem_hits: Counter,
+
+    /// Number of segments promoted into the memory tier
+    promotions: Counter,
+
+    /// Number of segments demoted from the memory tier
+    demotions: Counter,
+}
+
+struct MemSegment {
+    sid: SegmentId,
+    data: Bytes,
+    accesses: AtomicU64,
+}
+
+impl HTEntry for MemSegment {
+    type Key = SegmentId;
+
+    fn key(&self) -> &Self::Key {
+        &self.sid
+    }
+}
+
+#[derive(Default)]
+struct MemTier {
+    segments: HashTable<MemSegment>,
+
+    /// The total size of all segments in memory.
+    size: u64,
+
+    /// The number of accesses to segments in the lower tier.
+    lower_accesses: HashMap<SegmentId, u64>,
+}
+
+impl MemTier {
+    fn record_lower_access(&mut self, sid: &SegmentId) -> u64 {
+        if self.lower_accesses.len() >= MAX_TRACKED_SEGMENTS
+            && !self.lower_accesses.contains_key(sid)
+        {
+            self.lower_accesses.retain(|_, accesses| {
+                *accesses /= 2;
+                *accesses > 0
+            });
+        }
+        let accesses = self.lower_accesses.entry(sid.clone()).or_default();
+        *accesses += 1;
+        *accesses
+    }
+
+    /// Select the segments to demote in order to fit `size` more bytes within
+    /// `limit`. Only segments accessed less than `accesses` times may be
+    /// demoted. Returns None if there isn't enough room.
+    fn select_demotions(&self, size: u64, limit: u64, accesses: u64) -> Option<Vec<SegmentId>> {
+        if size > limit {
+            return None;
+        }
+        let mut candidates: Vec<_> = self
+            .segments
+            .iter()
+            .map(|segment| (segment.accesses.load(Ordering::Relaxed), segment))
+            .filter(|(segment_accesses, _)| *segment_accesses < accesses)
+            .collect();
+        candidates.sort_unstable_by_key(|(segment_accesses, _)| *segment_accesses);
+
+        let mut demotions = vec![];
+        let mut used = self.size;
+        for (_, segment) in candidates {
+            if used + size <= limit {
+                break;
+            }
+            used -= segment.data.len() as u64;
+            demotions.push(segment.sid.clone());
+        }
+        (used + size <= limit).then_some(demotions)
+    }
+
+    /// Halve the access count of every segment in memory
+    fn age(&self) {
+        for segment in self.segments.iter() {
+            let accesses = segment.accesses.load(Ordering::Relaxed);
+            segment.accesses.store(accesses / 2, Ordering::Relaxed);
+        }
+    }
+}
+
+/// A segment returned by the `TieredCache`
+pub enum TieredSegment<T> {
+    Mem(Bytes),
+    Lower(T),
+}
+
+impl<T: Deref<Target = [u8]>> Deref for TieredSegment<T> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Mem(data) => data,
+            Self::Lower(segment) => segment,
+        }
+    }
+}
+
+pub struct TieredCache<C> {
+    metrics: Arc<TieredCacheMetrics>,
+    config: TieredCacheConfig,
+    mem: RwLock<MemTier>,
+    lower: C,
+}
+
+impl<C: Cache> TieredCache<C> {
+    pub fn new(metrics: Arc<TieredCacheMetrics>, config: TieredCacheConfig, lower: C) -> Self {
+        Self {
+            metrics,
+            config,
+            mem: Default::default(),
+            lower,
+        }
+    }
+
+    /// Returns true if the segment is in the memory tier
+    pub fn in_memory(&self, sid: &SegmentId) -> bool {
+        self.mem.read().segments.has(sid)
+    }
+}
+
+impl<C: Cache> Cache for TieredCache<C> {
+    type Item<'a>
+        = TieredSegment<C::Item<'a>>
+    where
+        Self: 'a;
+
+    async fn put<T: Buf + Send + 'static>(
+        &self,
+        sid: &SegmentId,
+        data: T,
+    ) -> culprit::Result<(), io::Error> {
+        // segments start in the lower tier and are promoted once they are
+        // accessed frequently
+        self.lower.put(sid, data).await
+    }
+
+    async fn get(&self, sid: &SegmentId) -> culprit::Result<Option<Self::Item<'_>>, io::Error> {
+        if let Some(segment) = self.mem.read().segments.find(sid) {
+            self.metrics.mem_hits.inc();
+            segment.accesses.fetch_add(1, Ordering::Relaxed);
+            return Ok(Some(TieredSegment::Mem(segment.data.clone())));
+        }
+
+        let Some(segment) = self.lower.get(sid).await? else {
+            return Ok(None);
+        };
+
+        let mut mem = self.mem.write();
+        if mem.segments.has(sid) {
+            // another task promoted the segment
+            return Ok(Some(TieredSegment::Lower(segment)));
+        }
+        let accesses = mem.record_lower_access(sid);
+        if accesses < self.config.promote_after {
+            return Ok(Some(TieredSegment::Lower(segment)));
+        }
+
+        let size = segment.len() as u64;
+        let Some(demotions) =
+            mem.select_demotions(size, self.config.space_limit.as_u64(), accesses)
+        else {
+            return Ok(Some(TieredSegment::Lower(segment)));
+        };
+
+        for sid in demotions {
+            tracing::trace!("demoting segment {:?} from memory", sid);
+            let demoted = mem.segments.remove(&sid).expect("segment is in memory");
+            mem.size -= demoted.data.len() as u64;
+            self.metrics.demotions.inc();
+        }
+        mem.age();
+
+        tracing::trace!("promoting segment {:?} into memory", sid);
+        let data = Bytes::copy_from_slice(&segment);
+        mem.lower_accesses.remove(sid);
+        mem.segments.insert(MemSegment {
+            sid: sid.clone(),
+            data: data.clone(),
+            accesses: AtomicU64::new(accesses),
+        });
+        mem.size += size;
+        self.metrics.promotions.inc();
+
+        Ok(Some(TieredSegment::Mem(data)))
+    }
+
+    // every segment is stored in the lower tier, so only it needs to respect
+    // pins
+    async fn pin(&self, sid: &SegmentId) {
+        self.lower.pin(sid).await
+    }
+
+    async fn unpin(&self, sid: &SegmentId) {
+        self.lower.unpin(sid).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segment::cache::mem::MemCache;
+
+    use super::*;
+
+    fn count(counter: &Counter) -> u64 {
+        counter.get_metric().count.load(Ordering::Relaxed)
+    }
+
+    #[graft_test::test]
+    async fn test_tiered_cache() {
+        let metrics = Arc::new(TieredCacheMetrics::default());
+        let cache = TieredCache::new(
+            metrics.clone(),
+            TieredCacheConfig {
+                space_limit: ByteUnit::new(200),
+                promote_after: 2,
+            },
+            MemCache::default(),
+        );
+
+        let sids: Vec<_> = (0..3).map(|_| SegmentId::random()).collect();
+        let data = |i: usize| Bytes::from(vec![i as u8; 100]);
+        for (i, sid) in sids.iter().enumerate() {
+            cache.put(sid, data(i)).await.unwrap();
+        }
+        assert!(cache.get(&SegmentId::random()).await.unwrap().is_none());
+
+        // segments are promoted once they are accessed frequently
+        let read = async |i: usize| {
+            let segment = cache.get(&sids[i]).await.unwrap().unwrap();
+            assert_eq!(&*segment, &data(i));
+        };
+        read(0).await;
+        assert!(!cache.in_memory(&sids[0]));
+        read(0).await;
+        assert!(cache.in_memory(&sids[0]));
+        read(0).await;
+        assert_eq!(count(&metrics.promotions), 1);
+        assert_eq!(count(&metrics.mem_hits), 1);
+
+        // fill up the memory tier, and access both segments frequently
+        read(1).await;
+        read(1).await;
+        assert!(cache.in_memory(&sids[1]));
+        for _ in 0..3 {
+            read(0).await;
+            read(1).await;
+        }
+
+        // a segment which is accessed less frequently than the segments in
+        // memory is not promoted
+        for _ in 0..4 {
+            read(2).await;
+        }
+        assert!(!cache.in_memory(&sids[2]));
+        assert_eq!(count(&metrics.demotions), 0);
+
+        // once it's accessed more frequently, the least frequently accessed
+        // segment is demoted
+        read(2).await;
+        assert!(cache.in_memory(&sids[2]));
+        assert!(!cache.in_memory(&sids[0]));
+        assert!(cache.in_memory(&sids[1]));
+        assert_eq!(count(&metrics.demotions), 1);
+
+        // demoted segments are still served from the lower tier
+        read(0).await;
+    }
+}
@@ -1,26 +1,61 @@
 //! A closed segment is immutable and serialized. It can be directly mapped into
 //! memory and read from in an efficient way.
+//!
+//! Segment layout:
+//! - pages: `page_size` bytes per full page, where the page size is stored in
+//!   the footer. If the segment is compressed, each page is compressed
+//!   separately and may be shorter than `page_size`.
+//! - deltas: page deltas stored back to back, see `PageDelta`
+//! - delta table: a `DeltaEntry` per delta, sorted by local offset
+//! - page offsets: only present in compressed segments, a `U32` per full page
+//!   containing the offset of the end of the page
+//! - page checksums: a `PageChecksum` per page, in the same order as the
+//!   index. Checksums are calculated over the uncompressed page, or the
+//!   encoded delta.
+//! - index: see `SegmentIndex`
+//! - footer: see `SegmentFooter`
+//!
+//! The footer checksum covers the metadata following the deltas, rather than
+//! the entire segment. Since the metadata contains a checksum for every page,
+//! this allows a subset of pages to be loaded and verified without
+//! downloading the entire segment.
+//!
+//! Segments written before the footer recorded a format version end with a
+//! `LegacySegmentFooter` instead. These segments only contain uncompressed
+//! pages of the default page size, followed by the index, and have no
+//! checksums.
 
-use std::fmt::Debug;
+use std::{fmt::Debug, ops::Range};
 
+use bytes::Bytes;
 use culprit::{Culprit, ResultExt};
 use graft_core::{
-    PageCount, PageIdx, SegmentId, VolumeId,
-    byte_unit::ByteUnit,
-    page::{PAGESIZE, Page},
+    PageCount, PageIdx, PageSize, SegmentId, VolumeId, byte_unit::ByteUnit,
     zerocopy_ext::ZerocopyErr,
 };
 use thiserror::Error;
 use zerocopy::{
-    Immutable, IntoBytes, KnownLayout, TryFromBytes,
-    little_endian::{U16, U32},
+    FromBytes, Immutable, IntoBytes, KnownLayout, TryFromBytes,
+    little_endian::{U16, U32, U64},
 };
 
 use crate::segment::index::SegmentIndex;
 
-use super::index::SegmentIndexBuilder;
+use super::{
+    compression::SegmentCompression,
+    delta::{PageDelta, SegmentPage},
+    index::SegmentIndexBuilder,
+};
+
+pub const SEGMENT_MAGIC: U32 = U32::from_bytes([0x9E, 0x0F, 0xEE, 0x30]);
+
+/// The magic number of segments which end with a `LegacySegmentFooter`
+pub const LEGACY_SEGMENT_MAGIC: U32 = U32::from_bytes([0x9E, 0x0F, 0xEE, 0x2D]);
 
-pub const SEGMENT_MAGIC: U32 = U32::from_bytes([0x9E, 0x0F, 0xEE, 0x2D]);
+/// The version of the segment layout, stored in the footer of every segment.
+/// Changes to the layout must bump the version and keep a read path for
+/// segments written with earlier versions.
+pub const SEGMENT_VERSION: u8 = 1;
 
 // segments must be no larger than 16 MB
 pub const SEGMENT_MAX_SIZE: ByteUnit = ByteUnit::from_mb(16);
@@ -28,7 +63,21 @@ pub const SEGMENT_MAX_SIZE: ByteUnit = ByteUnit::from_mb(16);
 // the maximum number of pages a segment can store taking into
 // account index/footer overhead
 // This calculation is validated in test_segment_max_pages
-pub const SEGMENT_MAX_PAGES: PageCount = PageCount::new(4090);
+pub const SEGMENT_MAX_PAGES: PageCount = PageCount::new(4082);
+
+/// The maximum number of pages of the specified size a segment can store.
+/// `SEGMENT_MAX_PAGES` is calculated for the default page size, larger pages
+/// proportionally reduce the number of pages while smaller pages are limited
+/// by the size of the segment index.
+pub const fn segment_max_pages(page_size: PageSize) -> PageCount {
+    let default = PageSize::DEFAULT.as_u32();
+    let page_size = page_size.as_u32();
+    if page_size <= default {
+        SEGMENT_MAX_PAGES
+    } else {
+        PageCount::new(SEGMENT_MAX_PAGES.to_u32() / (page_size / default))
+    }
+}
 
 // the maximum number of volumes a segment can store pages for
 pub const SEGMENT_MAX_VOLUMES: usize = 400;
@@ -45,46 +94,195 @@ pub struct SegmentFooter {
     /// size of the index in bytes
     index_size: U16,
 
-    /// pad the footer to 32 bytes for future use
-    _padding: [u8; 8],
+    /// the size of every page in the segment
+    page_size: PageSize,
+
+    /// how pages in the segment are compressed
+    compression: SegmentCompression,
+
+    /// number of pages stored as deltas
+    deltas: U16,
+
+    /// the version of the segment layout
+    version: u8,
+
+    /// pad the footer to 64 bytes for future use
+    _padding: [u8; 3],
+
+    /// blake3 hash of every byte following the deltas and preceding the
+    /// checksum
+    checksum: [u8; 32],
 
     /// the last 4 bytes of the footer are reserved for a magic number
     magic: U32,
 }
 
-static_assertions::assert_eq_size!(SegmentFooter, [u8; 32]);
+static_assertions::assert_eq_size!(SegmentFooter, [u8; 64]);
+
+/// The number of bytes at the end of the segment which are not covered by the
+/// segment checksum
+const FOOTER_UNCHECKED_SIZE: usize = 32 + size_of::<U32>();
 
 impl SegmentFooter {
-    pub fn new(sid: SegmentId, volumes: usize, index_size: ByteUnit) -> Self {
+    /// Create a new footer. The checksum is calculated over the provided
+    /// segment metadata hasher along with the footer itself.
+    pub fn new(
+        sid: SegmentId,
+        volumes: usize,
+        index_size: ByteUnit,
+        page_size: PageSize,
+        compression: SegmentCompression,
+        deltas: usize,
+        mut meta: blake3::Hasher,
+    ) -> Self {
         assert!(volumes <= u16::MAX as usize);
         assert!(index_size.as_usize() <= u16::MAX as usize);
-        Self {
+        assert!(deltas <= u16::MAX as usize);
+        let mut footer = Self {
             _padding: Default::default(),
             sid,
             volumes: U16::new(volumes as u16),
             index_size: U16::new(index_size.as_u16()),
+            page_size,
+            compression,
+            deltas: U16::new(deltas as u16),
+            version: SEGMENT_VERSION,
+            checksum: Default::default(),
             magic: SEGMENT_MAGIC,
+        };
+        let footer_bytes = footer.as_bytes();
+        meta.update(&footer_bytes[..footer_bytes.len() - FOOTER_UNCHECKED_SIZE]);
+        footer.checksum = meta.finalize().into();
+        footer
+    }
+}
+
+/// The footer of segments written before segments were versioned
+#[derive(Clone, IntoBytes, TryFromBytes, Immutable, KnownLayout)]
+#[repr(C)]
+pub struct LegacySegmentFooter {
+    sid: SegmentId,
+    volumes: U16,
+    index_size: U16,
+    _padding: [u8; 8],
+    magic: U32,
+}
+
+static_assertions::assert_eq_size!(LegacySegmentFooter, [u8; 32]);
+
+/// A segment footer decoded from any supported version of the segment layout
+#[derive(Clone)]
+struct Footer {
+    /// the version of the segment layout; 0 for legacy segments
+    version: u8,
+    /// the size of the footer at the end of the segment
+    size: usize,
+    sid: SegmentId,
+    volumes: usize,
+    index_size: ByteUnit,
+    page_size: PageSize,
+    compression: SegmentCompression,
+    deltas: usize,
+    /// the checksum of the segment; legacy segments are not checksummed
+    checksum: Option<[u8; 32]>,
+}
+
+impl Footer {
+    /// Returns true if the segment has a footer checksum, which covers the
+    /// segment metadata. Legacy segments are not checksummed.
+    fn tail_checksum(&self) -> bool {
+        self.checksum.is_some()
+    }
+
+    /// Verify the checksum over `checked`, which must contain every byte
+    /// covered by the checksum followed by the rest of the footer
+    fn verify(&self, checked: &[u8]) -> Result<(), Culprit<SegmentValidationErr>> {
+        let Some(checksum) = self.checksum else {
+            return Ok(());
+        };
+        let checked = &checked[..checked.len() - FOOTER_UNCHECKED_SIZE];
+        if blake3::hash(checked) != blake3::Hash::from(checksum) {
+            return Err(Culprit::new_with_note(
+                SegmentValidationErr::ChecksumMismatch,
+                format!("segment {:?} failed checksum validation", self.sid),
+            ));
         }
+        Ok(())
     }
+}
 
-    fn sid(&self) -> &SegmentId {
-        &self.sid
+impl From<&SegmentFooter> for Footer {
+    fn from(footer: &SegmentFooter) -> Self {
+        Self {
+            version: footer.version,
+            size: size_of::<SegmentFooter>(),
+            sid: footer.sid.clone(),
+            volumes: footer.volumes.get().into(),
+            index_size: footer.index_size.get().into(),
+            page_size: footer.page_size,
+            compression: footer.compression,
+            deltas: footer.deltas.get().into(),
+            checksum: Some(footer.checksum),
+        }
     }
+}
 
-    fn volumes(&self) -> usize {
-        self.volumes.get().into()
+impl From<&LegacySegmentFooter> for Footer {
+    fn from(footer: &LegacySegmentFooter) -> Self {
+        Self {
+            version: 0,
+            size: size_of::<LegacySegmentFooter>(),
+            sid: footer.sid.clone(),
+            volumes: footer.volumes.get().into(),
+            index_size: footer.index_size.get().into(),
+            page_size: PageSize::DEFAULT,
+            compression: SegmentCompression::None,
+            deltas: 0,
+            checksum: None,
+        }
     }
+}
+
+/// Locates a page delta within a segment
+#[derive(Clone, Copy, IntoBytes, FromBytes, Immutable, KnownLayout)]
+#[repr(C)]
+pub struct DeltaEntry {
+    /// the local offset of the page in the segment index
+    local_offset: U16,
+
+    /// the offset of the end of the delta in the delta data
+    end: U32,
+}
 
-    fn index_size(&self) -> ByteUnit {
-        self.index_size.get().into()
+impl DeltaEntry {
+    pub fn new(local_offset: usize, end: usize) -> Self {
+        Self {
+            local_offset: U16::new(local_offset as u16),
+            end: U32::new(end as u32),
+        }
     }
 }
 
-static_assertions::const_assert_eq!(size_of::<SegmentFooter>(), 32);
+static_assertions::const_assert_eq!(size_of::<SegmentFooter>(), 64);
 
-pub fn closed_segment_size(volumes: usize, pages: PageCount) -> ByteUnit {
+/// The first 8 bytes of the blake3 hash of a page
+pub type PageChecksum = U64;
+
+pub fn page_checksum(page: &[u8]) -> PageChecksum {
+    let hash = blake3::hash(page);
+    PageChecksum::read_from_prefix(hash.as_bytes())
+        .expect("blake3 hash is larger than a page checksum")
+        .0
+}
+
+/// The size of a serialized uncompressed segment without deltas. Compressed
+/// segments are only written if they are smaller than this size, and a page is
+/// only stored as a delta if the delta and its `DeltaEntry` are smaller than
+/// the page.
+pub fn closed_segment_size(volumes: usize, pages: PageCount, page_size: PageSize) -> ByteUnit {
     let index_size = SegmentIndexBuilder::serialized_size(volumes, pages);
-    (PAGESIZE * pages.to_usize()) + index_size + size_of::<SegmentFooter>()
+    let checksums_size = size_of::<PageChecksum>() * pages.to_usize();
+    pages.size(page_size) + checksums_size + index_size + size_of::<SegmentFooter>()
 }
 
 #[derive(Debug, Error)]
@@ -99,16 +297,329 @@ pub enum SegmentValidationErr {
     Magic,
     #[error("corrupt segment index")]
     CorruptIndex(ZerocopyErr),
-    #[error("page storage length must be a multiple of {}", PAGESIZE)]
+    #[error("invalid page size or page storage length")]
     InvalidPageSize,
     #[error("segment has invalid page count")]
     InvalidPageCount,
+    #[error("segment has invalid page offsets")]
+    InvalidPageOffsets,
+    #[error("segment has an invalid delta table")]
+    InvalidDeltaTable,
+    #[error("failed to decompress page")]
+    DecompressErr,
+    #[error("segment checksum mismatch")]
+    ChecksumMismatch,
+    #[error("unsupported segment version {0}")]
+    UnsupportedVersion(u8),
 }
 
-pub struct ClosedSegment<'a> {
-    page_data: &'a [u8],
+/// The sections of a segment between the deltas and the footer, which locate
+/// and verify each page in the segment
+struct SegmentMeta<'a> {
+    page_size: PageSize,
+    compression: SegmentCompression,
+    /// the end offset of each full page; empty if the segment is not
+    /// compressed
+    page_offsets: &'a [U32],
+    delta_table: &'a [DeltaEntry],
+    /// the checksum of each page; None if the segment is a legacy segment
+    page_checksums: Option<&'a [PageChecksum]>,
     index: SegmentIndex<'a>,
-    footer: &'a SegmentFooter,
+}
+
+/// The location of a page or delta within a segment
+#[derive(Debug, Clone)]
+enum PageLocation {
+    Full(Range<usize>),
+    Delta(Range<usize>),
+}
+
+impl PageLocation {
+    fn range(&self) -> Range<usize> {
+        match self {
+            Self::Full(range) | Self::Delta(range) => range.clone(),
+        }
+    }
+}
+
+impl<'a> SegmentMeta<'a> {
+    /// The size of the metadata described by the footer, or None if the
+    /// footer is inconsistent
+    fn size(footer: &Footer) -> Option<usize> {
+        let pages =
+            SegmentIndex::pages_for_size(footer.index_size.as_usize(), footer.volumes)?.to_usize();
+        let full_pages = pages.checked_sub(footer.deltas)?;
+        let offsets = if footer.compression.is_none() {
+            0
+        } else {
+            full_pages
+        };
+        let checksums = if footer.checksum.is_some() { pages } else { 0 };
+        Some(
+            size_of::<DeltaEntry>() * footer.deltas
+                + size_of::<U32>() * offsets
+                + size_of::<PageChecksum>() * checksums
+                + footer.index_size.as_usize(),
+        )
+    }
+
+    /// Parse the metadata from the end of `data`, which must not include the
+    /// footer. Returns the remaining data, which contains the pages and
+    /// deltas.
+    fn split_from(
+        data: &'a [u8],
+        footer: &Footer,
+    ) -> Result<(&'a [u8], Self), Culprit<SegmentValidationErr>> {
+        let page_size = footer.page_size;
+
+        let (data, index_data) = data
+            .split_at_checked(data.len().wrapping_sub(footer.index_size.as_usize()))
+            .ok_or_else(|| Culprit::new(SegmentValidationErr::TooSmall))?;
+
+        // load the index
+        let index = SegmentIndex::from_bytes(index_data, footer.volumes)
+            .or_ctx(SegmentValidationErr::CorruptIndex)?;
+
+        // split off the page checksums
+        let (data, page_checksums) = if footer.checksum.is_none() {
+            (data, None)
+        } else {
+            let checksums_size = size_of::<PageChecksum>() * index.pages().to_usize();
+            let (data, checksum_data) = data
+                .split_at_checked(data.len().saturating_sub(checksums_size))
+                .ok_or_else(|| Culprit::new(SegmentValidationErr::TooSmall))?;
+            let page_checksums = <[PageChecksum]>::ref_from_bytes(checksum_data)
+                .or_ctx(|err| SegmentValidationErr::CorruptIndex(err.into()))?;
+            if page_checksums.len() != index.pages().to_usize() {
+                return Err(Culprit::new(SegmentValidationErr::TooSmall));
+            }
+            (data, Some(page_checksums))
+        };
+
+        let deltas = footer.deltas;
+        if deltas > index.pages().to_usize() {
+            return Err(Culprit::new_with_note(
+                SegmentValidationErr::InvalidDeltaTable,
+                format!(
+                    "segment contains {deltas} deltas but only {} pages",
+                    index.pages()
+                ),
+            ));
+        }
+        let full_pages = index.pages().to_usize() - deltas;
+
+        // split off the page offsets
+        let (data, page_offsets): (&[u8], &[U32]) = if footer.compression.is_none() {
+            (data, &[])
+        } else {
+            let offsets_size = size_of::<U32>() * full_pages;
+            let (data, offsets_data) = data
+                .split_at_checked(data.len().saturating_sub(offsets_size))
+                .ok_or_else(|| Culprit::new(SegmentValidationErr::TooSmall))?;
+            let page_offsets = <[U32]>::ref_from_bytes(offsets_data)
+                .or_ctx(|err| SegmentValidationErr::CorruptIndex(err.into()))?;
+            if page_offsets.len() != full_pages {
+                return Err(Culprit::new(SegmentValidationErr::TooSmall));
+            }
+            (data, page_offsets)
+        };
+
+        // validate that the pages are in order and no larger than the page size
+        let mut start = 0;
+        for end in page_offsets.iter().map(|o| o.get() as usize) {
+            if end <= start || end - start > page_size.as_usize() {
+                return Err(Culprit::new_with_note(
+                    SegmentValidationErr::InvalidPageOffsets,
+                    format!("invalid page range {start}..{end}"),
+                ));
+            }
+            start = end;
+        }
+
+        // split off the delta table
+        let table_size = size_of::<DeltaEntry>() * deltas;
+        let (data, table_data) = data
+            .split_at_checked(data.len().saturating_sub(table_size))
+            .ok_or_else(|| Culprit::new(SegmentValidationErr::TooSmall))?;
+        let delta_table = <[DeltaEntry]>::ref_from_bytes(table_data)
+            .or_ctx(|err| SegmentValidationErr::CorruptIndex(err.into()))?;
+        if delta_table.len() != deltas {
+            return Err(Culprit::new(SegmentValidationErr::TooSmall));
+        }
+
+        // validate that the deltas are sorted by local offset, no larger than
+        // the page size, and in order
+        let mut prev: Option<usize> = None;
+        let mut start = 0;
+        for entry in delta_table {
+            let local_offset = entry.local_offset.get() as usize;
+            let end = entry.end.get() as usize;
+            if prev.is_some_and(|prev| local_offset <= prev)
+                || local_offset >= index.pages().to_usize()
+                || end < start
+                || end - start > page_size.as_usize()
+            {
+                return Err(Culprit::new_with_note(
+                    SegmentValidationErr::InvalidDeltaTable,
+                    format!("invalid delta for local offset {local_offset} at {start}..{end}"),
+                ));
+            }
+            prev = Some(local_offset);
+            start = end;
+        }
+
+        Ok((
+            data,
+            Self {
+                page_size,
+                compression: footer.compression,
+                page_offsets,
+                delta_table,
+                page_checksums,
+                index,
+            },
+        ))
+    }
+
+    fn full_pages(&self) -> usize {
+        self.index.pages().to_usize() - self.delta_table.len()
+    }
+
+    /// The size of the stored full pages
+    fn pages_size(&self) -> usize {
+        if self.page_offsets.is_empty() {
+            self.full_pages() * self.page_size.as_usize()
+        } else {
+            self.page_offsets.last().map_or(0, |end| end.get() as usize)
+        }
+    }
+
+    /// The size of the stored deltas
+    fn deltas_size(&self) -> usize {
+        self.delta_table
+            .last()
+            .map_or(0, |entry| entry.end.get() as usize)
+    }
+
+    /// Locate the page or delta stored at the local offset, relative to the
+    /// start of the segment
+    fn locate(&self, local_offset: usize) -> PageLocation {
+        let search = self
+            .delta_table
+            .binary_search_by_key(&local_offset, |entry| entry.local_offset.get() as usize);
+        match search {
+            Ok(delta) => {
+                let start = match delta {
+                    0 => 0,
+                    n => self.delta_table[n - 1].end.get() as usize,
+                };
+                let end = self.delta_table[delta].end.get() as usize;
+                let base = self.pages_size();
+                PageLocation::Delta(base + start..base + end)
+            }
+            Err(preceding_deltas) => {
+                let offset = local_offset - preceding_deltas;
+                if self.page_offsets.is_empty() {
+                    let page_size = self.page_size.as_usize();
+                    let start = offset * page_size;
+                    PageLocation::Full(start..start + page_size)
+                } else {
+                    let start = match offset {
+                        0 => 0,
+                        n => self.page_offsets[n - 1].get() as usize,
+                    };
+                    let end = self.page_offsets[offset].get() as usize;
+                    PageLocation::Full(start..end)
+                }
+            }
+        }
+    }
+
+    /// Decode the stored bytes of a page or delta
+    fn decode(
+        &self,
+        location: &PageLocation,
+        stored: &[u8],
+    ) -> Result<SegmentPage, Culprit<SegmentValidationErr>> {
+        match location {
+            PageLocation::Delta(_) => Ok(SegmentPage::Delta(PageDelta::from(
+                Bytes::copy_from_slice(stored),
+            ))),
+            PageLocation::Full(_) => {
+                let page = self
+                    .compression
+                    .decompress(stored, self.page_size)
+                    .or_ctx(|_| SegmentValidationErr::DecompressErr)?;
+                Ok(SegmentPage::Full(page))
+            }
+        }
+    }
+
+    /// Verify a page against its checksum. Pages in legacy segments have no
+    /// checksum and are not verified.
+    fn verify(
+        &self,
+        local_offset: usize,
+        page: &SegmentPage,
+        sid: &SegmentId,
+        vid: &VolumeId,
+        pageidx: PageIdx,
+    ) -> Result<(), Culprit<SegmentValidationErr>> {
+        let Some(page_checksums) = self.page_checksums else {
+            return Ok(());
+        };
+        let checksum = match page {
+            SegmentPage::Full(page) => page_checksum(page),
+            SegmentPage::Delta(delta) => page_checksum(delta.as_ref()),
+        };
+        if checksum != page_checksums[local_offset] {
+            return Err(Culprit::new_with_note(
+                SegmentValidationErr::ChecksumMismatch,
+                format!("page {vid:?}/{pageidx} in segment {sid:?} failed checksum validation"),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Parse and validate the footer and page size of a segment
+fn parse_footer(data: &[u8]) -> Result<(&[u8], Footer), Culprit<SegmentValidationErr>> {
+    // legacy segments are identified by the magic number at the end of their
+    // footer
+    if data.ends_with(LEGACY_SEGMENT_MAGIC.as_bytes()) {
+        let (data, footer) = LegacySegmentFooter::try_ref_from_suffix(data)
+            .or_ctx(|err| SegmentValidationErr::CorruptFooter(err.into()))?;
+        return Ok((data, footer.into()));
+    }
+
+    let (data, footer) = SegmentFooter::try_ref_from_suffix(data)
+        .or_ctx(|err| SegmentValidationErr::CorruptFooter(err.into()))?;
+
+    if footer.magic != SEGMENT_MAGIC {
+        return Err(Culprit::new(SegmentValidationErr::Magic));
+    }
+
+    if footer.version != SEGMENT_VERSION {
+        return Err(Culprit::new(SegmentValidationErr::UnsupportedVersion(
+            footer.version,
+        )));
+    }
+
+    let page_size = footer.page_size;
+    if !page_size.is_valid() {
+        return Err(Culprit::new_with_note(
+            SegmentValidationErr::InvalidPageSize,
+            format!("invalid page size {page_size:?}"),
+        ));
+    }
+
+    Ok((data, footer.into()))
+}
+
+pub struct ClosedSegment<'a> {
+    data: &'a [u8],
+    meta: SegmentMeta<'a>,
+    footer: Footer,
 }
 
 impl<'a> ClosedSegment<'a> {
@@ -123,73 +634,254 @@ impl<'a> ClosedSegment<'a> {
             ));
         }
 
-        let (data, footer) = SegmentFooter::try_ref_from_suffix(data)
-            .or_ctx(|err| SegmentValidationErr::CorruptFooter(err.into()))?;
+        let full_data = data;
+        let (data, footer) = parse_footer(data)?;
+        let (data, meta) = SegmentMeta::split_from(data, &footer)?;
 
-        if footer.magic != SEGMENT_MAGIC {
-            return Err(Culprit::new(SegmentValidationErr::Magic));
-        }
-
-        let (page_data, index_data) = data
-            .split_at_checked(data.len() - footer.index_size().as_usize())
+        // split off the deltas
+        let deltas_size = meta.deltas_size();
+        let (page_data, _) = data
+            .split_at_checked(data.len().saturating_sub(deltas_size))
+            .filter(|(_, delta_data)| delta_data.len() == deltas_size)
             .ok_or_else(|| Culprit::new(SegmentValidationErr::TooSmall))?;
 
-        // load the index
-        let index = SegmentIndex::from_bytes(index_data, footer.volumes())
-            .or_ctx(SegmentValidationErr::CorruptIndex)?;
-
-        // validate pages
-        if page_data.len() % PAGESIZE != 0 {
-            return Err(Culprit::new(SegmentValidationErr::InvalidPageSize));
-        }
-        if page_data.len() / PAGESIZE != index.pages().to_usize() {
-            let actual = (page_data.len() / PAGESIZE).as_usize();
-            let expected = index.pages().to_usize();
+        let page_size = meta.page_size;
+        let full_pages = meta.full_pages();
+        if meta.page_offsets.is_empty() {
+            // validate pages
+            if page_data.len() % page_size.as_usize() != 0 {
+                return Err(Culprit::new(SegmentValidationErr::InvalidPageSize));
+            }
+            if page_data.len() / page_size.as_usize() != full_pages {
+                let actual = page_data.len() / page_size.as_usize();
+                return Err(Culprit::new_with_note(
+                    SegmentValidationErr::InvalidPageCount,
+                    format!("segment contains {actual} pages; expected {full_pages}"),
+                ));
+            }
+        } else if meta.pages_size() != page_data.len() {
+            // the pages must exactly cover the page data
             return Err(Culprit::new_with_note(
-                SegmentValidationErr::InvalidPageCount,
-                format!("segment contains {actual} pages; expected {expected}"),
+                SegmentValidationErr::InvalidPageOffsets,
+                format!(
+                    "pages end at offset {}; expected {}",
+                    meta.pages_size(),
+                    page_data.len()
+                ),
             ));
         }
 
-        Ok(Self { page_data, index, footer })
+        Ok(Self { data: full_data, meta, footer })
+    }
+
+    /// Verify the checksum of the entire segment. This is relatively
+    /// expensive, so it should only be done when a segment is first loaded
+    /// from the object store. Individual pages are verified as they are read.
+    /// Legacy segments have no checksum, so they always pass.
+    pub fn verify_checksum(&self) -> Result<(), Culprit<SegmentValidationErr>> {
+        if !self.footer.tail_checksum() {
+            return Ok(());
+        }
+
+        // the footer checksum covers the metadata, which contains the checksum
+        // of every page
+        let meta_start = self.meta.pages_size() + self.meta.deltas_size();
+        self.footer.verify(&self.data[meta_start..])?;
+        for page in self.iter() {
+            page?;
+        }
+        Ok(())
     }
 
     pub fn pages(&self) -> PageCount {
-        self.index.pages()
+        self.meta.index.pages()
+    }
+
+    pub fn page_size(&self) -> PageSize {
+        self.footer.page_size
+    }
+
+    pub fn compression(&self) -> SegmentCompression {
+        self.footer.compression
+    }
+
+    /// The version of the segment layout; 0 for legacy segments
+    pub fn version(&self) -> u8 {
+        self.footer.version
     }
 
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.index.is_empty()
+        self.meta.index.is_empty()
     }
 
     pub fn sid(&self) -> &SegmentId {
-        self.footer.sid()
+        &self.footer.sid
     }
 
-    pub fn find_page(&self, vid: &VolumeId, pageidx: PageIdx) -> Option<Page> {
-        self.index.lookup(vid, pageidx).map(|local_offset| {
-            let start = local_offset * PAGESIZE;
-            let end = start + PAGESIZE;
-            (&self.page_data[start.range(end)])
-                .try_into()
-                .expect("invalid page")
-        })
+    /// The number of pages stored as deltas
+    pub fn deltas(&self) -> usize {
+        self.meta.delta_table.len()
+    }
+
+    /// Find a page in the segment, verifying it against its checksum.
+    pub fn find_page(
+        &self,
+        vid: &VolumeId,
+        pageidx: PageIdx,
+    ) -> Result<Option<SegmentPage>, Culprit<SegmentValidationErr>> {
+        let Some(local_offset) = self.meta.index.lookup(vid, pageidx) else {
+            return Ok(None);
+        };
+        let page = self.load_page(local_offset)?;
+        self.meta
+            .verify(local_offset, &page, self.sid(), vid, pageidx)?;
+        Ok(Some(page))
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = (&VolumeId, PageIdx, Page)> {
-        self.index
+    /// Iterate over every page in the segment, verifying each page against its
+    /// checksum.
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = Result<(&VolumeId, PageIdx, SegmentPage), Culprit<SegmentValidationErr>>>
+    {
+        self.meta
+            .index
             .iter()
             .zip(0usize..)
-            .map(|((vid, pageidx), local_offset)| {
-                let start = local_offset * PAGESIZE;
-                let end = start + PAGESIZE;
-                let page = (&self.page_data[start.range(end)])
-                    .try_into()
-                    .expect("invalid page");
-                (vid, pageidx, page)
+            .map(move |((vid, pageidx), local_offset)| {
+                let page = self.load_page(local_offset)?;
+                self.meta
+                    .verify(local_offset, &page, self.sid(), vid, pageidx)?;
+                Ok((vid, pageidx, page))
             })
     }
+
+    /// Load the page or delta stored at the local offset
+    fn load_page(&self, local_offset: usize) -> Result<SegmentPage, Culprit<SegmentValidationErr>> {
+        let location = self.meta.locate(local_offset);
+        self.meta.decode(&location, &self.data[location.range()])
+    }
+}
+
+/// The metadata of a segment whose pages have not been loaded. Used to load
+/// individual pages from a segment via range requests, without downloading
+/// the entire segment.
+pub struct PartialSegment<'a> {
+    meta: SegmentMeta<'a>,
+    footer: Footer,
+}
+
+/// A page located in a `PartialSegment`
+#[derive(Debug, Clone)]
+pub struct PartialPage {
+    vid: VolumeId,
+    pageidx: PageIdx,
+    local_offset: usize,
+    location: PageLocation,
+}
+
+impl PartialPage {
+    pub fn pageidx(&self) -> PageIdx {
+        self.pageidx
+    }
+
+    /// The range of bytes storing the page within the segment
+    pub fn range(&self) -> Range<usize> {
+        self.location.range()
+    }
+}
+
+impl<'a> PartialSegment<'a> {
+    /// The number of bytes at the end of a segment which are guaranteed to
+    /// contain its footer, regardless of the segment's version
+    pub const FOOTER_SIZE: usize = size_of::<SegmentFooter>();
+
+    /// Returns the number of bytes at the end of the segment which must be
+    /// loaded to construct a `PartialSegment`, given the last `FOOTER_SIZE`
+    /// bytes of the segment
+    pub fn tail_size(footer: &[u8]) -> Result<usize, Culprit<SegmentValidationErr>> {
+        let (_, footer) = parse_footer(footer)?;
+        let meta_size = SegmentMeta::size(&footer).ok_or_else(|| {
+            Culprit::new_with_note(
+                SegmentValidationErr::TooSmall,
+                "footer describes an invalid index",
+            )
+        })?;
+        Ok(meta_size + footer.size)
+    }
+
+    /// Parse the tail of a segment, as sized by `tail_size`. `size` is the
+    /// size of the entire segment. The tail is verified against the footer
+    /// checksum if the segment supports it, see `is_verified`.
+    pub fn from_tail(size: usize, tail: &'a [u8]) -> Result<Self, Culprit<SegmentValidationErr>> {
+        if size > SEGMENT_MAX_SIZE {
+            return Err(Culprit::new(SegmentValidationErr::TooLarge));
+        }
+        let (data, footer) = parse_footer(tail)?;
+        let (data, meta) = SegmentMeta::split_from(data, &footer)?;
+        if !data.is_empty() {
+            return Err(Culprit::new_with_note(
+                SegmentValidationErr::TooSmall,
+                "segment tail contains more than the segment metadata",
+            ));
+        }
+
+        // validate that the pages and deltas exactly fill the rest of the segment
+        let expected = meta.pages_size() + meta.deltas_size() + tail.len();
+        if expected != size {
+            return Err(Culprit::new_with_note(
+                SegmentValidationErr::InvalidPageCount,
+                format!("segment is {size} bytes; expected {expected}"),
+            ));
+        }
+
+        if footer.tail_checksum() {
+            footer.verify(tail)?;
+        }
+
+        Ok(Self { meta, footer })
+    }
+
+    pub fn sid(&self) -> &SegmentId {
+        &self.footer.sid
+    }
+
+    /// Returns true if the segment metadata was verified against the footer
+    /// checksum, and thus pages decoded from this segment are fully verified.
+    /// Legacy segments have no checksum, so they are never verified.
+    pub fn is_verified(&self) -> bool {
+        self.footer.tail_checksum()
+    }
+
+    /// Locate a page in the segment
+    pub fn locate(&self, vid: &VolumeId, pageidx: PageIdx) -> Option<PartialPage> {
+        let local_offset = self.meta.index.lookup(vid, pageidx)?;
+        Some(PartialPage {
+            vid: vid.clone(),
+            pageidx,
+            local_offset,
+            location: self.meta.locate(local_offset),
+        })
+    }
+
+    /// Decode a page from the bytes stored in its range, verifying it against
+    /// its checksum
+    pub fn decode_page(
+        &self,
+        page: &PartialPage,
+        stored: &[u8],
+    ) -> Result<SegmentPage, Culprit<SegmentValidationErr>> {
+        let decoded = self.meta.decode(&page.location, stored)?;
+        self.meta.verify(
+            page.local_offset,
+            &decoded,
+            self.sid(),
+            &page.vid,
+            page.pageidx,
+        )?;
+        Ok(decoded)
+    }
 }
 
 impl Debug for ClosedSegment<'_> {
@@ -200,16 +892,29 @@ impl Debug for ClosedSegment<'_> {
     }
 }
 
+impl Debug for PartialSegment<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PartialSegment")
+            .field("sid", self.sid())
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::vec;
 
     use assert_matches::assert_matches;
-    use bytes::BytesMut;
-    use graft_core::pageidx;
+    use bytes::{Buf, BytesMut};
+    use graft_core::{
+        page::{PAGESIZE, Page},
+        pageidx,
+    };
     use zerocopy::U16;
 
-    use crate::bytes_vec::BytesVec;
+    use crate::{bytes_vec::BytesVec, segment::open::OpenSegment};
+
+    use super::super::compression::SegmentCompression;
 
     use super::*;
 
@@ -241,7 +946,12 @@ mod tests {
             sid: SegmentId::random(),
             volumes: U16::new(0),
             index_size: U16::new(0),
+            page_size: PageSize::DEFAULT,
+            compression: SegmentCompression::None,
             _padding: Default::default(),
+            deltas: U16::new(0),
+            version: SEGMENT_VERSION,
+            checksum: Default::default(),
             magic: U32::from_bytes([0x00, 0x3B, 0x41, 0x00]),
         };
         assert_matches!(
@@ -256,7 +966,12 @@ mod tests {
             sid: SegmentId::random(),
             volumes: U16::new(0),
             index_size: U16::new(0),
+            page_size: PageSize::DEFAULT,
+            compression: SegmentCompression::None,
             _padding: Default::default(),
+            deltas: U16::new(0),
+            version: SEGMENT_VERSION,
+            checksum: Default::default(),
             magic: U32::from_bytes([0x00, 0x3B, 0x41, 0x00]),
         };
         let mut bytes: BytesMut = footer.as_bytes().into();
@@ -273,7 +988,12 @@ mod tests {
             sid: SegmentId::random(),
             volumes: U16::new(0),
             index_size: U16::new(0),
+            page_size: PageSize::DEFAULT,
+            compression: SegmentCompression::None,
             _padding: Default::default(),
+            deltas: U16::new(0),
+            version: SEGMENT_VERSION,
+            checksum: Default::default(),
             magic: SEGMENT_MAGIC,
         };
         let mut bytes = BytesMut::zeroed((PAGESIZE / 2).as_usize());
@@ -283,9 +1003,30 @@ mod tests {
             SegmentValidationErr::InvalidPageSize
         );
 
+        // test unsupported page size err
+        let footer = SegmentFooter {
+            sid: SegmentId::random(),
+            volumes: U16::new(0),
+            index_size: U16::new(0),
+            page_size: PageSize::read_from_bytes(&[0]).unwrap(),
+            compression: SegmentCompression::None,
+            _padding: Default::default(),
+            deltas: U16::new(0),
+            version: SEGMENT_VERSION,
+            checksum: Default::default(),
+            magic: SEGMENT_MAGIC,
+        };
+        assert_matches!(
+            ClosedSegment::from_bytes(footer.as_bytes())
+                .unwrap_err()
+                .ctx(),
+            SegmentValidationErr::InvalidPageSize
+        );
+
         // test invalid page count err
         let mut buf = BytesVec::default();
         buf.put(BytesMut::zeroed(PAGESIZE.as_usize()).freeze());
+        buf.put_slice([PageChecksum::ZERO; 2].as_bytes());
         let mut index = SegmentIndexBuilder::new_with_capacity(1, PageCount::new(2));
         let vid = VolumeId::random();
         index.insert(&vid, pageidx!(1));
@@ -296,7 +1037,12 @@ mod tests {
                 sid: SegmentId::random(),
                 volumes: U16::new(1),
                 index_size: U16::new(index_size.as_u16()),
+                page_size: PageSize::DEFAULT,
+                compression: SegmentCompression::None,
                 _padding: Default::default(),
+                deltas: U16::new(0),
+                version: SEGMENT_VERSION,
+                checksum: Default::default(),
                 magic: SEGMENT_MAGIC,
             }
             .as_bytes(),
@@ -308,11 +1054,214 @@ mod tests {
         );
     }
 
+    #[graft_test::test]
+    fn test_segment_checksums() {
+        let vid = VolumeId::random();
+        let mut segment = OpenSegment::default();
+        segment
+            .insert(vid.clone(), pageidx!(1), Page::test_filled(1))
+            .unwrap();
+        segment
+            .insert(vid.clone(), pageidx!(2), Page::test_filled(2))
+            .unwrap();
+        let (_, buf) = segment.serialize(SegmentCompression::None);
+        let buf = buf.into_bytes();
+
+        let closed = ClosedSegment::from_bytes(&buf).unwrap();
+        closed.verify_checksum().unwrap();
+
+        // corrupt the second page
+        let mut corrupt = BytesMut::from(buf.as_ref());
+        corrupt[PAGESIZE.as_usize() + 1] ^= 0xFF;
+        let closed = ClosedSegment::from_bytes(&corrupt).unwrap();
+        assert_matches!(
+            closed.verify_checksum().unwrap_err().ctx(),
+            SegmentValidationErr::ChecksumMismatch
+        );
+        assert_eq!(
+            closed.find_page(&vid, pageidx!(1)).unwrap(),
+            Some(Page::test_filled(1).into())
+        );
+        assert_matches!(
+            closed.find_page(&vid, pageidx!(2)).unwrap_err().ctx(),
+            SegmentValidationErr::ChecksumMismatch
+        );
+        let mut iter = closed.iter();
+        assert!(iter.next().unwrap().is_ok());
+        assert_matches!(
+            iter.next().unwrap().unwrap_err().ctx(),
+            SegmentValidationErr::ChecksumMismatch
+        );
+
+        // corrupt the page checksums, which are covered by the footer checksum
+        let checksums_offset = buf.len()
+            - size_of::<SegmentFooter>()
+            - SegmentIndexBuilder::serialized_size(1, PageCount::new(2)).as_usize()
+            - size_of::<PageChecksum>();
+        let mut corrupt = BytesMut::from(buf.as_ref());
+        corrupt[checksums_offset] ^= 0xFF;
+        let closed = ClosedSegment::from_bytes(&corrupt).unwrap();
+        assert_matches!(
+            closed.verify_checksum().unwrap_err().ctx(),
+            SegmentValidationErr::ChecksumMismatch
+        );
+        let tail_size = PartialSegment::tail_size(&corrupt[checksums_offset..]).unwrap();
+        assert_matches!(
+            PartialSegment::from_tail(corrupt.len(), &corrupt[corrupt.len() - tail_size..])
+                .unwrap_err()
+                .ctx(),
+            SegmentValidationErr::ChecksumMismatch
+        );
+
+        // segments written by a newer version are rejected
+        let mut newer = BytesMut::from(buf.as_ref());
+        let version_offset = newer.len() - size_of::<SegmentFooter>() + 24;
+        assert_eq!(newer[version_offset], SEGMENT_VERSION);
+        newer[version_offset] += 1;
+        assert_matches!(
+            ClosedSegment::from_bytes(&newer).unwrap_err().ctx(),
+            SegmentValidationErr::UnsupportedVersion(2)
+        );
+    }
+
+    #[graft_test::test]
+    fn test_legacy_segment() {
+        // build a segment using the layout from before segments were versioned
+        let vid = VolumeId::random();
+        let sid = SegmentId::random();
+        let mut buf = BytesVec::default();
+        buf.put_slice(Page::test_filled(1).as_ref());
+        buf.put_slice(Page::test_filled(2).as_ref());
+        let mut index = SegmentIndexBuilder::new_with_capacity(1, PageCount::new(2));
+        index.insert(&vid, pageidx!(1));
+        index.insert(&vid, pageidx!(2));
+        let index_size = index.finish(&mut buf);
+        buf.put_slice(
+            LegacySegmentFooter {
+                sid: sid.clone(),
+                volumes: U16::new(1),
+                index_size: U16::new(index_size.as_u16()),
+                _padding: Default::default(),
+                magic: LEGACY_SEGMENT_MAGIC,
+            }
+            .as_bytes(),
+        );
+        let buf = buf.into_bytes();
+
+        let closed = ClosedSegment::from_bytes(&buf).unwrap();
+        closed.verify_checksum().unwrap();
+        assert_eq!(closed.version(), 0);
+        assert_eq!(closed.sid(), &sid);
+        assert_eq!(closed.page_size(), PageSize::DEFAULT);
+        assert_eq!(closed.pages(), 2);
+        assert_eq!(
+            closed.find_page(&vid, pageidx!(2)).unwrap(),
+            Some(Page::test_filled(2).into())
+        );
+        let found: Vec<_> = closed.iter().map(|r| r.unwrap().2).collect();
+        assert_eq!(
+            found,
+            [Page::test_filled(1), Page::test_filled(2)].map(SegmentPage::from)
+        );
+
+        // legacy segments can also be read via range requests
+        let footer = &buf[buf.len() - PartialSegment::FOOTER_SIZE..];
+        let tail_size = PartialSegment::tail_size(footer).unwrap();
+        let partial = PartialSegment::from_tail(buf.len(), &buf[buf.len() - tail_size..]).unwrap();
+        let page = partial.locate(&vid, pageidx!(1)).unwrap();
+        assert_eq!(
+            partial.decode_page(&page, &buf[page.range()]).unwrap(),
+            Page::test_filled(1).into()
+        );
+    }
+
+    #[graft_test::test]
+    fn test_segment_compression() {
+        let vid = VolumeId::random();
+        let mksegment = |pages: &[Page]| {
+            let mut segment = OpenSegment::default();
+            for (pageidx, page) in SEGMENT_MAX_PAGES.iter().zip(pages) {
+                segment.insert(vid.clone(), pageidx, page.clone()).unwrap();
+            }
+            segment
+        };
+
+        let pages = [
+            Page::test_filled(1),
+            Page::empty(PageSize::DEFAULT),
+            rand::random(),
+            Page::test_filled(2),
+        ];
+        for compression in [SegmentCompression::Zstd, SegmentCompression::Lz4] {
+            let segment = mksegment(&pages);
+            let max_size = segment.serialized_size();
+            let (_, buf) = segment.serialize(compression);
+            let buf = buf.into_bytes();
+            assert!(buf.len() < max_size);
+
+            let closed = ClosedSegment::from_bytes(&buf).unwrap();
+            closed.verify_checksum().unwrap();
+            assert_eq!(closed.compression(), compression);
+            assert_eq!(closed.pages(), 4);
+            for (pageidx, page) in SEGMENT_MAX_PAGES.iter().zip(&pages) {
+                assert_eq!(
+                    closed.find_page(&vid, pageidx).unwrap(),
+                    Some(page.clone().into())
+                );
+            }
+            let found: Vec<_> = closed.iter().map(|r| r.unwrap().2).collect();
+            assert_eq!(found, pages.clone().map(SegmentPage::from));
+
+            // corrupting a compressed page is detected
+            let mut corrupt = BytesMut::from(buf.as_ref());
+            corrupt[2] ^= 0xFF;
+            let closed = ClosedSegment::from_bytes(&corrupt).unwrap();
+            assert!(closed.find_page(&vid, pageidx!(1)).is_err());
+        }
+
+        // incompressible segments are stored uncompressed
+        let segment = mksegment(&[rand::random(), rand::random()]);
+        let expected_size = segment.serialized_size();
+        let (_, buf) = segment.serialize(SegmentCompression::Zstd);
+        assert_eq!(buf.remaining(), expected_size);
+        let buf = buf.into_bytes();
+        let closed = ClosedSegment::from_bytes(&buf).unwrap();
+        assert_eq!(closed.compression(), SegmentCompression::None);
+        closed.verify_checksum().unwrap();
+
+        // invalid page offsets are rejected
+        let (_, buf) = mksegment(&pages).serialize(SegmentCompression::Lz4);
+        let mut corrupt = BytesMut::from(buf.into_bytes().as_ref());
+        let footer_offset = corrupt.len() - size_of::<SegmentFooter>();
+        let index_size = SegmentIndexBuilder::serialized_size(1, PageCount::new(4)).as_usize();
+        let offsets_end = footer_offset - index_size - size_of::<PageChecksum>() * 4;
+        // swap the first two page offsets so they are out of order
+        let offsets = offsets_end - size_of::<U32>() * 4;
+        let (first, second) = corrupt[offsets..offsets + 8].split_at_mut(4);
+        first.swap_with_slice(second);
+        assert_matches!(
+            ClosedSegment::from_bytes(&corrupt).unwrap_err().ctx(),
+            SegmentValidationErr::InvalidPageOffsets
+        );
+    }
+
     #[graft_test::test]
     fn test_segment_max_pages() {
-        let size_at_max = closed_segment_size(SEGMENT_MAX_VOLUMES, SEGMENT_MAX_PAGES);
+        let size_at_max =
+            closed_segment_size(SEGMENT_MAX_VOLUMES, SEGMENT_MAX_PAGES, PageSize::DEFAULT);
         println!("size_at_max: {:?}", size_at_max);
         // size_at_max should be within one page of the max segment size
         assert!(size_at_max <= SEGMENT_MAX_SIZE && size_at_max >= (SEGMENT_MAX_SIZE - PAGESIZE));
+
+        // segments must never exceed the max segment size regardless of page size
+        for page_size in (9..=16).map(|shift| PageSize::try_from(1u32 << shift).unwrap()) {
+            let max_pages = segment_max_pages(page_size);
+            assert!(max_pages <= SEGMENT_MAX_PAGES);
+            let size_at_max = closed_segment_size(SEGMENT_MAX_VOLUMES, max_pages, page_size);
+            assert!(
+                size_at_max <= SEGMENT_MAX_SIZE,
+                "segment with page size {page_size} is too large: {size_at_max}"
+            );
+        }
     }
 }
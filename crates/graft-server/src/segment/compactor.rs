@@ -0,0 +1,662 @@
+//! The segment compactor merges the small L0 segments produced by the
+//! `SegmentWriterTask` into larger single-volume L1 segments.
+//!
+//! Compaction never modifies existing commits. Instead the latest version of
+//! every page changed since the volume's last checkpoint is rewritten into new
+//! segments, which are appended to the volume as a rewrite commit. Rewrites
+//! don't change the contents of the volume, so readers of earlier snapshots are
+//! unaffected while readers of later snapshots only need to scan the compacted
+//! segments. Segments which are no longer referenced are left in the object
+//! store for GC.
+
+use std::{collections::HashSet, convert::Infallible, ops::RangeBounds, sync::Arc};
+
+use culprit::{Culprit, ResultExt};
+use futures::{FutureExt, StreamExt, TryStreamExt, stream};
+use graft_client::MetastoreClient;
+use graft_core::{
+    PageIdx, SegmentId, VolumeId, lsn::LSN, page::Page, page_count::PageCount,
+    page_idx::ConvertToPageIdxErr,
+};
+use graft_proto::common::v1::SegmentInfo;
+use measured::{Counter, CounterVec, MetricGroup};
+use object_store::{ObjectStore, PutPayload, path::Path};
+use serde::{Deserialize, Serialize};
+use splinter_rs::{Splinter, ops::Merge};
+use thiserror::Error;
+use tokio::{sync::mpsc, task::spawn_blocking};
+
+use crate::{
+    metrics::labels::ResultLabelSet,
+    supervisor::{SupervisedTask, TaskCfg, TaskCtx},
+    volume::{
+        catalog::{VolumeCatalog, VolumeCatalogErr},
+        commit::CommitMeta,
+        kv::SegmentKey,
+        updater::{UpdateErr, VolumeCatalogUpdater},
+    },
+};
+
+use super::{
+    cache::Cache,
+    closed::{ClosedSegment, SegmentValidationErr, segment_max_pages},
+    compression::SegmentCompression,
+    delta::{DeltaErr, SegmentPage, resolve_deltas},
+    loader::{SegmentLoader, SegmentLoaderErr},
+    open::OpenSegment,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SegmentCompactorConfig {
+    /// compact a volume once a read has to scan at least this many segments
+    pub min_segments: usize,
+
+    /// create a new checkpoint once a volume has this many commits since its
+    /// last checkpoint
+    pub checkpoint_interval: u64,
+
+    /// the maximum number of segments to download concurrently
+    pub download_concurrency: usize,
+
+    /// how pages are compressed in compacted segments
+    pub compression: SegmentCompression,
+}
+
+impl Default for SegmentCompactorConfig {
+    fn default() -> Self {
+        Self {
+            min_segments: 32,
+            checkpoint_interval: 256,
+            download_concurrency: 4,
+            compression: SegmentCompression::None,
+        }
+    }
+}
+
+#[derive(MetricGroup)]
+#[metric(new())]
+pub struct SegmentCompactorMetrics {
+    /// Number of volume compactions, broken down by result
+    compactions: CounterVec<ResultLabelSet>,
+
+    /// Number of segments no longer referenced by a compacted volume
+    removed_segments: Counter,
+
+    /// Number of segments created by compaction
+    created_segments: Counter,
+
+    /// Number of checkpoints created
+    checkpoints: Counter,
+}
+
+impl Default for SegmentCompactorMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum CompactorErr {
+    #[error("catalog error")]
+    CatalogErr(#[from] VolumeCatalogErr),
+
+    #[error("failed to load segment")]
+    LoaderErr(#[from] SegmentLoaderErr),
+
+    #[error("failed to validate segment")]
+    SegmentValidationErr(#[from] SegmentValidationErr),
+
+    #[error("invalid page index")]
+    ConvertToPageIdxErr(#[from] ConvertToPageIdxErr),
+
+    #[error("segment is missing a page referenced by the catalog")]
+    MissingPage,
+
+    #[error("failed to upload segment")]
+    UploadErr,
+
+    #[error("metastore request failed")]
+    ClientErr(#[from] graft_client::ClientErr),
+
+    #[error("failed to update catalog")]
+    UpdateErr(#[from] UpdateErr),
+
+    #[error("failed to resolve page delta")]
+    DeltaErr(#[from] DeltaErr),
+}
+
+impl From<object_store::Error> for CompactorErr {
+    fn from(_: object_store::Error) -> Self {
+        Self::UploadErr
+    }
+}
+
+/// A request for the compactor to rewrite a volume
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompactionRequest {
+    /// Compact the segments written since the volume's last checkpoint
+    Compact(VolumeId),
+
+    /// Rewrite every page in the volume into a new checkpoint
+    Checkpoint(VolumeId),
+}
+
+/// Used by the read path to request that a volume is compacted once reads
+/// start to touch too many segments, and by the write path to request a
+/// checkpoint once a volume has too many commits since its last checkpoint.
+#[derive(Clone)]
+pub struct CompactionTrigger {
+    tx: mpsc::Sender<CompactionRequest>,
+    min_segments: usize,
+    checkpoint_interval: u64,
+}
+
+impl CompactionTrigger {
+    pub fn new(tx: mpsc::Sender<CompactionRequest>, config: &SegmentCompactorConfig) -> Self {
+        Self {
+            tx,
+            min_segments: config.min_segments,
+            checkpoint_interval: config.checkpoint_interval,
+        }
+    }
+
+    /// A trigger which never requests compaction
+    pub fn disabled() -> Self {
+        let (tx, _) = mpsc::channel(1);
+        Self {
+            tx,
+            min_segments: usize::MAX,
+            checkpoint_interval: u64::MAX,
+        }
+    }
+
+    /// Request compaction of the volume if a read scanned at least
+    /// `min_segments` segments.
+    pub fn maybe_compact(&self, vid: &VolumeId, scanned_segments: usize) {
+        if scanned_segments >= self.min_segments {
+            // if the compactor is busy we drop the request; the next read
+            // will request compaction again
+            let _ = self.tx.try_send(CompactionRequest::Compact(vid.clone()));
+        }
+    }
+
+    /// Request a checkpoint of the volume if pages are being written on top of
+    /// a snapshot which is at least `checkpoint_interval` commits past its
+    /// checkpoint.
+    pub fn maybe_checkpoint(&self, vid: &VolumeId, snapshot: &CommitMeta) {
+        let commits_since_checkpoint = snapshot.lsn().since(&snapshot.checkpoint()).unwrap_or(0);
+        if commits_since_checkpoint >= self.checkpoint_interval {
+            // if the compactor is busy we drop the request; the next write
+            // will request a checkpoint again
+            let _ = self.tx.try_send(CompactionRequest::Checkpoint(vid.clone()));
+        }
+    }
+}
+
+/// The segments storing the latest version of each page in a range of commits.
+/// Segments must be added from newest to oldest, matching the read path.
+#[derive(Default)]
+pub struct CompactionSources {
+    sources: Vec<(SegmentKey, Splinter)>,
+    pages: Splinter,
+    segments: HashSet<SegmentId>,
+}
+
+impl CompactionSources {
+    /// Collect the sources for every page within the page count which changed
+    /// in the range of commits
+    pub fn scan<R: RangeBounds<LSN>>(
+        catalog: &VolumeCatalog,
+        vid: &VolumeId,
+        lsns: &R,
+        page_count: PageCount,
+    ) -> Result<Self, Culprit<VolumeCatalogErr>> {
+        let mut sources = Self::default();
+        for result in catalog.scan_segments(vid, lsns) {
+            let (key, graft) = result?;
+            sources.add(key, graft.into(), page_count);
+        }
+        Ok(sources)
+    }
+
+    /// Add a segment which is older than every segment added so far. Only
+    /// pages which haven't been seen in a newer segment are read from it.
+    pub fn add(&mut self, key: SegmentKey, graft: Splinter, page_count: PageCount) {
+        let pages = Splinter::from_iter(
+            graft
+                .iter()
+                .take_while(|&pageidx| pageidx <= page_count.to_u32())
+                .filter(|&pageidx| !self.pages.contains(pageidx)),
+        );
+        if !pages.is_empty() {
+            self.segments.insert(key.sid().clone());
+            self.pages.merge(&pages);
+            self.sources.push((key, pages));
+        }
+    }
+
+    /// The number of distinct segments which supply at least one page
+    pub fn segments(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// The number of pages which will be rewritten
+    pub fn pages(&self) -> usize {
+        self.pages.cardinality()
+    }
+
+    pub fn into_sources(self) -> Vec<(SegmentKey, Splinter)> {
+        self.sources
+    }
+}
+
+pub struct SegmentCompactorTask<C> {
+    metrics: Arc<SegmentCompactorMetrics>,
+    input: mpsc::Receiver<CompactionRequest>,
+    store: Arc<dyn ObjectStore>,
+    cache: Arc<C>,
+    loader: SegmentLoader<C>,
+    catalog: VolumeCatalog,
+    updater: VolumeCatalogUpdater,
+    metastore: MetastoreClient,
+    min_segments: usize,
+    checkpoint_interval: u64,
+    download_concurrency: usize,
+    compression: SegmentCompression,
+}
+
+impl<C: Cache + 'static> SupervisedTask for SegmentCompactorTask<C> {
+    type Err = Infallible;
+
+    fn cfg(&self) -> TaskCfg {
+        TaskCfg { name: "segment-compactor" }
+    }
+
+    async fn run(mut self, ctx: TaskCtx) -> Result<(), Culprit<Infallible>> {
+        loop {
+            tokio::select! {
+                Some(req) = self.input.recv() => {
+                    let result = match &req {
+                        CompactionRequest::Compact(vid) => self.compact_volume(vid, false).await,
+                        CompactionRequest::Checkpoint(vid) => self.compact_volume(vid, true).await,
+                    };
+                    if let Err(err) = &result {
+                        tracing::error!("failed to process {req:?}\n{err:?}");
+                    }
+                    self.metrics.compactions.inc((&result).into());
+                }
+
+                _ = ctx.wait_shutdown() => {
+                    // Shutdown immediately, abandoning any in progress
+                    // compaction. Uploaded segments will be cleaned up by GC.
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<C: Cache + 'static> SegmentCompactorTask<C> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        metrics: Arc<SegmentCompactorMetrics>,
+        config: SegmentCompactorConfig,
+        input: mpsc::Receiver<CompactionRequest>,
+        store: Arc<dyn ObjectStore>,
+        cache: Arc<C>,
+        catalog: VolumeCatalog,
+        metastore: MetastoreClient,
+    ) -> Self {
+        let loader = SegmentLoader::new(store.clone(), cache.clone(), config.download_concurrency);
+        Self {
+            metrics,
+            input,
+            store,
+            cache,
+            loader,
+            catalog,
+            updater: VolumeCatalogUpdater::new(1),
+            metastore,
+            min_segments: config.min_segments,
+            checkpoint_interval: config.checkpoint_interval,
+            download_concurrency: config.download_concurrency,
+            compression: config.compression,
+        }
+    }
+
+    #[tracing::instrument(name = "compact volume", skip(self))]
+    async fn compact_volume(
+        &mut self,
+        vid: &VolumeId,
+        checkpoint: bool,
+    ) -> Result<(), Culprit<CompactorErr>> {
+        // the metastore only accepts rewrites of the latest snapshot, which
+        // may be newer than the snapshot read by the request which triggered
+        // compaction
+        // TODO: switch this to an async client once one exists
+        let latest = {
+            let client = self.metastore.clone();
+            let vid = vid.clone();
+            spawn_blocking(move || client.snapshot(&vid, None))
+                .map(|r| r.expect("spawn_blocking failed"))
+                .await
+                .or_into_ctx()?
+        };
+        let Some(latest) = latest else {
+            return Ok(());
+        };
+        let latest: CommitMeta = latest.try_into().expect("invalid snapshot");
+        self.updater
+            .update_catalog_from_metastore(&self.metastore, &self.catalog, vid, latest.lsn())
+            .await
+            .or_into_ctx()?;
+
+        let Some(snapshot) = self.catalog.latest_snapshot(vid).or_into_ctx()? else {
+            return Ok(());
+        };
+
+        if checkpoint {
+            // an earlier request may have already checkpointed the volume
+            let commits_since_checkpoint =
+                snapshot.lsn().since(&snapshot.checkpoint()).unwrap_or(0);
+            if commits_since_checkpoint < self.checkpoint_interval {
+                return Ok(());
+            }
+            return self.checkpoint_volume(vid, snapshot).await;
+        }
+
+        // the latest commit is a rewrite which already covers every commit
+        // since the checkpoint
+        if snapshot.is_rewrite() {
+            tracing::trace!("volume has already been compacted");
+            return Ok(());
+        }
+
+        // the checkpoint is never compacted, so pages which haven't changed
+        // since the checkpoint remain in the checkpoint's segments
+        let Some(start) = snapshot
+            .checkpoint()
+            .next()
+            .filter(|&l| l <= snapshot.lsn())
+        else {
+            return Ok(());
+        };
+        let sources = CompactionSources::scan(
+            &self.catalog,
+            vid,
+            &(start..=snapshot.lsn()),
+            snapshot.page_count(),
+        )
+        .or_into_ctx()?;
+
+        let input_segments = sources.segments();
+        if input_segments < self.min_segments {
+            tracing::trace!(
+                segments = input_segments,
+                "volume does not need to be compacted"
+            );
+            return Ok(());
+        }
+        let max_pages = segment_max_pages(snapshot.page_size()).to_usize();
+        if sources.pages().div_ceil(max_pages) >= input_segments {
+            tracing::debug!(
+                segments = input_segments,
+                "compaction would not reduce the number of segments"
+            );
+            return Ok(());
+        }
+
+        let segments = self.rewrite_pages(vid, &snapshot, sources).await?;
+        let output_segments = segments.len();
+
+        // append the compacted segments to the volume
+        // TODO: switch this to an async client once one exists
+        let lsn = snapshot.lsn();
+        let result = {
+            let client = self.metastore.clone();
+            let vid = vid.clone();
+            let segments = segments.clone();
+            spawn_blocking(move || client.compact(&vid, lsn, segments))
+                .map(|r| r.expect("spawn_blocking failed"))
+                .await
+        };
+        let compacted = match result {
+            Ok(compacted) => compacted,
+            Err(err) if err.ctx().is_rewrite_rejected() => {
+                // the volume has changed since we loaded the latest snapshot;
+                // the next read will request another compaction
+                tracing::debug!("compaction rejected for volume {vid:?} at lsn {lsn}");
+                return Ok(());
+            }
+            Err(err) => return Err(err).or_into_ctx(),
+        };
+
+        // update the local catalog to match the metastore
+        let meta: CommitMeta = compacted.try_into().expect("invalid snapshot");
+        let mut batch = self.catalog.batch_insert();
+        batch
+            .insert_snapshot(vid.clone(), meta, segments)
+            .or_into_ctx()?;
+        batch.commit().or_into_ctx()?;
+
+        self.metrics.removed_segments.inc_by(input_segments as u64);
+        self.metrics.created_segments.inc_by(output_segments as u64);
+
+        tracing::info!(
+            "compacted {input_segments} segments into {output_segments} segments for volume {vid:?}"
+        );
+
+        Ok(())
+    }
+
+    /// Rewrite every page visible in the latest snapshot into new segments and
+    /// append them to the volume as a new checkpoint.
+    async fn checkpoint_volume(
+        &mut self,
+        vid: &VolumeId,
+        snapshot: CommitMeta,
+    ) -> Result<(), Culprit<CompactorErr>> {
+        let lsn = snapshot.lsn();
+        let sources = CompactionSources::scan(
+            &self.catalog,
+            vid,
+            &(snapshot.checkpoint()..=lsn),
+            snapshot.page_count(),
+        )
+        .or_into_ctx()?;
+        let segments_info = self.rewrite_pages(vid, &snapshot, sources).await?;
+        let output_segments = segments_info.len();
+
+        // TODO: switch this to an async client once one exists
+        let result = {
+            let client = self.metastore.clone();
+            let vid = vid.clone();
+            let segments_info = segments_info.clone();
+            spawn_blocking(move || client.checkpoint(&vid, lsn, segments_info))
+                .map(|r| r.expect("spawn_blocking failed"))
+                .await
+        };
+        let checkpoint = match result {
+            Ok(checkpoint) => checkpoint,
+            Err(err) if err.ctx().is_rewrite_rejected() => {
+                // the volume has changed since we loaded the latest snapshot;
+                // the next write will request another checkpoint
+                tracing::debug!("checkpoint rejected for volume {vid:?} at lsn {lsn}");
+                return Ok(());
+            }
+            Err(err) => return Err(err).or_into_ctx(),
+        };
+
+        // update the local catalog to match the metastore
+        let meta: CommitMeta = checkpoint.try_into().expect("invalid snapshot");
+        let mut batch = self.catalog.batch_insert();
+        batch
+            .insert_snapshot(vid.clone(), meta, segments_info)
+            .or_into_ctx()?;
+        batch.commit().or_into_ctx()?;
+
+        self.metrics.checkpoints.inc();
+        self.metrics.created_segments.inc_by(output_segments as u64);
+
+        tracing::info!(
+            "created checkpoint for volume {vid:?} after lsn {lsn} with {output_segments} segments"
+        );
+
+        Ok(())
+    }
+
+    /// Copy the latest version of each page from its source segment into new
+    /// segments. Source segments are loaded concurrently, while the new
+    /// segments are filled one at a time and uploaded as soon as they are full.
+    /// Returns the uploaded segments along with the pages each one contains.
+    async fn rewrite_pages(
+        &self,
+        vid: &VolumeId,
+        snapshot: &CommitMeta,
+        sources: CompactionSources,
+    ) -> Result<Vec<SegmentInfo>, Culprit<CompactorErr>> {
+        let page_size = snapshot.page_size();
+        let checkpoint = snapshot.checkpoint();
+
+        let mut loading = stream::iter(sources.into_sources())
+            .map(|(key, graft)| self.load_pages(vid, checkpoint, key, graft))
+            .buffered(self.download_concurrency);
+
+        let mut segments = vec![];
+        let mut segment = OpenSegment::new(page_size);
+        let mut graft = Splinter::default();
+        while let Some(pages) = loading.try_next().await? {
+            for (pageidx, page) in pages {
+                if !segment.has_space_for(vid) {
+                    let full = std::mem::replace(&mut segment, OpenSegment::new(page_size));
+                    segments.push(
+                        self.upload_segment(full, std::mem::take(&mut graft))
+                            .await?,
+                    );
+                }
+                segment
+                    .insert(vid.clone(), pageidx, page)
+                    .expect("segment was just verified to have space");
+                graft.insert(pageidx.to_u32());
+            }
+        }
+        if !segment.is_empty() {
+            segments.push(self.upload_segment(segment, graft).await?);
+        }
+        Ok(segments)
+    }
+
+    /// Load pages from a segment. Pages stored as deltas are reconstructed
+    /// using the checkpoint, so compacted segments only contain full pages.
+    async fn load_pages(
+        &self,
+        vid: &VolumeId,
+        checkpoint: LSN,
+        key: SegmentKey,
+        graft: Splinter,
+    ) -> Result<Vec<(PageIdx, Page)>, Culprit<CompactorErr>> {
+        let sid = key.sid();
+        let mut pages = Vec::with_capacity(graft.cardinality());
+        let mut deltas = vec![];
+        {
+            let segment = self.loader.load_segment(sid.clone()).await.or_into_ctx()?;
+            let segment = ClosedSegment::from_bytes(&segment).or_into_ctx()?;
+            for pageidx in graft.iter() {
+                let pageidx = PageIdx::try_from(pageidx).or_into_ctx()?;
+                // pages inherited from a forked volume are stored under the
+                // source volume
+                match segment.find_page(key.source_vid(), pageidx).or_into_ctx()? {
+                    Some(SegmentPage::Full(page)) => pages.push((pageidx, page)),
+                    Some(SegmentPage::Delta(delta)) => deltas.push((key.lsn(), pageidx, delta)),
+                    None => {
+                        return Err(Culprit::new_with_note(
+                            CompactorErr::MissingPage,
+                            format!("segment {sid:?} is missing page {pageidx}"),
+                        ));
+                    }
+                }
+            }
+        }
+
+        let resolved = resolve_deltas(&self.catalog, &self.loader, vid, checkpoint, deltas)
+            .await
+            .or_into_ctx()?;
+        pages.extend(resolved);
+        Ok(pages)
+    }
+
+    async fn upload_segment(
+        &self,
+        segment: OpenSegment,
+        graft: Splinter,
+    ) -> Result<SegmentInfo, Culprit<CompactorErr>> {
+        let (sid, segment) = segment.serialize(self.compression);
+
+        // optimistically cache the segment, as compaction is triggered
+        // by reads
+        if let Err(err) = self.cache.put(&sid, segment.clone()).await {
+            tracing::warn!("failed to cache compacted segment {sid:?}\n{err:?}");
+        }
+
+        let path = Path::from(sid.pretty());
+        self.store
+            .put(&path, PutPayload::from_iter(segment.iter().cloned()))
+            .await
+            .or_into_ctx()?;
+        Ok(SegmentInfo::new(&sid, graft.serialize_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::volume::kv::CommitKey;
+
+    use super::*;
+
+    #[graft_test::test]
+    fn test_compaction_sources() {
+        let vid = VolumeId::random();
+        let key = |lsn: u64| {
+            SegmentKey::new(
+                CommitKey::new(vid.clone(), LSN::new(lsn)),
+                SegmentId::random(),
+                vid.clone(),
+            )
+        };
+        let page_count = PageCount::new(4);
+        let mut sources = CompactionSources::default();
+
+        // segments are added from newest to oldest
+        let newest = key(3);
+        sources.add(newest.clone(), Splinter::from_iter([1u32]), page_count);
+        let middle = key(2);
+        sources.add(
+            middle.clone(),
+            Splinter::from_iter([1u32, 3, 5]),
+            page_count,
+        );
+        // every page in the oldest segment has a newer version
+        let oldest = key(1);
+        sources.add(oldest.clone(), Splinter::from_iter([1u32, 3]), page_count);
+
+        // the oldest segment doesn't supply any pages so it isn't counted
+        assert_eq!(sources.segments(), 2);
+        assert_eq!(sources.pages(), 2);
+
+        // page 5 is past the end of the volume
+        let sources: Vec<(SegmentId, Vec<u32>)> = sources
+            .into_sources()
+            .into_iter()
+            .map(|(key, graft)| (key.sid().clone(), graft.iter().collect()))
+            .collect();
+        assert_eq!(
+            sources,
+            vec![
+                (newest.sid().clone(), vec![1]),
+                (middle.sid().clone(), vec![3])
+            ]
+        );
+    }
+}
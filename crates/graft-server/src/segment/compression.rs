@@ -0,0 +1,143 @@
+//! Pages in a segment may optionally be compressed. Each page is compressed
+//! individually so that pages can still be read without decompressing the
+//! entire segment. Pages which don't compress well are stored uncompressed,
+//! which is detected on read by the stored page having the full page size.
+
+use bytes::Bytes;
+use culprit::Culprit;
+use graft_core::{PageSize, page::Page};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use zerocopy::{Immutable, IntoBytes, KnownLayout, TryFromBytes};
+
+/// The zstd compression level used for segment pages
+const ZSTD_LEVEL: i32 = 3;
+
+#[derive(Debug, Error)]
+#[error("failed to decompress page")]
+pub struct DecompressErr;
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    TryFromBytes,
+    IntoBytes,
+    Immutable,
+    KnownLayout,
+)]
+#[serde(rename_all = "lowercase")]
+#[repr(u8)]
+pub enum SegmentCompression {
+    /// pages are stored uncompressed
+    #[default]
+    None = 0,
+
+    /// pages are compressed with zstd
+    Zstd = 1,
+
+    /// pages are compressed with lz4
+    Lz4 = 2,
+}
+
+impl SegmentCompression {
+    #[inline]
+    pub fn is_none(self) -> bool {
+        self == Self::None
+    }
+
+    /// Compress a page. Returns the page unchanged if compression doesn't
+    /// reduce its size.
+    pub fn compress(self, page: &Page) -> Bytes {
+        let compressed = match self {
+            Self::None => None,
+            Self::Zstd => zstd::bulk::compress(page, ZSTD_LEVEL).ok(),
+            Self::Lz4 => Some(lz4_flex::block::compress(page)),
+        };
+        match compressed {
+            Some(data) if data.len() < page.len() => data.into(),
+            _ => page.clone().into(),
+        }
+    }
+
+    /// Decompress a page previously returned by `compress`.
+    pub fn decompress(
+        self,
+        data: &[u8],
+        page_size: PageSize,
+    ) -> Result<Page, Culprit<DecompressErr>> {
+        let size = page_size.as_usize();
+        let page = if data.len() == size || self.is_none() {
+            Page::try_from(data).ok()
+        } else {
+            let decompressed = match self {
+                Self::None => unreachable!("uncompressed pages are handled above"),
+                Self::Zstd => zstd::bulk::decompress(data, size).ok(),
+                Self::Lz4 => lz4_flex::block::decompress(data, size).ok(),
+            };
+            decompressed
+                .filter(|data| data.len() == size)
+                .and_then(|data| Page::try_from(Bytes::from(data)).ok())
+        };
+        page.ok_or_else(|| {
+            Culprit::new_with_note(
+                DecompressErr,
+                format!(
+                    "failed to decompress {} byte page with {self:?}; expected page size {page_size}",
+                    data.len()
+                ),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[graft_test::test]
+    fn test_compression_roundtrip() {
+        let pages = [
+            Page::empty(PageSize::DEFAULT),
+            Page::test_filled(7),
+            rand::random::<Page>(),
+            Page::test_filled_sized(3, PageSize::MAX),
+        ];
+
+        for compression in [
+            SegmentCompression::None,
+            SegmentCompression::Zstd,
+            SegmentCompression::Lz4,
+        ] {
+            for page in &pages {
+                let data = compression.compress(page);
+                assert!(data.len() <= page.len());
+                if compression.is_none() {
+                    assert_eq!(data.len(), page.len());
+                }
+                let decompressed = compression.decompress(&data, page.size()).unwrap();
+                assert_eq!(&decompressed, page, "{compression:?} roundtrip failed");
+            }
+
+            // mostly empty pages should compress well
+            if !compression.is_none() {
+                let data = compression.compress(&pages[0]);
+                assert!(data.len() < 64, "{compression:?}: {}", data.len());
+            }
+        }
+
+        // corrupt data fails to decompress
+        for compression in [SegmentCompression::Zstd, SegmentCompression::Lz4] {
+            assert!(
+                compression
+                    .decompress(&[0xFF; 16], PageSize::DEFAULT)
+                    .is_err()
+            );
+        }
+    }
+}
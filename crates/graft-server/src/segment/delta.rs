@@ -0,0 +1,438 @@
+//! Pages which only differ slightly from the version of the page stored in the
+//! volume's checkpoint may be stored as a sparse XOR delta against the
+//! checkpoint page.
+//!
+//! A delta is a sequence of runs, each of which starts with a `RunHeader`
+//! followed by `len` bytes which are XOR-ed into the base page starting at
+//! `offset`. Short gaps between differing bytes are included in a run rather
+//! than starting a new one, as each run costs a header.
+//!
+//! The base of a delta is always the page visible at the checkpoint of the
+//! commit which references the delta. The metastore enforces this by rejecting
+//! commits whose segments contain deltas against a different checkpoint.
+//!
+//! The one exception is a forked volume, whose first commit is its checkpoint
+//! and may reference deltas written to the source volume. The base of those
+//! deltas is the page visible at the source volume's checkpoint, which the
+//! fork's first commit references using delta base grafts. Pages which are
+//! stored as a delta in the checkpoint are never used as a delta base, so
+//! deltas are never nested.
+
+use std::collections::HashMap;
+
+use bytes::{BufMut, Bytes, BytesMut};
+use culprit::{Culprit, ResultExt};
+use futures::future::try_join_all;
+use graft_core::{PageIdx, VolumeId, lsn::LSN, page::Page, page_idx::ConvertToPageIdxErr};
+use splinter_rs::SplinterRef;
+use splinter_rs::{Splinter, ops::Cut};
+use thiserror::Error;
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, little_endian::U16};
+
+use crate::volume::{
+    catalog::{VolumeCatalog, VolumeCatalogErr},
+    kv::SegmentKey,
+};
+
+use super::{
+    cache::Cache,
+    loader::{SegmentLoader, SegmentLoaderErr},
+};
+
+/// A page is only stored as a delta if the delta is no larger than the page
+/// size divided by this ratio
+const MAX_DELTA_RATIO: usize = 2;
+
+#[derive(Debug, Error)]
+pub enum DeltaErr {
+    #[error("corrupt page delta")]
+    Corrupt,
+
+    #[error("checkpoint is missing from the catalog")]
+    MissingCheckpoint,
+
+    #[error("checkpoint contains a page delta")]
+    NestedDelta,
+
+    #[error("catalog error")]
+    CatalogErr(#[from] VolumeCatalogErr),
+
+    #[error("failed to load segment")]
+    LoaderErr(#[from] SegmentLoaderErr),
+
+    #[error("invalid page index")]
+    ConvertToPageIdxErr(#[from] ConvertToPageIdxErr),
+}
+
+#[derive(Debug, Clone, Copy, FromBytes, IntoBytes, Immutable, KnownLayout)]
+#[repr(C)]
+struct RunHeader {
+    offset: U16,
+    len: U16,
+}
+
+/// A sparse XOR delta between a page and its checkpoint version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageDelta(Bytes);
+
+impl PageDelta {
+    /// Encode `page` as a delta against `base`. Returns None if the pages
+    /// differ too much for the delta to be worth storing.
+    pub fn encode(base: &Page, page: &Page) -> Option<Self> {
+        if base.size() != page.size() {
+            return None;
+        }
+        let len = page.len();
+        let max_size = len / MAX_DELTA_RATIO;
+        let diff = |i: usize| base[i] ^ page[i];
+
+        let mut out = BytesMut::new();
+        let mut start = 0;
+        while start < len {
+            if diff(start) == 0 {
+                start += 1;
+                continue;
+            }
+
+            // extend the run until we hit a gap which is longer than a header
+            let mut end = start + 1;
+            let mut cursor = end;
+            while cursor < len && cursor - end < size_of::<RunHeader>() {
+                if diff(cursor) != 0 {
+                    end = cursor + 1;
+                }
+                cursor += 1;
+            }
+
+            if out.len() + size_of::<RunHeader>() + (end - start) > max_size {
+                return None;
+            }
+            let header = RunHeader {
+                offset: U16::new(start as u16),
+                len: U16::new((end - start) as u16),
+            };
+            out.put_slice(header.as_bytes());
+            out.extend((start..end).map(diff));
+            start = end;
+        }
+
+        Some(Self(out.freeze()))
+    }
+
+    /// Reconstruct the page by applying this delta to its base page
+    pub fn apply(&self, base: &Page) -> Result<Page, Culprit<DeltaErr>> {
+        let mut page = BytesMut::from(base.as_ref());
+        let mut data = self.0.as_ref();
+        while !data.is_empty() {
+            let (header, rest) = RunHeader::read_from_prefix(data)
+                .ok()
+                .ok_or_else(|| Culprit::new_with_note(DeltaErr::Corrupt, "truncated run header"))?;
+            let offset = header.offset.get() as usize;
+            let len = header.len.get() as usize;
+            let (run, rest) = rest.split_at_checked(len).ok_or_else(|| {
+                Culprit::new_with_note(DeltaErr::Corrupt, format!("truncated run of {len} bytes"))
+            })?;
+            let target = page.get_mut(offset..offset + len).ok_or_else(|| {
+                Culprit::new_with_note(
+                    DeltaErr::Corrupt,
+                    format!(
+                        "run {offset}..{} exceeds page size {}",
+                        offset + len,
+                        base.size()
+                    ),
+                )
+            })?;
+            for (dst, src) in target.iter_mut().zip(run) {
+                *dst ^= src;
+            }
+            data = rest;
+        }
+        Ok(Page::try_from(page).expect("page size is unchanged"))
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl AsRef<[u8]> for PageDelta {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Bytes> for PageDelta {
+    fn from(value: Bytes) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PageDelta> for Bytes {
+    fn from(value: PageDelta) -> Self {
+        value.0
+    }
+}
+
+/// A page stored in a segment, which is either a full page or a delta against
+/// the checkpoint version of the page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SegmentPage {
+    Full(Page),
+    Delta(PageDelta),
+}
+
+impl From<Page> for SegmentPage {
+    fn from(value: Page) -> Self {
+        Self::Full(value)
+    }
+}
+
+impl From<PageDelta> for SegmentPage {
+    fn from(value: PageDelta) -> Self {
+        Self::Delta(value)
+    }
+}
+
+/// Load the version of each page in `graft` which is visible at the
+/// checkpoint. Pages which don't exist in the checkpoint are returned as empty
+/// pages, while pages which the checkpoint stores as a delta are omitted as
+/// they can't be used as a delta base. The caller is responsible for ensuring
+/// the catalog contains the current version of the checkpoint.
+pub async fn load_delta_bases<C: Cache>(
+    catalog: &VolumeCatalog,
+    loader: &SegmentLoader<C>,
+    vid: &VolumeId,
+    checkpoint: LSN,
+    mut graft: Splinter,
+) -> Result<HashMap<PageIdx, Page>, Culprit<DeltaErr>> {
+    let meta = catalog
+        .snapshot(vid.clone(), checkpoint)
+        .or_into_ctx()?
+        .ok_or_else(|| {
+            Culprit::new_with_note(
+                DeltaErr::MissingCheckpoint,
+                format!("volume {vid:?} is missing checkpoint {checkpoint}"),
+            )
+        })?;
+
+    let mut bases = HashMap::with_capacity(graft.cardinality());
+    let empty = Page::empty(meta.page_size());
+
+    // pages past the end of the checkpoint are empty
+    for pageidx in graft.iter().filter(|&idx| idx > meta.page_count().to_u32()) {
+        bases.insert(PageIdx::try_from(pageidx).or_into_ctx()?, empty.clone());
+    }
+
+    let segments = find_segments(
+        catalog.scan_segments(vid, &(checkpoint..=checkpoint)),
+        &mut graft,
+    )?;
+    for (pageidx, page) in load_segment_pages(loader, segments).await? {
+        if let SegmentPage::Full(page) = page {
+            bases.insert(pageidx, page);
+        }
+    }
+
+    // the remaining pages are not stored in the checkpoint
+    for pageidx in graft.iter() {
+        let pageidx = PageIdx::try_from(pageidx).or_into_ctx()?;
+        bases.entry(pageidx).or_insert_with(|| empty.clone());
+    }
+
+    Ok(bases)
+}
+
+/// Load the base of each delta in `graft` which a forked volume inherited
+/// from its source volume. The bases are stored in the delta base grafts of
+/// the fork's checkpoint. Pages without a delta base didn't exist in the
+/// source volume's checkpoint, so their base is an empty page.
+async fn load_inherited_bases<C: Cache>(
+    catalog: &VolumeCatalog,
+    loader: &SegmentLoader<C>,
+    vid: &VolumeId,
+    checkpoint: LSN,
+    mut graft: Splinter,
+) -> Result<HashMap<PageIdx, Page>, Culprit<DeltaErr>> {
+    let meta = catalog
+        .snapshot(vid.clone(), checkpoint)
+        .or_into_ctx()?
+        .ok_or_else(|| {
+            Culprit::new_with_note(
+                DeltaErr::MissingCheckpoint,
+                format!("volume {vid:?} is missing checkpoint {checkpoint}"),
+            )
+        })?;
+
+    let mut bases = HashMap::with_capacity(graft.cardinality());
+    let segments = find_segments(
+        catalog.scan_delta_bases(vid, &(checkpoint..=checkpoint)),
+        &mut graft,
+    )?;
+    for (pageidx, page) in load_segment_pages(loader, segments).await? {
+        match page {
+            SegmentPage::Full(page) => {
+                bases.insert(pageidx, page);
+            }
+            SegmentPage::Delta(_) => {
+                return Err(Culprit::new_with_note(
+                    DeltaErr::NestedDelta,
+                    format!("delta base of checkpoint {checkpoint} page {pageidx} is a delta"),
+                ));
+            }
+        }
+    }
+
+    let empty = Page::empty(meta.page_size());
+    for pageidx in graft.iter() {
+        let pageidx = PageIdx::try_from(pageidx).or_into_ctx()?;
+        bases.insert(pageidx, empty.clone());
+    }
+
+    Ok(bases)
+}
+
+/// Find the segments containing each page in `graft`, removing the pages which
+/// are found from `graft`. The scan isn't Send, so the segments are collected
+/// before they are loaded.
+fn find_segments(
+    scan: impl Iterator<Item = Result<(SegmentKey, SplinterRef<Bytes>), Culprit<VolumeCatalogErr>>>,
+    graft: &mut Splinter,
+) -> Result<Vec<(SegmentKey, Splinter)>, Culprit<DeltaErr>> {
+    let mut segments = vec![];
+    for result in scan {
+        if graft.is_empty() {
+            break;
+        }
+        let (key, splinter) = result.or_into_ctx()?;
+        let cut = graft.cut(&splinter);
+        if !cut.is_empty() {
+            segments.push((key, cut));
+        }
+    }
+    Ok(segments)
+}
+
+/// Load the pages from each segment concurrently. The loader serves pages from
+/// cached segments, and otherwise respects the download limiter.
+async fn load_segment_pages<C: Cache>(
+    loader: &SegmentLoader<C>,
+    segments: Vec<(SegmentKey, Splinter)>,
+) -> Result<Vec<(PageIdx, SegmentPage)>, Culprit<DeltaErr>> {
+    let loads = segments.into_iter().map(|(key, cut)| async move {
+        let pageidxs = cut
+            .iter()
+            .map(PageIdx::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .or_into_ctx()?;
+        loader
+            .load_pages(key.sid().clone(), key.source_vid(), pageidxs)
+            .await
+            .or_into_ctx()
+    });
+    Ok(try_join_all(loads).await?.into_iter().flatten().collect())
+}
+
+/// Reconstruct pages from deltas against the provided checkpoint. Each delta
+/// is accompanied by the LSN of the commit which references it, as deltas
+/// referenced by the checkpoint itself were inherited from a forked volume.
+pub async fn resolve_deltas<C: Cache>(
+    catalog: &VolumeCatalog,
+    loader: &SegmentLoader<C>,
+    vid: &VolumeId,
+    checkpoint: LSN,
+    deltas: Vec<(LSN, PageIdx, PageDelta)>,
+) -> Result<Vec<(PageIdx, Page)>, Culprit<DeltaErr>> {
+    if deltas.is_empty() {
+        return Ok(vec![]);
+    }
+    let (inherited, deltas): (Vec<_>, Vec<_>) = deltas
+        .into_iter()
+        .partition(|(lsn, _, _)| *lsn == checkpoint);
+    let graft_of = |deltas: &[(LSN, PageIdx, PageDelta)]| {
+        Splinter::from_iter(deltas.iter().map(|(_, idx, _)| idx.to_u32()))
+    };
+
+    let mut bases = HashMap::new();
+    if !deltas.is_empty() {
+        bases = load_delta_bases(catalog, loader, vid, checkpoint, graft_of(&deltas)).await?;
+    }
+    let mut inherited_bases = HashMap::new();
+    if !inherited.is_empty() {
+        inherited_bases =
+            load_inherited_bases(catalog, loader, vid, checkpoint, graft_of(&inherited)).await?;
+    }
+
+    inherited
+        .into_iter()
+        .map(|delta| (delta, &inherited_bases))
+        .chain(deltas.into_iter().map(|delta| (delta, &bases)))
+        .map(|((_, pageidx, delta), bases)| {
+            let base = bases.get(&pageidx).ok_or_else(|| {
+                Culprit::new_with_note(
+                    DeltaErr::NestedDelta,
+                    format!("checkpoint {checkpoint} page {pageidx} is a delta"),
+                )
+            })?;
+            Ok((pageidx, delta.apply(base)?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use graft_core::PageSize;
+
+    use super::*;
+
+    #[graft_test::test]
+    fn test_page_delta() {
+        let base = Page::test_filled(1);
+
+        // change a handful of bytes in the page
+        let mut data = BytesMut::from(base.clone());
+        data[0] = 0xFF;
+        data[10] = 0xFF;
+        data[12] = 0xFF;
+        data[4000] = 0xAA;
+        data[4095] = 0xBB;
+        let page = Page::try_from(data).unwrap();
+
+        let delta = PageDelta::encode(&base, &page).unwrap();
+        // bytes 10 and 12 share a run, so we expect 4 runs
+        assert_eq!(delta.len(), 4 * size_of::<RunHeader>() + 1 + 3 + 1 + 1);
+        assert_eq!(delta.apply(&base).unwrap(), page);
+
+        // identical pages produce an empty delta
+        let delta = PageDelta::encode(&base, &base).unwrap();
+        assert!(delta.is_empty());
+        assert_eq!(delta.apply(&base).unwrap(), base);
+
+        // pages which mostly differ are not stored as deltas
+        assert_eq!(PageDelta::encode(&base, &Page::test_filled(2)), None);
+        assert_eq!(PageDelta::encode(&base, &rand::random()), None);
+
+        // pages of different sizes are not stored as deltas
+        let small = Page::test_filled_sized(1, PageSize::try_from(512u32).unwrap());
+        assert_eq!(PageDelta::encode(&base, &small), None);
+
+        // deltas against an empty page work for mostly empty pages
+        let empty = Page::empty(PageSize::MAX);
+        let mut data = BytesMut::from(empty.clone());
+        data[PageSize::MAX.as_usize() - 1] = 1;
+        let page = Page::try_from(data).unwrap();
+        let delta = PageDelta::encode(&empty, &page).unwrap();
+        assert_eq!(delta.apply(&empty).unwrap(), page);
+
+        // corrupt deltas are rejected
+        let corrupt = PageDelta::from(Bytes::from_static(&[0xFF, 0xFF, 0x10, 0x00]));
+        assert!(corrupt.apply(&base).is_err());
+        let truncated = PageDelta::from(Bytes::from_static(&[0x00, 0x00, 0x10]));
+        assert!(truncated.apply(&base).is_err());
+    }
+}
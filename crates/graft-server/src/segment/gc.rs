@@ -0,0 +1,699 @@
+//! The segment garbage collector deletes segments and commits which are no
+//! longer needed to read any Volume.
+//!
+//! A segment is referenced if any commit at or after its Volume's latest
+//! checkpoint contains a graft pointing at it. Segments which are not
+//! referenced are marked with the time they were first observed to be
+//! unreferenced, and are only deleted once they have remained unreferenced for
+//! the configured grace period. This protects segments which have been
+//! uploaded but not yet committed, as well as readers which are still using an
+//! older snapshot.
+//!
+//! Commits before a Volume's latest checkpoint are deleted once the checkpoint
+//! is older than the grace period.
+//!
+//! Every pass refreshes the catalog from the store before counting references,
+//! so commits written by other Metastores are always taken into account. This
+//! relies on commits being immutable: compaction and checkpointing append new
+//! rewrite commits rather than modifying existing ones, so a refreshed catalog
+//! never holds a stale copy of a commit. The segments replaced by a rewrite
+//! remain referenced by the commits before it until the next checkpoint
+//! outlives the grace period.
+//!
+//! Readers need a transaction's recorded outcome to resolve its commits, so
+//! outcomes are only deleted once no remaining commit carries the
+//! transaction's token and the outcome is older than the grace period.
+//! Transactions write their commits before recording their outcome, so every
+//! commit of a transaction with an outcome older than the grace period has
+//! been seen by the time the outcome is considered.
+
+use std::{
+    collections::HashSet,
+    convert::Infallible,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use culprit::{Culprit, ResultExt};
+use futures::TryStreamExt;
+use graft_core::{SegmentId, VolumeId, commit_token::CommitToken};
+use measured::{Counter, CounterVec, MetricGroup};
+use object_store::{ObjectStore, path::Path};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::time::sleep;
+
+use crate::{
+    metrics::labels::ResultLabelSet,
+    object_store_util::ObjectStoreConfig,
+    supervisor::{SupervisedTask, TaskCfg, TaskCtx},
+    volume::{
+        catalog::{VolumeCatalog, VolumeCatalogErr},
+        store::{VolumeStore, VolumeStoreErr},
+        updater::{UpdateErr, VolumeCatalogUpdater},
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SegmentGcConfig {
+    /// the object store containing segments, usually the same store the
+    /// pagestore uploads segments to. GC is disabled if not provided
+    pub segments: Option<ObjectStoreConfig>,
+
+    /// how often to run garbage collection, in seconds
+    pub interval_secs: u64,
+
+    /// how long a segment or commit must be unreferenced before it is
+    /// deleted, in seconds
+    pub grace_period_secs: u64,
+}
+
+impl Default for SegmentGcConfig {
+    fn default() -> Self {
+        Self {
+            segments: None,
+            interval_secs: 60 * 60,
+            grace_period_secs: 24 * 60 * 60,
+        }
+    }
+}
+
+#[derive(MetricGroup)]
+#[metric(new())]
+pub struct SegmentGcMetrics {
+    /// Number of garbage collection passes, broken down by result
+    passes: CounterVec<ResultLabelSet>,
+
+    /// Number of segments marked for deletion
+    marked_segments: Counter,
+
+    /// Number of segments deleted
+    deleted_segments: Counter,
+
+    /// Number of commits deleted
+    deleted_commits: Counter,
+
+    /// Number of transaction outcomes deleted
+    deleted_transactions: Counter,
+}
+
+impl Default for SegmentGcMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SegmentGcErr {
+    #[error("catalog error")]
+    CatalogErr(#[from] VolumeCatalogErr),
+
+    #[error("volume store error")]
+    StoreErr(#[from] VolumeStoreErr),
+
+    #[error("failed to update catalog")]
+    UpdateErr(#[from] UpdateErr),
+
+    #[error("object store error")]
+    ObjectStoreErr,
+}
+
+impl From<object_store::Error> for SegmentGcErr {
+    fn from(_: object_store::Error) -> Self {
+        Self::ObjectStoreErr
+    }
+}
+
+pub struct SegmentGcTask {
+    metrics: Arc<SegmentGcMetrics>,
+    store: Arc<VolumeStore>,
+    catalog: VolumeCatalog,
+    updater: VolumeCatalogUpdater,
+    segments: Arc<dyn ObjectStore>,
+    interval: Duration,
+    grace_period: Duration,
+}
+
+impl SupervisedTask for SegmentGcTask {
+    type Err = Infallible;
+
+    fn cfg(&self) -> TaskCfg {
+        TaskCfg { name: "segment-gc" }
+    }
+
+    async fn run(self, ctx: TaskCtx) -> Result<(), Culprit<Infallible>> {
+        loop {
+            tokio::select! {
+                _ = sleep(self.interval) => {
+                    let result = self.collect().await;
+                    if let Err(err) = &result {
+                        tracing::error!("segment gc failed\n{err:?}");
+                    }
+                    self.metrics.passes.inc((&result).into());
+                }
+
+                _ = ctx.wait_shutdown() => break,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SegmentGcTask {
+    pub fn new(
+        metrics: Arc<SegmentGcMetrics>,
+        store: Arc<VolumeStore>,
+        catalog: VolumeCatalog,
+        updater: VolumeCatalogUpdater,
+        segments: Arc<dyn ObjectStore>,
+        interval: Duration,
+        grace_period: Duration,
+    ) -> Self {
+        Self {
+            metrics,
+            store,
+            catalog,
+            updater,
+            segments,
+            interval,
+            grace_period,
+        }
+    }
+
+    /// Run a single garbage collection pass.
+    #[tracing::instrument(name = "segment gc", skip(self))]
+    pub async fn collect(&self) -> Result<(), Culprit<SegmentGcErr>> {
+        let now = SystemTime::now();
+
+        // collect every segment referenced by a live commit, along with the
+        // tokens of the remaining commits
+        let mut referenced = HashSet::new();
+        let mut tokens = HashSet::new();
+        for vid in self.store.list_volumes().await.or_into_ctx()? {
+            // ensure the catalog contains every commit in the store; otherwise
+            // we may collect segments referenced by commits the catalog has
+            // not seen yet
+            self.updater
+                .update_catalog_from_store(&self.store, &self.catalog, &vid, None)
+                .await
+                .or_into_ctx()?;
+
+            self.collect_volume(&vid, now, &mut referenced).await?;
+
+            for result in self.catalog.scan_volume(&vid, &(..)) {
+                let (meta, _) = result.or_into_ctx()?;
+                if !meta.token().is_empty() {
+                    tokens.insert(meta.token());
+                }
+            }
+        }
+
+        // mark and sweep unreferenced segments
+        let mut marked = HashSet::new();
+        let mut listing = self.segments.list(None);
+        while let Some(meta) = listing.try_next().await? {
+            let Some(sid) = parse_segment_path(&meta.location) else {
+                tracing::trace!("ignoring non-segment object {}", meta.location);
+                continue;
+            };
+            if referenced.contains(&sid) {
+                continue;
+            }
+
+            let marked_at = match self.catalog.gc_mark(&sid).or_into_ctx()? {
+                Some(marked_at) => marked_at,
+                None => {
+                    self.catalog.set_gc_mark(&sid, now).or_into_ctx()?;
+                    self.metrics.marked_segments.inc();
+                    now
+                }
+            };
+
+            if now.duration_since(marked_at).unwrap_or_default() >= self.grace_period {
+                tracing::debug!("deleting segment {sid:?}");
+                self.segments.delete(&meta.location).await?;
+                self.catalog.clear_gc_mark(&sid).or_into_ctx()?;
+                self.metrics.deleted_segments.inc();
+            } else {
+                marked.insert(sid);
+            }
+        }
+
+        // clear marks for segments which have been referenced again or no
+        // longer exist
+        let marks = self
+            .catalog
+            .scan_gc_marks()
+            .collect::<Result<Vec<_>, _>>()
+            .or_into_ctx()?;
+        for sid in marks {
+            if !marked.contains(&sid) {
+                self.catalog.clear_gc_mark(&sid).or_into_ctx()?;
+            }
+        }
+
+        self.collect_transactions(now, &tokens).await
+    }
+
+    /// Delete the outcomes of transactions which are older than the grace
+    /// period and no longer referenced by any commit.
+    async fn collect_transactions(
+        &self,
+        now: SystemTime,
+        tokens: &HashSet<CommitToken>,
+    ) -> Result<(), Culprit<SegmentGcErr>> {
+        for (token, decided_at) in self.store.list_transactions().await.or_into_ctx()? {
+            if tokens.contains(&token) {
+                continue;
+            }
+            if now.duration_since(decided_at).unwrap_or_default() < self.grace_period {
+                continue;
+            }
+            tracing::debug!("deleting outcome of transaction {token}");
+            self.store.delete_transaction(&token).await.or_into_ctx()?;
+            self.metrics.deleted_transactions.inc();
+        }
+        Ok(())
+    }
+
+    /// Collect the segments referenced by the Volume since its last checkpoint
+    /// and delete commits which precede the checkpoint.
+    async fn collect_volume(
+        &self,
+        vid: &VolumeId,
+        now: SystemTime,
+        referenced: &mut HashSet<SegmentId>,
+    ) -> Result<(), Culprit<SegmentGcErr>> {
+        let Some(snapshot) = self.catalog.latest_snapshot(vid).or_into_ctx()? else {
+            return Ok(());
+        };
+        let checkpoint = snapshot.checkpoint();
+
+        // the delta bases of a forked volume are referenced until the
+        // volume's next checkpoint
+        let lsns = checkpoint..=snapshot.lsn();
+        for kv in self
+            .catalog
+            .scan_segments(vid, &lsns)
+            .chain(self.catalog.scan_delta_bases(vid, &lsns))
+        {
+            let (key, _) = kv.or_into_ctx()?;
+            referenced.insert(key.sid().clone());
+        }
+
+        // commits before the checkpoint are no longer needed once the
+        // checkpoint has outlived the grace period
+        let Some(checkpoint_meta) = self
+            .catalog
+            .snapshot(vid.clone(), checkpoint)
+            .or_into_ctx()?
+        else {
+            return Ok(());
+        };
+        let checkpoint_age = now
+            .duration_since(checkpoint_meta.system_time())
+            .unwrap_or_default();
+        if checkpoint_age < self.grace_period {
+            return Ok(());
+        }
+
+        let expired = ..checkpoint;
+        let deleted = self
+            .store
+            .delete_commits(vid, &expired)
+            .await
+            .or_into_ctx()?;
+
+        let mut batch = self.catalog.batch_insert();
+        batch.remove_commits(vid, &expired).or_into_ctx()?;
+        batch.commit().or_into_ctx()?;
+
+        if !deleted.is_empty() {
+            tracing::debug!(
+                "deleted {} commits preceding checkpoint {checkpoint} for volume {vid:?}",
+                deleted.len()
+            );
+            self.metrics.deleted_commits.inc_by(deleted.len() as u64);
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_segment_path(path: &Path) -> Option<SegmentId> {
+    path.filename()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use graft_core::{PageSize, gid::ClientId, lsn::LSN, page_count::PageCount};
+    use object_store::memory::InMemory;
+    use splinter_rs::Splinter;
+
+    use crate::volume::{
+        commit::{CommitBuilder, CommitMeta},
+        transaction::TransactionOutcome,
+    };
+
+    use super::*;
+
+    #[graft_test::test]
+    async fn test_segment_gc_sanity() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let segments: Arc<dyn ObjectStore> = Arc::new(InMemory::default());
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+        let [sid1, sid2, orphan] = [(); 3].map(|_| SegmentId::random());
+        for sid in [&sid1, &sid2, &orphan] {
+            segments
+                .put(
+                    &Path::from(sid.pretty()),
+                    Bytes::from_static(b"segment").into(),
+                )
+                .await
+                .unwrap();
+        }
+
+        // commit 2 is a checkpoint, which means only segment 2 is still live
+        let checkpoint_time = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+        for (lsn, sid) in [(LSN::new(1), &sid1), (LSN::new(2), &sid2)] {
+            let meta = CommitMeta::new(
+                vid.clone(),
+                cid.clone(),
+                lsn,
+                lsn,
+                PageCount::new(1),
+                PageSize::DEFAULT,
+                checkpoint_time,
+            );
+            let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+            commit.write_graft(
+                sid.clone(),
+                Splinter::from_iter([0u32]).serialize_to_bytes(),
+            );
+            store.commit(commit.build()).await.unwrap();
+        }
+
+        let gc = |grace_period| {
+            SegmentGcTask::new(
+                Arc::new(SegmentGcMetrics::default()),
+                store.clone(),
+                catalog.clone(),
+                VolumeCatalogUpdater::new(8),
+                segments.clone(),
+                Duration::from_secs(60),
+                grace_period,
+            )
+        };
+
+        // nothing is deleted until the grace period elapses
+        gc(Duration::from_secs(u32::MAX as u64))
+            .collect()
+            .await
+            .unwrap();
+        assert!(catalog.gc_mark(&sid1).unwrap().is_some());
+        assert!(catalog.gc_mark(&orphan).unwrap().is_some());
+        assert!(catalog.gc_mark(&sid2).unwrap().is_none());
+        assert_eq!(
+            segments
+                .list(None)
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap()
+                .len(),
+            3
+        );
+        assert!(catalog.contains_snapshot(vid.clone(), LSN::new(1)).unwrap());
+
+        // once the grace period elapses unreferenced segments and commits
+        // before the checkpoint are deleted
+        gc(Duration::ZERO).collect().await.unwrap();
+        let remaining = segments
+            .list(None)
+            .map_ok(|meta| parse_segment_path(&meta.location).unwrap())
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(remaining, vec![sid2.clone()]);
+        assert_eq!(catalog.scan_gc_marks().count(), 0);
+        assert!(!catalog.contains_snapshot(vid.clone(), LSN::new(1)).unwrap());
+        assert!(catalog.contains_snapshot(vid.clone(), LSN::new(2)).unwrap());
+        assert!(store.get_commit(vid.clone(), LSN::new(1)).await.is_err());
+        assert!(store.get_commit(vid.clone(), LSN::new(2)).await.is_ok());
+    }
+
+    #[graft_test::test]
+    async fn test_segment_gc_rewrites() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let segments: Arc<dyn ObjectStore> = Arc::new(InMemory::default());
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+        let put_segment = |sid: SegmentId| {
+            let segments = segments.clone();
+            async move {
+                segments
+                    .put(
+                        &Path::from(sid.pretty()),
+                        Bytes::from_static(b"segment").into(),
+                    )
+                    .await
+                    .unwrap();
+            }
+        };
+        let [sid1, sid2, compacted, checkpoint] = [(); 4].map(|_| SegmentId::random());
+        for sid in [&sid1, &sid2, &compacted] {
+            put_segment(sid.clone()).await;
+        }
+
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+        let mut latest = None;
+        for (lsn, sid, pageidx) in [(LSN::new(1), &sid1, 1u32), (LSN::new(2), &sid2, 2)] {
+            let meta = CommitMeta::new(
+                vid.clone(),
+                cid.clone(),
+                lsn,
+                LSN::FIRST,
+                PageCount::new(2),
+                PageSize::DEFAULT,
+                time,
+            );
+            let mut commit = CommitBuilder::new_with_capacity(meta.clone(), 1);
+            commit.write_graft(
+                sid.clone(),
+                Splinter::from_iter([pageidx]).serialize_to_bytes(),
+            );
+            store.commit(commit.build()).await.unwrap();
+            latest = Some(meta);
+        }
+        let latest = latest.unwrap();
+
+        let gc = |grace_period| {
+            SegmentGcTask::new(
+                Arc::new(SegmentGcMetrics::default()),
+                store.clone(),
+                catalog.clone(),
+                VolumeCatalogUpdater::new(8),
+                segments.clone(),
+                Duration::from_secs(60),
+                grace_period,
+            )
+        };
+        let remaining = || async {
+            let mut remaining = segments
+                .list(None)
+                .map_ok(|meta| parse_segment_path(&meta.location).unwrap())
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap();
+            remaining.sort();
+            remaining
+        };
+        let sorted = |mut sids: Vec<SegmentId>| {
+            sids.sort();
+            sids
+        };
+
+        // the compacted segment hasn't been committed yet
+        gc(Duration::from_secs(u32::MAX as u64))
+            .collect()
+            .await
+            .unwrap();
+        assert!(catalog.gc_mark(&compacted).unwrap().is_some());
+
+        // another metastore appends the compaction to the volume
+        let meta = CommitMeta::rewrite(&latest, LSN::new(3), LSN::FIRST, time);
+        let mut commit = CommitBuilder::new_with_capacity(meta.clone(), 1);
+        commit.write_graft(
+            compacted.clone(),
+            Splinter::from_iter([1u32, 2]).serialize_to_bytes(),
+        );
+        store.commit(commit.build()).await.unwrap();
+
+        // the compacted segment is referenced, and the segments it replaced
+        // remain referenced by the commits before it
+        gc(Duration::ZERO).collect().await.unwrap();
+        assert_eq!(catalog.scan_gc_marks().count(), 0);
+        assert_eq!(
+            remaining().await,
+            sorted(vec![sid1.clone(), sid2.clone(), compacted.clone()])
+        );
+
+        // once the volume is checkpointed, the earlier segments are deleted
+        put_segment(checkpoint.clone()).await;
+        let meta = CommitMeta::rewrite(&meta, LSN::new(4), LSN::new(4), time);
+        let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+        commit.write_graft(
+            checkpoint.clone(),
+            Splinter::from_iter([1u32, 2]).serialize_to_bytes(),
+        );
+        store.commit(commit.build()).await.unwrap();
+
+        gc(Duration::ZERO).collect().await.unwrap();
+        assert_eq!(remaining().await, vec![checkpoint]);
+        assert!(store.get_commit(vid.clone(), LSN::new(3)).await.is_err());
+    }
+
+    #[graft_test::test]
+    async fn test_segment_gc_transactions() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let segments: Arc<dyn ObjectStore> = Arc::new(InMemory::default());
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+        let [expired, live, orphan] = [(); 3].map(|_| CommitToken::random());
+
+        // commit 1 belongs to a transaction and precedes the checkpoint at
+        // commit 2, while commit 3 belongs to a transaction after it
+        for (lsn, checkpoint, token) in [
+            (LSN::new(1), LSN::new(1), Some(expired)),
+            (LSN::new(2), LSN::new(2), None),
+            (LSN::new(3), LSN::new(2), Some(live)),
+        ] {
+            let mut meta = CommitMeta::new(
+                vid.clone(),
+                cid.clone(),
+                lsn,
+                checkpoint,
+                PageCount::new(1),
+                PageSize::DEFAULT,
+                time,
+            );
+            if let Some(token) = token {
+                meta = meta.with_token(token).with_transaction(PageCount::new(1));
+            }
+            store
+                .commit(CommitBuilder::new_with_capacity(meta, 0).build())
+                .await
+                .unwrap();
+        }
+        for token in [&expired, &live, &orphan] {
+            store
+                .decide_transaction(token, TransactionOutcome::Committed)
+                .await
+                .unwrap();
+        }
+
+        let gc = |grace_period| {
+            SegmentGcTask::new(
+                Arc::new(SegmentGcMetrics::default()),
+                store.clone(),
+                catalog.clone(),
+                VolumeCatalogUpdater::new(8),
+                segments.clone(),
+                Duration::from_secs(60),
+                grace_period,
+            )
+        };
+        let outcomes = async || {
+            store
+                .list_transactions()
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|(token, _)| token)
+                .collect::<Vec<_>>()
+        };
+
+        // nothing is deleted until the grace period elapses
+        gc(Duration::from_secs(u32::MAX as u64))
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(outcomes().await.len(), 3);
+
+        // once the grace period elapses, only the outcome of the transaction
+        // with a remaining commit is kept
+        gc(Duration::ZERO).collect().await.unwrap();
+        assert!(store.get_commit(vid.clone(), LSN::new(1)).await.is_err());
+        assert_eq!(outcomes().await, vec![live]);
+    }
+
+    #[graft_test::test]
+    async fn test_segment_gc_deleted_volume() {
+        let store = Arc::new(VolumeStore::new(Arc::new(InMemory::default())));
+        let segments: Arc<dyn ObjectStore> = Arc::new(InMemory::default());
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+
+        let vid = VolumeId::random();
+        let cid = ClientId::random();
+        let sid = SegmentId::random();
+        segments
+            .put(
+                &Path::from(sid.pretty()),
+                Bytes::from_static(b"segment").into(),
+            )
+            .await
+            .unwrap();
+
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+        let meta = CommitMeta::new(
+            vid.clone(),
+            cid.clone(),
+            LSN::FIRST,
+            LSN::FIRST,
+            PageCount::new(1),
+            PageSize::DEFAULT,
+            time,
+        );
+        let mut commit = CommitBuilder::new_with_capacity(meta, 1);
+        commit.write_graft(sid, Splinter::from_iter([1u32]).serialize_to_bytes());
+        store.commit(commit.build()).await.unwrap();
+
+        // delete the volume
+        let tombstone =
+            CommitMeta::tombstone(vid.clone(), cid, LSN::new(2), PageSize::DEFAULT, time);
+        store
+            .commit(CommitBuilder::new_with_capacity(tombstone, 0).build())
+            .await
+            .unwrap();
+
+        SegmentGcTask::new(
+            Arc::new(SegmentGcMetrics::default()),
+            store.clone(),
+            catalog.clone(),
+            VolumeCatalogUpdater::new(8),
+            segments.clone(),
+            Duration::from_secs(60),
+            Duration::ZERO,
+        )
+        .collect()
+        .await
+        .unwrap();
+
+        // the tombstone references no segments, so the volume's segments and
+        // commits are deleted, leaving only the tombstone
+        let remaining = segments.list(None).try_collect::<Vec<_>>().await.unwrap();
+        assert!(remaining.is_empty());
+        assert!(store.get_commit(vid.clone(), LSN::FIRST).await.is_err());
+        let tombstone = store.get_commit(vid.clone(), LSN::new(2)).await.unwrap();
+        assert!(tombstone.meta().is_tombstone());
+    }
+}
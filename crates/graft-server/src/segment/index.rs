@@ -69,6 +69,15 @@ impl<'a> SegmentIndex<'a> {
         })
     }
 
+    /// Returns the number of pages stored in an index of the provided size, or
+    /// None if the size is invalid
+    pub fn pages_for_size(size: usize, volumes: usize) -> Option<PageCount> {
+        let page_idxs_size = size.checked_sub(volumes * size_of::<VolumeMeta>())?;
+        let pageidx_size = size_of::<U32<LittleEndian>>();
+        (page_idxs_size % pageidx_size == 0)
+            .then(|| PageCount::new((page_idxs_size / pageidx_size) as u32))
+    }
+
     pub fn pages(&self) -> PageCount {
         PageCount::new(self.page_idxs.len() as u32)
     }
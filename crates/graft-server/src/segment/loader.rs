@@ -1,11 +1,28 @@
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc, time::Duration};
 
-use culprit::ResultExt;
-use graft_core::SegmentId;
-use object_store::{ObjectStore, path::Path};
+use bytes::Bytes;
+use culprit::{Culprit, ResultExt};
+use futures::{
+    FutureExt,
+    future::{BoxFuture, select_ok},
+};
+use graft_core::{PageIdx, SegmentId, VolumeId};
+use measured::{Counter, MetricGroup};
+use object_store::{
+    GetOptions, GetRange, OBJECT_STORE_COALESCE_DEFAULT, ObjectStore, coalesce_ranges, path::Path,
+};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use tokio::{
+    sync::oneshot,
+    time::{Instant, sleep_until, timeout},
+};
 
-use super::cache::Cache;
+use super::{
+    cache::Cache,
+    closed::{ClosedSegment, PartialSegment, SegmentValidationErr},
+    delta::SegmentPage,
+};
 use crate::{api::error::ApiErrCtx, limiter::Limiter};
 
 #[derive(Debug, Error)]
@@ -15,6 +32,9 @@ pub enum SegmentLoaderErr {
 
     #[error("failed to download segment")]
     DownloadSegment(object_store::Error),
+
+    #[error("downloaded segment failed validation")]
+    InvalidSegment(#[from] SegmentValidationErr),
 }
 
 impl From<object_store::Error> for SegmentLoaderErr {
@@ -34,13 +54,67 @@ impl From<SegmentLoaderErr> for ApiErrCtx {
         match err {
             SegmentLoaderErr::Cache(ioerr) => ApiErrCtx::IoErr(ioerr),
             SegmentLoaderErr::DownloadSegment(_) => ApiErrCtx::SegmentDownloadErr,
+            SegmentLoaderErr::InvalidSegment(err) => err.into(),
+        }
+    }
+}
+
+/// Configures when the loader issues a second, hedged request for a segment
+/// which is taking too long to download. Whichever request finishes first is
+/// used, and the other is cancelled.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct HedgeConfig {
+    /// hedge if the first byte of the segment hasn't been received within
+    /// this many milliseconds
+    pub first_byte_ms: Option<u64>,
+
+    /// hedge if the segment hasn't been completely downloaded within this many
+    /// milliseconds
+    pub download_ms: Option<u64>,
+}
+
+impl Default for HedgeConfig {
+    fn default() -> Self {
+        Self {
+            first_byte_ms: Some(200),
+            download_ms: None,
         }
     }
 }
 
+impl HedgeConfig {
+    pub fn disabled() -> Self {
+        Self { first_byte_ms: None, download_ms: None }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.first_byte_ms.is_some() || self.download_ms.is_some()
+    }
+}
+
+#[derive(MetricGroup, Default)]
+pub struct SegmentLoaderMetrics {
+    /// Number of segments downloaded from object storage
+    downloads: Counter,
+
+    /// Number of requests which issued a hedged request
+    hedged_downloads: Counter,
+
+    /// Number of hedged requests which finished before the original request
+    hedge_wins: Counter,
+
+    /// Number of uncached segments read with range requests rather than
+    /// downloaded
+    range_reads: Counter,
+}
+
 pub struct SegmentLoader<C> {
+    metrics: Arc<SegmentLoaderMetrics>,
     store: Arc<dyn ObjectStore>,
     cache: Arc<C>,
+    hedge: HedgeConfig,
+    range_reads: bool,
 
     download_limiter: Limiter<SegmentId>,
 }
@@ -48,12 +122,137 @@ pub struct SegmentLoader<C> {
 impl<C: Cache> SegmentLoader<C> {
     pub fn new(store: Arc<dyn ObjectStore>, cache: Arc<C>, download_concurrency: usize) -> Self {
         Self {
+            metrics: Default::default(),
             store,
             cache,
+            hedge: HedgeConfig::default(),
+            range_reads: false,
             download_limiter: Limiter::new(download_concurrency),
         }
     }
 
+    pub fn with_metrics(mut self, metrics: Arc<SegmentLoaderMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    pub fn with_hedging(mut self, hedge: HedgeConfig) -> Self {
+        self.hedge = hedge;
+        self
+    }
+
+    /// If enabled, `load_pages` reads uncached segments with range requests
+    /// for the requested pages, rather than downloading the whole segment
+    pub fn with_range_reads(mut self, range_reads: bool) -> Self {
+        self.range_reads = range_reads;
+        self
+    }
+
+    /// Load pages for a volume from a segment. The pages must exist in the
+    /// segment and are returned in order.
+    pub async fn load_pages(
+        &self,
+        sid: SegmentId,
+        vid: &VolumeId,
+        pageidxs: Vec<PageIdx>,
+    ) -> culprit::Result<Vec<(PageIdx, SegmentPage)>, SegmentLoaderErr> {
+        if self.range_reads {
+            if let Some(segment) = self.cache.get(&sid).await.or_into_ctx()? {
+                return find_pages(&segment, vid, pageidxs);
+            }
+            return self.load_pages_ranged(&sid, vid, pageidxs).await;
+        }
+        let segment = self.load_segment(sid).await?;
+        find_pages(&segment, vid, pageidxs)
+    }
+
+    /// Load pages from a segment via range requests. The segment's metadata
+    /// is loaded and verified against the footer checksum to locate the
+    /// pages, and then each page is loaded and verified against its checksum.
+    /// The segment is not cached. Segments whose metadata can't be verified
+    /// are downloaded in full.
+    async fn load_pages_ranged(
+        &self,
+        sid: &SegmentId,
+        vid: &VolumeId,
+        pageidxs: Vec<PageIdx>,
+    ) -> culprit::Result<Vec<(PageIdx, SegmentPage)>, SegmentLoaderErr> {
+        // range reads share the download permit for the segment
+        let permit = self.download_limiter.acquire(sid).await;
+
+        // check the cache again in case another task has downloaded the segment
+        if let Some(segment) = self.cache.get(sid).await.or_into_ctx()? {
+            return find_pages(&segment, vid, pageidxs);
+        }
+
+        let path = Path::from(sid.pretty());
+
+        // load the footer, which determines the size of the segment metadata
+        let footer_size = PartialSegment::FOOTER_SIZE as u64;
+        let (footer, size) = self
+            .download(&path, Some(GetRange::Suffix(footer_size)))
+            .await?;
+
+        // load the segment metadata along with the footer
+        let tail_size = PartialSegment::tail_size(&footer).or_into_ctx()? as u64;
+        let tail = if tail_size == footer_size {
+            footer
+        } else {
+            let start = size.checked_sub(tail_size).ok_or_else(|| {
+                Culprit::new_with_note(
+                    SegmentLoaderErr::InvalidSegment(SegmentValidationErr::TooSmall),
+                    format!("segment {sid:?} is smaller than its metadata"),
+                )
+            })?;
+            self.download(&path, Some(GetRange::Bounded(start..size)))
+                .await?
+                .0
+        };
+        let segment = PartialSegment::from_tail(size as usize, &tail).or_into_ctx()?;
+        if !segment.is_verified() {
+            // release the permit, as load_segment acquires it again
+            drop(permit);
+            let segment = self.load_segment(sid.clone()).await?;
+            return find_pages(&segment, vid, pageidxs);
+        }
+        self.metrics.range_reads.inc();
+
+        // load the pages
+        let pages = pageidxs
+            .into_iter()
+            .map(|pageidx| {
+                segment
+                    .locate(vid, pageidx)
+                    .expect("bug: failed to find expected pageidx in segment; index out of sync")
+            })
+            .collect::<Vec<_>>();
+        let ranges: Vec<Range<u64>> = pages
+            .iter()
+            .map(|page| {
+                let range = page.range();
+                range.start as u64..range.end as u64
+            })
+            .collect();
+        let data = coalesce_ranges(
+            &ranges,
+            |range| {
+                self.download(&path, Some(GetRange::Bounded(range)))
+                    .map(|result| result.map(|(data, _)| data))
+            },
+            OBJECT_STORE_COALESCE_DEFAULT,
+        )
+        .await?;
+
+        pages
+            .iter()
+            .zip(data)
+            .map(|(page, data)| {
+                let decoded = segment.decode_page(page, &data).or_into_ctx()?;
+                Ok((page.pageidx(), decoded))
+            })
+            .collect()
+    }
+
     pub async fn load_segment(
         &self,
         sid: SegmentId,
@@ -72,9 +271,15 @@ impl<C: Cache> SegmentLoader<C> {
         }
 
         // download the segment
+        self.metrics.downloads.inc();
         let path = Path::from(sid.pretty());
-        let obj = self.store.get(&path).await?;
-        let data = obj.bytes().await?;
+        let (data, _) = self.download(&path, None).await?;
+
+        // verify the segment before caching it, so that corrupt segments are
+        // never served to readers
+        ClosedSegment::from_bytes(&data)
+            .and_then(|segment| segment.verify_checksum())
+            .or_into_ctx()?;
 
         // insert the segment into the cache
         self.cache.put(&sid, data).await.or_into_ctx()?;
@@ -90,4 +295,281 @@ impl<C: Cache> SegmentLoader<C> {
             .or_into_ctx()?
             .expect("segment not found after download"))
     }
+
+    /// Download a segment or a range of a segment, issuing a hedged request
+    /// if the original request is slower than configured. Returns the
+    /// downloaded bytes along with the size of the entire segment.
+    async fn download(
+        &self,
+        path: &Path,
+        range: Option<GetRange>,
+    ) -> Result<(Bytes, u64), object_store::Error> {
+        let (first_byte_tx, first_byte_rx) = oneshot::channel();
+        let mut primary = fetch(&self.store, path, range.clone(), Some(first_byte_tx));
+        if !self.hedge.is_enabled() {
+            return primary.await;
+        }
+
+        let start = Instant::now();
+        let deadline = |ms: Option<u64>| ms.map(|ms| start + Duration::from_millis(ms));
+        let first_byte_deadline = deadline(self.hedge.first_byte_ms);
+        let download_deadline = deadline(self.hedge.download_ms);
+
+        // resolves once the primary request is slow enough to be hedged
+        let should_hedge = async {
+            if let Some(first_byte_deadline) = first_byte_deadline {
+                let remaining = first_byte_deadline.saturating_duration_since(Instant::now());
+                if timeout(remaining, first_byte_rx).await.is_err() {
+                    return;
+                }
+            }
+            match download_deadline {
+                Some(download_deadline) => sleep_until(download_deadline).await,
+                None => std::future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            result = &mut primary => return result,
+            _ = should_hedge => {}
+        }
+
+        tracing::debug!(?path, elapsed = ?start.elapsed(), "hedging segment download");
+        self.metrics.hedged_downloads.inc();
+        let hedge = fetch(&self.store, path, range, None);
+
+        // use whichever request succeeds first
+        let primary = primary.map(|result| result.map(|data| (data, false)));
+        let hedge = hedge.map(|result| result.map(|data| (data, true)));
+        let ((data, hedge_won), _) = select_ok([primary.boxed(), hedge.boxed()]).await?;
+        if hedge_won {
+            self.metrics.hedge_wins.inc();
+        }
+        Ok(data)
+    }
+}
+
+/// Find pages for a volume in a segment
+fn find_pages(
+    segment: &[u8],
+    vid: &VolumeId,
+    pageidxs: Vec<PageIdx>,
+) -> culprit::Result<Vec<(PageIdx, SegmentPage)>, SegmentLoaderErr> {
+    let segment = ClosedSegment::from_bytes(segment).or_into_ctx()?;
+    pageidxs
+        .into_iter()
+        .map(|pageidx| {
+            let page = segment
+                .find_page(vid, pageidx)
+                .or_into_ctx()?
+                .expect("bug: failed to find expected pageidx in segment; index out of sync");
+            Ok((pageidx, page))
+        })
+        .collect()
+}
+
+/// Download an object or a range of an object, notifying `first_byte` once
+/// the object's response headers have been received. Returns the downloaded
+/// bytes along with the size of the entire object.
+fn fetch<'a>(
+    store: &'a Arc<dyn ObjectStore>,
+    path: &'a Path,
+    range: Option<GetRange>,
+    first_byte: Option<oneshot::Sender<()>>,
+) -> BoxFuture<'a, Result<(Bytes, u64), object_store::Error>> {
+    async move {
+        let obj = store
+            .get_opts(path, GetOptions { range, ..Default::default() })
+            .await?;
+        if let Some(first_byte) = first_byte {
+            let _ = first_byte.send(());
+        }
+        let size = obj.meta.size;
+        Ok((obj.bytes().await?, size))
+    }
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use assert_matches::assert_matches;
+    use graft_core::{PageSize, page::Page, pageidx};
+    use measured::Counter;
+    use object_store::{
+        PutPayload,
+        memory::InMemory,
+        throttle::{ThrottleConfig, ThrottledStore},
+    };
+
+    use crate::segment::{
+        cache::mem::MemCache, compression::SegmentCompression, delta::PageDelta, open::OpenSegment,
+    };
+
+    use super::*;
+
+    fn count(counter: &Counter) -> u64 {
+        counter.get_metric().count.load(Ordering::Relaxed)
+    }
+
+    #[graft_test::test]
+    async fn test_loader_hedging() {
+        // every get request takes 50ms before returning the response headers
+        let store: Arc<dyn ObjectStore> = Arc::new(ThrottledStore::new(
+            InMemory::default(),
+            ThrottleConfig {
+                wait_get_per_call: Duration::from_millis(50),
+                ..Default::default()
+            },
+        ));
+
+        let vid = VolumeId::random();
+        let mut sids = vec![];
+        for i in 0..2 {
+            let mut segment = OpenSegment::default();
+            segment
+                .insert(vid.clone(), pageidx!(1), Page::test_filled(i))
+                .unwrap();
+            let (sid, data) = segment.serialize(SegmentCompression::None);
+            store
+                .put(
+                    &Path::from(sid.pretty()),
+                    PutPayload::from_iter(data.iter().cloned()),
+                )
+                .await
+                .unwrap();
+            sids.push(sid);
+        }
+
+        // a slow request is hedged
+        let metrics = Arc::new(SegmentLoaderMetrics::default());
+        let loader = SegmentLoader::new(store.clone(), Arc::new(MemCache::default()), 8)
+            .with_metrics(metrics.clone())
+            .with_hedging(HedgeConfig {
+                first_byte_ms: Some(10),
+                download_ms: None,
+            });
+        let data = loader.load_segment(sids[0].clone()).await.unwrap();
+        let segment = ClosedSegment::from_bytes(&data).unwrap();
+        assert_eq!(
+            segment.find_page(&vid, pageidx!(1)).unwrap(),
+            Some(Page::test_filled(0).into())
+        );
+        assert_eq!(count(&metrics.downloads), 1);
+        assert_eq!(count(&metrics.hedged_downloads), 1);
+
+        // cached segments are not downloaded again
+        loader.load_segment(sids[0].clone()).await.unwrap();
+        assert_eq!(count(&metrics.downloads), 1);
+
+        // requests are not hedged when hedging is disabled
+        let metrics = Arc::new(SegmentLoaderMetrics::default());
+        let loader = SegmentLoader::new(store, Arc::new(MemCache::default()), 8)
+            .with_metrics(metrics.clone())
+            .with_hedging(HedgeConfig::disabled());
+        loader.load_segment(sids[1].clone()).await.unwrap();
+        assert_eq!(count(&metrics.downloads), 1);
+        assert_eq!(count(&metrics.hedged_downloads), 0);
+        assert_eq!(count(&metrics.hedge_wins), 0);
+    }
+
+    #[graft_test::test]
+    async fn test_loader_range_reads() {
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::default());
+        let vid = VolumeId::random();
+        let base = Page::test_filled(1);
+        let mut data = base.to_vec();
+        data[10] = 0xFF;
+        let delta = PageDelta::encode(&base, &Page::try_from(data.as_slice()).unwrap()).unwrap();
+
+        let mut sids = vec![];
+        for compression in [SegmentCompression::None, SegmentCompression::Zstd] {
+            let mut segment = OpenSegment::default();
+            segment
+                .insert(vid.clone(), pageidx!(1), Page::test_filled(1))
+                .unwrap();
+            segment
+                .insert(vid.clone(), pageidx!(2), delta.clone())
+                .unwrap();
+            segment
+                .insert(vid.clone(), pageidx!(3), Page::empty(PageSize::DEFAULT))
+                .unwrap();
+            segment
+                .insert(VolumeId::random(), pageidx!(1), Page::test_filled(2))
+                .unwrap();
+            let (sid, data) = segment.serialize(compression);
+            store
+                .put(
+                    &Path::from(sid.pretty()),
+                    PutPayload::from_iter(data.iter().cloned()),
+                )
+                .await
+                .unwrap();
+            sids.push(sid);
+        }
+
+        let metrics = Arc::new(SegmentLoaderMetrics::default());
+        let cache = Arc::new(MemCache::default());
+        let loader = SegmentLoader::new(store.clone(), cache.clone(), 8)
+            .with_metrics(metrics.clone())
+            .with_range_reads(true);
+        let pageidxs = vec![pageidx!(1), pageidx!(2), pageidx!(3)];
+        let expected = vec![
+            (pageidx!(1), Page::test_filled(1).into()),
+            (pageidx!(2), delta.into()),
+            (pageidx!(3), Page::empty(PageSize::DEFAULT).into()),
+        ];
+
+        for sid in &sids {
+            // uncached segments are read with range requests and not cached
+            let pages = loader
+                .load_pages(sid.clone(), &vid, pageidxs.clone())
+                .await
+                .unwrap();
+            assert_eq!(pages, expected);
+            assert!(cache.get(sid).await.unwrap().is_none());
+
+            // a subset of pages can be read
+            let pages = loader
+                .load_pages(sid.clone(), &vid, vec![pageidx!(3)])
+                .await
+                .unwrap();
+            assert_eq!(pages, expected[2..]);
+        }
+        assert_eq!(count(&metrics.range_reads), 4);
+        assert_eq!(count(&metrics.downloads), 0);
+
+        // cached segments are read from the cache
+        loader.load_segment(sids[0].clone()).await.unwrap();
+        let pages = loader
+            .load_pages(sids[0].clone(), &vid, pageidxs.clone())
+            .await
+            .unwrap();
+        assert_eq!(pages, expected);
+        assert_eq!(count(&metrics.range_reads), 4);
+        assert_eq!(count(&metrics.downloads), 1);
+
+        // range reads verify the segment metadata against the footer checksum
+        let path = Path::from(sids[1].pretty());
+        let mut data = store
+            .get(&path)
+            .await
+            .unwrap()
+            .bytes()
+            .await
+            .unwrap()
+            .to_vec();
+        let index_offset = data.len() - PartialSegment::FOOTER_SIZE - 1;
+        data[index_offset] ^= 0xFF;
+        store.put(&path, data.into()).await.unwrap();
+        let err = loader
+            .load_pages(sids[1].clone(), &vid, pageidxs)
+            .await
+            .unwrap_err();
+        assert_matches!(
+            err.ctx(),
+            SegmentLoaderErr::InvalidSegment(SegmentValidationErr::ChecksumMismatch)
+        );
+    }
 }
@@ -4,16 +4,21 @@ use std::{collections::BTreeMap, fmt::Debug};
 
 use culprit::Culprit;
 use graft_core::{
-    PageIdx, SegmentId, VolumeId, byte_unit::ByteUnit, page::Page, page_count::PageCount,
+    PageIdx, PageSize, SegmentId, VolumeId, byte_unit::ByteUnit, page_count::PageCount,
 };
 use splinter_rs::Splinter;
 use thiserror::Error;
-use zerocopy::IntoBytes;
+use zerocopy::{IntoBytes, little_endian::U32};
 
 use crate::bytes_vec::BytesVec;
 
 use super::{
-    closed::{SEGMENT_MAX_PAGES, SEGMENT_MAX_VOLUMES, SegmentFooter, closed_segment_size},
+    closed::{
+        DeltaEntry, SEGMENT_MAX_VOLUMES, SegmentFooter, closed_segment_size, page_checksum,
+        segment_max_pages,
+    },
+    compression::SegmentCompression,
+    delta::SegmentPage,
     index::SegmentIndexBuilder,
 };
 
@@ -23,15 +28,13 @@ pub struct SegmentFullErr;
 
 pub struct OpenSegment {
     sid: SegmentId,
-    index: BTreeMap<VolumeId, BTreeMap<PageIdx, Page>>,
+    page_size: PageSize,
+    index: BTreeMap<VolumeId, BTreeMap<PageIdx, SegmentPage>>,
 }
 
 impl Default for OpenSegment {
     fn default() -> Self {
-        Self {
-            sid: SegmentId::random(),
-            index: Default::default(),
-        }
+        Self::new(PageSize::DEFAULT)
     }
 }
 
@@ -46,11 +49,21 @@ impl Debug for OpenSegment {
 }
 
 impl OpenSegment {
+    /// creates an empty segment which stores pages of the specified size
+    pub fn new(page_size: PageSize) -> Self {
+        Self {
+            sid: SegmentId::random(),
+            page_size,
+            index: Default::default(),
+        }
+    }
+
     /// returns true if the segment can accept another page for the specified volume
     pub fn has_space_for(&self, vid: &VolumeId) -> bool {
+        let max_pages = self.max_pages();
         match self.index.contains_key(vid) {
-            true => self.pages() < SEGMENT_MAX_PAGES,
-            false => self.volumes() < SEGMENT_MAX_VOLUMES && self.pages() < SEGMENT_MAX_PAGES,
+            true => self.pages() < max_pages,
+            false => self.volumes() < SEGMENT_MAX_VOLUMES && self.pages() < max_pages,
         }
     }
 
@@ -59,12 +72,19 @@ impl OpenSegment {
         self.index.contains_key(vid)
     }
 
+    /// returns true if the segment contains the specified page
+    pub fn contains_page(&self, vid: &VolumeId, pageidx: PageIdx) -> bool {
+        self.index
+            .get(vid)
+            .is_some_and(|pages| pages.contains_key(&pageidx))
+    }
+
     /// inserts pages into the segment from the iterator returning when the
     /// segment is full or the iterator is empty, whichever happens first
-    pub fn batch_insert(
+    pub fn batch_insert<P: Into<SegmentPage>>(
         &mut self,
         vid: VolumeId,
-        pages: impl ExactSizeIterator<Item = (PageIdx, Page)>,
+        pages: impl ExactSizeIterator<Item = (PageIdx, P)>,
     ) -> Result<Splinter, Culprit<SegmentFullErr>> {
         // early exit if segment can't fit a write to this volume
         if !self.has_space_for(&vid) {
@@ -72,14 +92,20 @@ impl OpenSegment {
         }
 
         // calculate how many pages we can insert
-        let space = SEGMENT_MAX_PAGES.to_usize() - self.pages().to_usize();
+        let space = self.max_pages().to_usize() - self.pages().to_usize();
 
         let mut graft = Splinter::default();
 
         // insert pages
+        let page_size = self.page_size;
         let index = self.index.entry(vid).or_default();
-        index.extend(pages.take(space).inspect(|(idx, _)| {
+        index.extend(pages.take(space).map(|(idx, page)| {
+            let page = page.into();
+            if let SegmentPage::Full(page) = &page {
+                debug_assert_eq!(page.size(), page_size, "page size mismatch");
+            }
             graft.insert(idx.to_u32());
+            (idx, page)
         }));
 
         Ok(graft)
@@ -89,11 +115,15 @@ impl OpenSegment {
         &mut self,
         vid: VolumeId,
         pageidx: PageIdx,
-        page: Page,
+        page: impl Into<SegmentPage>,
     ) -> Result<(), Culprit<SegmentFullErr>> {
         if !self.has_space_for(&vid) {
             return Err(Culprit::new(SegmentFullErr));
         }
+        let page = page.into();
+        if let SegmentPage::Full(page) = &page {
+            debug_assert_eq!(page.size(), self.page_size, "page size mismatch");
+        }
         self.index.entry(vid).or_default().insert(pageidx, page);
         Ok(())
     }
@@ -103,6 +133,19 @@ impl OpenSegment {
         &self.sid
     }
 
+    /// the size of every page stored in this segment
+    #[inline]
+    pub fn page_size(&self) -> PageSize {
+        self.page_size
+    }
+
+    /// the maximum number of pages this segment can store
+    #[inline]
+    #[must_use]
+    pub fn max_pages(&self) -> PageCount {
+        segment_max_pages(self.page_size)
+    }
+
     #[inline]
     #[must_use]
     pub fn volumes(&self) -> usize {
@@ -122,39 +165,133 @@ impl OpenSegment {
     }
 
     #[cfg(test)]
-    pub fn find_page(&self, vid: &VolumeId, pageidx: PageIdx) -> Option<&Page> {
+    pub fn find_page(&self, vid: &VolumeId, pageidx: PageIdx) -> Option<&SegmentPage> {
         self.index.get(vid)?.get(&pageidx)
     }
 
+    /// The size of the segment once serialized without compression or deltas.
+    /// This is an upper bound on the size of the serialized segment.
     pub fn serialized_size(&self) -> ByteUnit {
-        closed_segment_size(self.volumes(), self.pages())
+        closed_segment_size(self.volumes(), self.pages(), self.page_size)
     }
 
-    pub fn serialize(self) -> (SegmentId, BytesVec) {
+    /// Serialize the segment, compressing each full page with the provided
+    /// compression. If compression doesn't reduce the size of the segment, the
+    /// segment is written uncompressed.
+    pub fn serialize(self, compression: SegmentCompression) -> (SegmentId, BytesVec) {
         let volumes = self.volumes();
         let pages = self.pages();
-        // +2 for the index, +1 for the footer
-        let mut data = BytesVec::with_capacity(pages.to_usize() + 2 + 1);
+        let full_pages = || {
+            self.index
+                .values()
+                .flat_map(|pages| pages.values())
+                .filter_map(|page| match page {
+                    SegmentPage::Full(page) => Some(page),
+                    SegmentPage::Delta(_) => None,
+                })
+        };
+        let num_full = full_pages().count();
+
+        // compress pages up front so we can determine if compression is worth it
+        let compressed = (!compression.is_none())
+            .then(|| {
+                full_pages()
+                    .map(|page| compression.compress(page))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|compressed| {
+                let size: usize = compressed.iter().map(|page| page.len()).sum();
+                self.page_size.as_usize() * num_full > size + (size_of::<U32>() * num_full)
+            });
+        let compression = match compressed {
+            Some(_) => compression,
+            None => SegmentCompression::None,
+        };
+        let mut compressed = compressed.map(|c| c.into_iter());
+
+        // +1 for the metadata, +1 for the footer
+        let mut data = BytesVec::with_capacity(pages.to_usize() + 1 + 1);
         let mut index_builder = SegmentIndexBuilder::new_with_capacity(volumes, pages);
+        let mut checksums = Vec::with_capacity(pages.to_usize());
+        let mut offsets = Vec::new();
+        let mut offset = 0;
+        let mut deltas = Vec::new();
+        let mut delta_table = Vec::new();
+        let mut delta_offset = 0;
 
         // write pages to buffer while building index
+        let mut local_offset = 0;
         for (vid, pages) in self.index {
             for (off, page) in pages {
-                data.put(page.into());
+                match page {
+                    SegmentPage::Full(page) => {
+                        checksums.push(page_checksum(&page));
+                        if let Some(compressed) = compressed.as_mut() {
+                            let page = compressed.next().expect("missing compressed page");
+                            offset += page.len();
+                            offsets.push(U32::new(offset as u32));
+                            data.put(page);
+                        } else {
+                            data.put(page.into());
+                        }
+                    }
+                    SegmentPage::Delta(delta) => {
+                        checksums.push(page_checksum(delta.as_ref()));
+                        delta_offset += delta.len();
+                        delta_table.push(DeltaEntry::new(local_offset, delta_offset));
+                        deltas.push(delta);
+                    }
+                }
                 index_builder.insert(&vid, off);
+                local_offset += 1;
             }
         }
 
+        // write out the deltas
+        let num_deltas = deltas.len();
+        for delta in deltas {
+            data.put(delta.into());
+        }
+
+        // the metadata is written separately as it's covered by the footer
+        // checksum; +3 for the delta table, page offsets and checksums, +2 for
+        // the index
+        let mut meta = BytesVec::with_capacity(3 + 2);
+        if !delta_table.is_empty() {
+            meta.put_slice(delta_table.as_bytes());
+        }
+
+        // write out the page offsets if the segment is compressed
+        if !offsets.is_empty() {
+            meta.put_slice(offsets.as_bytes());
+        }
+
+        // write out the page checksums
+        meta.put_slice(checksums.as_bytes());
+
         // write out the index
-        let index_size = index_builder.finish(&mut data);
+        let index_size = index_builder.finish(&mut meta);
         debug_assert_eq!(
             index_size,
             SegmentIndexBuilder::serialized_size(volumes, pages),
             "index size mismatch"
         );
 
-        // write out the footer
-        let footer = SegmentFooter::new(self.sid.clone(), volumes, index_size);
+        // write out the footer, which contains a checksum of the metadata
+        let mut hasher = blake3::Hasher::new();
+        for chunk in meta.iter() {
+            hasher.update(chunk);
+        }
+        data.append(meta);
+        let footer = SegmentFooter::new(
+            self.sid.clone(),
+            volumes,
+            index_size,
+            self.page_size,
+            compression,
+            num_deltas,
+            hasher,
+        );
         data.put_slice(footer.as_bytes());
 
         (self.sid, data)
@@ -167,7 +304,7 @@ mod tests {
     use crate::segment::closed::{ClosedSegment, SEGMENT_MAX_PAGES};
     use assert_matches::assert_matches;
     use bytes::Buf;
-    use graft_core::pageidx;
+    use graft_core::{page::Page, pageidx};
 
     #[graft_test::test]
     fn test_segment_sanity() {
@@ -185,12 +322,18 @@ mod tests {
             .unwrap();
 
         // ensure that we can query pages in the open_segment
-        assert_eq!(open_segment.find_page(&vid, pageidx!(1)), Some(&page0));
-        assert_eq!(open_segment.find_page(&vid, pageidx!(2)), Some(&page1));
+        assert_eq!(
+            open_segment.find_page(&vid, pageidx!(1)),
+            Some(&page0.clone().into())
+        );
+        assert_eq!(
+            open_segment.find_page(&vid, pageidx!(2)),
+            Some(&page1.clone().into())
+        );
 
         let expected_size = open_segment.serialized_size();
 
-        let (sid, buf) = open_segment.serialize();
+        let (sid, buf) = open_segment.serialize(SegmentCompression::None);
 
         assert_eq!(buf.remaining(), expected_size);
 
@@ -200,15 +343,56 @@ mod tests {
         assert_eq!(closed_segment.sid(), &sid);
         assert_eq!(closed_segment.pages(), 2);
         assert!(!closed_segment.is_empty());
-        assert_eq!(closed_segment.find_page(&vid, pageidx!(1)), Some(page0));
-        assert_eq!(closed_segment.find_page(&vid, pageidx!(2)), Some(page1));
+        closed_segment.verify_checksum().unwrap();
+        assert_eq!(
+            closed_segment.find_page(&vid, pageidx!(1)).unwrap(),
+            Some(page0.into())
+        );
+        assert_eq!(
+            closed_segment.find_page(&vid, pageidx!(2)).unwrap(),
+            Some(page1.into())
+        );
+    }
+
+    #[graft_test::test]
+    fn test_segment_page_size() {
+        for page_size in [PageSize::MIN, PageSize::MAX] {
+            let mut open_segment = OpenSegment::new(page_size);
+            assert_eq!(open_segment.page_size(), page_size);
+
+            let vid = VolumeId::random();
+            let page = Page::test_filled_sized(7, page_size);
+            for pageidx in open_segment.max_pages().iter() {
+                open_segment
+                    .insert(vid.clone(), pageidx, page.clone())
+                    .unwrap();
+            }
+            assert!(!open_segment.has_space_for(&vid));
+
+            let expected_size = open_segment.serialized_size();
+            let (_, buf) = open_segment.serialize(SegmentCompression::None);
+            assert_eq!(buf.remaining(), expected_size);
+
+            let buf = buf.into_bytes();
+            let closed_segment = ClosedSegment::from_bytes(&buf).unwrap();
+            closed_segment.verify_checksum().unwrap();
+            assert_eq!(closed_segment.page_size(), page_size);
+            assert_eq!(closed_segment.pages(), segment_max_pages(page_size));
+            assert_eq!(
+                closed_segment.find_page(&vid, pageidx!(1)).unwrap(),
+                Some(page.clone().into())
+            );
+            for found in closed_segment.iter() {
+                assert_eq!(found.unwrap().2, page.clone().into());
+            }
+        }
     }
 
     #[graft_test::test]
     fn test_zero_length_segment() {
         let open_segment = OpenSegment::default();
         let expected_size = open_segment.serialized_size();
-        let (sid, buf) = open_segment.serialize();
+        let (sid, buf) = open_segment.serialize(SegmentCompression::None);
 
         assert_eq!(buf.remaining(), expected_size);
 
@@ -246,7 +430,7 @@ mod tests {
         assert!(!open_segment.has_space_for(&VolumeId::random()));
 
         let expected_size = open_segment.serialized_size();
-        let (_, buf) = open_segment.serialize();
+        let (_, buf) = open_segment.serialize(SegmentCompression::None);
         assert_eq!(buf.remaining(), expected_size);
 
         let buf = buf.into_bytes();
@@ -258,6 +442,7 @@ mod tests {
             assert!(
                 closed_segment
                     .find_page(vid_cycle.next().unwrap(), pageidx)
+                    .unwrap()
                     .is_some()
             );
         }
@@ -0,0 +1,200 @@
+//! Replicates segments to peer pagestores before they are uploaded to object
+//! storage. Once every peer has a copy of a segment, writes to the segment can
+//! be acknowledged without waiting for object storage. Each pagestore then
+//! uploads its copy of the segment independently; since every copy is
+//! identical, it doesn't matter which upload wins.
+
+use std::time::Duration;
+
+use bytes::Bytes;
+use culprit::Culprit;
+use futures::future::try_join_all;
+use graft_client::{ClientErr, NetClient, PagestoreClient};
+use serde::{Deserialize, Serialize};
+use tokio::task::spawn_blocking;
+use url::Url;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReplicationConfig {
+    /// the pagestores which receive a copy of every segment written by this
+    /// pagestore
+    #[serde(default)]
+    pub peers: Vec<Url>,
+
+    /// accept copies of segments from peer pagestores. Requires auth, as
+    /// only other Graft services may send replicas.
+    #[serde(default)]
+    pub accept_replicas: bool,
+
+    /// how often open segments are flushed, in milliseconds. Writes are
+    /// acknowledged once their segment has been flushed and replicated, so
+    /// this determines most of the write latency.
+    #[serde(default = "default_flush_interval_ms")]
+    pub flush_interval_ms: u64,
+}
+
+fn default_flush_interval_ms() -> u64 {
+    50
+}
+
+impl Default for ReplicationConfig {
+    fn default() -> Self {
+        Self {
+            peers: Vec::new(),
+            accept_replicas: false,
+            flush_interval_ms: default_flush_interval_ms(),
+        }
+    }
+}
+
+impl ReplicationConfig {
+    pub fn flush_interval(&self) -> Duration {
+        Duration::from_millis(self.flush_interval_ms)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SegmentReplicator {
+    peers: Vec<PagestoreClient>,
+}
+
+impl SegmentReplicator {
+    pub fn new(config: &ReplicationConfig, client: NetClient) -> Self {
+        let peers = config
+            .peers
+            .iter()
+            .map(|peer| PagestoreClient::new(peer.clone(), client.clone()))
+            .collect();
+        Self { peers }
+    }
+
+    /// Send a serialized segment to every peer, returning once every peer
+    /// has a copy of the segment
+    pub async fn replicate(&self, segment: Bytes) -> Result<(), Culprit<ClientErr>> {
+        try_join_all(self.peers.iter().map(|peer| {
+            let peer = peer.clone();
+            let segment = segment.clone();
+            async move {
+                spawn_blocking(move || peer.replicate_segment(segment))
+                    .await
+                    .expect("spawn_blocking failed")
+            }
+        }))
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum_test::TestServer;
+    use event_listener::Event;
+    use graft_client::MetastoreClient;
+    use graft_core::{VolumeId, page::Page, pageidx};
+    use object_store::{memory::InMemory, path::Path};
+    use tokio::sync::mpsc;
+
+    use crate::{
+        api::{
+            pagestore::{PagestoreApiState, pagestore_routes},
+            routes::build_router,
+        },
+        metrics::registry::Registry,
+        segment::{
+            cache::{Cache, mem::MemCache},
+            compression::SegmentCompression,
+            loader::SegmentLoader,
+            open::OpenSegment,
+            uploader::{SegmentUploaderTask, StoreSegmentMsg, testonly_wait_for_upload},
+        },
+        supervisor::SupervisedTask,
+        volume::{catalog::VolumeCatalog, updater::VolumeCatalogUpdater},
+    };
+
+    use super::*;
+
+    /// Spawns an uploader for a pagestore with its own store and cache,
+    /// returning senders for written and replicated segments
+    #[allow(clippy::type_complexity)]
+    fn spawn_uploader(
+        replicator: Option<SegmentReplicator>,
+    ) -> (
+        mpsc::Sender<StoreSegmentMsg>,
+        mpsc::Sender<StoreSegmentMsg>,
+        Arc<InMemory>,
+        Arc<MemCache>,
+    ) {
+        let store = Arc::new(InMemory::default());
+        let cache = Arc::new(MemCache::default());
+        let (store_tx, store_rx) = mpsc::channel(8);
+        let (replica_tx, replica_rx) = mpsc::channel(8);
+        let mut uploader = SegmentUploaderTask::new(
+            Default::default(),
+            store_rx,
+            store.clone(),
+            cache.clone(),
+            SegmentCompression::None,
+        )
+        .with_replicas(replica_rx);
+        if let Some(replicator) = replicator {
+            uploader = uploader.with_replicator(replicator);
+        }
+        uploader.testonly_spawn();
+        (store_tx, replica_tx, store, cache)
+    }
+
+    #[graft_test::test]
+    async fn test_replicate_to_peer() {
+        let client = NetClient::new(None);
+
+        // start a peer which accepts replicated segments
+        let (_, peer_tx, peer_store, peer_cache) = spawn_uploader(None);
+        let catalog = VolumeCatalog::open_temporary().unwrap();
+        let state = Arc::new(
+            PagestoreApiState::new(
+                mpsc::channel(1).0,
+                catalog,
+                SegmentLoader::new(peer_store.clone(), peer_cache.clone(), 8),
+                MetastoreClient::new("http://localhost:3000".parse().unwrap(), client.clone()),
+                VolumeCatalogUpdater::new(10),
+                10,
+            )
+            .with_replica_tx(peer_tx),
+        );
+        let router = build_router(Registry::default(), None, state, pagestore_routes());
+        let peer = TestServer::builder()
+            .http_transport()
+            .build(router)
+            .unwrap();
+
+        let config = ReplicationConfig {
+            peers: vec![peer.server_address().unwrap()],
+            ..Default::default()
+        };
+        let replicator = SegmentReplicator::new(&config, client);
+        let (store_tx, _, store, _) = spawn_uploader(Some(replicator));
+
+        let mut segment = OpenSegment::default();
+        segment
+            .insert(VolumeId::random(), pageidx!(1), Page::test_filled(1))
+            .unwrap();
+        let sid = segment.sid().clone();
+
+        // the segment is acknowledged once the peer has a copy
+        let event = Event::with_tag();
+        let listener = event.listen();
+        store_tx
+            .send(StoreSegmentMsg::new(segment, event))
+            .await
+            .unwrap();
+        listener.await.unwrap();
+        let replica = peer_cache.get(&sid).await.unwrap().unwrap().to_vec();
+
+        // both pagestores upload identical copies of the segment
+        let path = Path::from(sid.pretty());
+        assert_eq!(testonly_wait_for_upload(&*store, &path).await, replica);
+        assert_eq!(testonly_wait_for_upload(&*peer_store, &path).await, replica);
+    }
+}
@@ -1,13 +1,14 @@
-use std::{convert::Infallible, sync::Arc};
+use std::{convert::Infallible, sync::Arc, time::Duration};
 
-use bytes::Buf;
+use bytes::{Buf, Bytes};
 use culprit::Culprit;
 use event_listener::{Event, EventListener, IntoNotification};
 use futures::FutureExt;
+use graft_core::SegmentId;
 use measured::{CounterVec, Histogram, MetricGroup, metric::histogram::Thresholds};
 use object_store::{ObjectStore, PutPayload, path::Path};
 use thiserror::Error;
-use tokio::sync::mpsc;
+use tokio::{sync::mpsc, task::JoinSet, time::sleep};
 
 use crate::{
     api::error::ApiErrCtx,
@@ -15,7 +16,9 @@ use crate::{
     supervisor::{SupervisedTask, TaskCfg, TaskCtx},
 };
 
-use super::{cache::Cache, open::OpenSegment};
+use super::{
+    cache::Cache, compression::SegmentCompression, open::OpenSegment, replicator::SegmentReplicator,
+};
 
 #[derive(MetricGroup)]
 #[metric(new())]
@@ -23,6 +26,9 @@ pub struct SegmentUploaderMetrics {
     /// Number of segments uploaded, broken down by result
     uploaded_segments: CounterVec<ResultLabelSet>,
 
+    /// Number of segments replicated to peers, broken down by result
+    replicated_segments: CounterVec<ResultLabelSet>,
+
     /// Size of segments uploaded in bytes
     // Generates 8 buckets from 128 KiB to 16 MiB
     #[metric(metadata = Thresholds::exponential_buckets(131_072.0, 2.0))]
@@ -51,38 +57,85 @@ impl From<object_store::Error> for SegmentUploadErr {
     }
 }
 
+/// The delay before the first retry of a failed upload of a held segment
+const UPLOAD_RETRY_MIN: Duration = Duration::from_millis(100);
+
+/// The maximum delay between retries of a failed upload of a held segment
+const UPLOAD_RETRY_MAX: Duration = Duration::from_secs(30);
+
 // Event that is triggered when a segment upload completes
 pub type SegmentUploadEvent = Event<Result<(), SegmentUploadErr>>;
 pub type SegmentUploadListener = EventListener<Result<(), SegmentUploadErr>>;
 
+enum PendingSegment {
+    /// a segment written by this pagestore
+    Open(OpenSegment),
+
+    /// a serialized segment replicated from a peer
+    Replica(SegmentId, Bytes),
+}
+
 pub struct StoreSegmentMsg {
-    segment: OpenSegment,
+    segment: PendingSegment,
     complete: SegmentUploadEvent,
 }
 
 impl StoreSegmentMsg {
     pub fn new(segment: OpenSegment, complete: SegmentUploadEvent) -> Self {
-        Self { segment, complete }
+        Self {
+            segment: PendingSegment::Open(segment),
+            complete,
+        }
     }
 
-    pub fn segment(&self) -> &OpenSegment {
-        &self.segment
+    /// Store a serialized segment replicated from a peer. `complete` is
+    /// notified once this pagestore has a copy of the segment, rather than
+    /// once the segment has been uploaded.
+    pub fn replica(sid: SegmentId, segment: Bytes, complete: SegmentUploadEvent) -> Self {
+        Self {
+            segment: PendingSegment::Replica(sid, segment),
+            complete,
+        }
+    }
+
+    /// Returns the open segment, unless this segment was replicated from a
+    /// peer
+    pub fn segment(&self) -> Option<&OpenSegment> {
+        match &self.segment {
+            PendingSegment::Open(segment) => Some(segment),
+            PendingSegment::Replica(..) => None,
+        }
     }
 }
 
 impl std::fmt::Debug for StoreSegmentMsg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("StoreSegmentMsg")
-            .field("segment", &self.segment)
-            .finish()
+        let mut f = f.debug_struct("StoreSegmentMsg");
+        match &self.segment {
+            PendingSegment::Open(segment) => f.field("segment", segment),
+            PendingSegment::Replica(sid, _) => f.field("replica", sid),
+        };
+        f.finish()
     }
 }
 
 pub struct SegmentUploaderTask<C> {
     metrics: Arc<SegmentUploaderMetrics>,
+
+    /// segments flushed by the segment writer. The writer must hold the only
+    /// sender, so that the input closes once the writer exits.
     input: mpsc::Receiver<StoreSegmentMsg>,
+
+    /// segments replicated from peers
+    replicas: Option<mpsc::Receiver<StoreSegmentMsg>>,
+
     store: Arc<dyn ObjectStore>,
     cache: Arc<C>,
+    compression: SegmentCompression,
+    replicator: Option<SegmentReplicator>,
+
+    /// uploads of segments which were acknowledged before being uploaded
+    uploads: JoinSet<()>,
 }
 
 impl<C: Cache + 'static> SupervisedTask for SegmentUploaderTask<C> {
@@ -99,12 +152,31 @@ impl<C: Cache + 'static> SupervisedTask for SegmentUploaderTask<C> {
                     self.handle_store_request(req).await;
                 }
 
-                _ = ctx.wait_shutdown() => {
-                    // Shutdown immediately, discarding any pending writes
-                    break;
+                Some(req) = recv_replica(&mut self.replicas) => {
+                    self.handle_store_request(req).await;
                 }
+
+                Some(_) = self.uploads.join_next(), if !self.uploads.is_empty() => {}
+
+                _ = ctx.wait_shutdown() => break,
+            }
+        }
+
+        // Stop accepting replicas and store the replicas which are already
+        // queued. Then upload the segments flushed by the writer while it
+        // drains, until the writer exits on shutdown and closes the input.
+        // Finally, wait for background uploads to complete.
+        tracing::info!("segment uploader draining pending uploads");
+        if let Some(mut replicas) = self.replicas.take() {
+            replicas.close();
+            while let Some(req) = replicas.recv().await {
+                self.handle_store_request(req).await;
             }
         }
+        while let Some(req) = self.input.recv().await {
+            self.handle_store_request(req).await;
+        }
+        self.uploads.join_all().await;
         Ok(())
     }
 }
@@ -115,20 +187,52 @@ impl<C: Cache + 'static> SegmentUploaderTask<C> {
         input: mpsc::Receiver<StoreSegmentMsg>,
         store: Arc<dyn ObjectStore>,
         cache: Arc<C>,
+        compression: SegmentCompression,
     ) -> Self {
-        Self { metrics, input, store, cache }
+        Self {
+            metrics,
+            input,
+            store,
+            cache,
+            compression,
+            replicas: None,
+            replicator: None,
+            uploads: JoinSet::new(),
+        }
+    }
+
+    /// Replicate segments to peers before uploading them. Writes are
+    /// acknowledged once every peer has a copy of the segment, and the
+    /// segment is uploaded in the background.
+    pub fn with_replicator(mut self, replicator: SegmentReplicator) -> Self {
+        self.replicator = Some(replicator);
+        self
+    }
+
+    /// Store segments replicated from peers. Replicas are no longer accepted
+    /// once the uploader starts shutting down.
+    pub fn with_replicas(mut self, replicas: mpsc::Receiver<StoreSegmentMsg>) -> Self {
+        self.replicas = Some(replicas);
+        self
     }
 
     #[tracing::instrument(name = "upload segment", skip(self), fields(sid))]
     async fn handle_store_request(&mut self, req: StoreSegmentMsg) {
-        // skip uploading segment if all writers are gone
-        if req.complete.total_listeners() == 0 {
-            return;
-        }
+        let (sid, segment, replica) = match req.segment {
+            PendingSegment::Open(segment) => {
+                // skip uploading segment if all writers are gone
+                if req.complete.total_listeners() == 0 {
+                    return;
+                }
 
-        // serialize the segment
-        let segment = req.segment;
-        let (sid, segment) = segment.serialize();
+                // serialize the segment
+                let (sid, segment) = segment.serialize(self.compression);
+                (sid, segment.into_bytes(), false)
+            }
+            // replicas are always uploaded, as the peer which wrote the
+            // segment may fail before uploading its copy
+            PendingSegment::Replica(sid, segment) => (sid, segment, true),
+        };
 
         tracing::Span::current().record("sid", sid.short());
 
@@ -136,11 +240,19 @@ impl<C: Cache + 'static> SegmentUploaderTask<C> {
             .segment_size_bytes
             .observe(segment.remaining() as f64);
 
-        // optimistically cache the segment
-        // we don't care if this fails or we don't end up using the segment
-        // since we can always redownload missing segments and segments ids are
-        // never reused (and are globally unique)
-        {
+        // a segment is held once this pagestore has pinned it in the cache,
+        // and every peer has a copy if it was written by this pagestore. Held
+        // segments are acknowledged immediately and uploaded in the
+        // background until the upload succeeds.
+        let held = if replica {
+            self.hold(&sid, segment.clone(), None).await
+        } else if let Some(replicator) = &self.replicator {
+            self.hold(&sid, segment.clone(), Some(replicator)).await
+        } else {
+            // optimistically cache the segment
+            // we don't care if this fails or we don't end up using the segment
+            // since we can always redownload missing segments and segments ids are
+            // never reused (and are globally unique)
             let cache = self.cache.clone();
             let sid = sid.clone();
             let segment = segment.clone();
@@ -155,25 +267,124 @@ impl<C: Cache + 'static> SegmentUploaderTask<C> {
                     tracing::error!("failed to cache segment {:?}\n{:?}", sid, err);
                 }
             });
-        }
+            false
+        };
 
-        let path = Path::from(sid.pretty());
-        if let Err(err) = self
-            .store
-            .put(&path, PutPayload::from_iter(segment.iter().cloned()))
-            .inspect(|result| {
-                self.metrics.uploaded_segments.inc(result.into());
-            })
-            .await
-        {
-            tracing::error!("failed to upload segment {:?}\n{:?}", sid, err);
-            req.complete.notify(usize::MAX.tag(Err(SegmentUploadErr)));
-        } else {
+        if held {
             req.complete.notify(usize::MAX.tag(Ok(())));
+            self.uploads.spawn(upload_held_segment(
+                self.metrics.clone(),
+                self.store.clone(),
+                self.cache.clone(),
+                sid,
+                segment,
+            ));
+        } else {
+            let result =
+                upload_segment(self.metrics.clone(), self.store.clone(), sid, segment).await;
+            req.complete.notify(usize::MAX.tag(result));
+        }
+    }
+
+    /// Pin the segment in the cache and replicate it to peers, returning true
+    /// if both succeed. Held segments remain pinned until they are uploaded.
+    async fn hold(
+        &self,
+        sid: &SegmentId,
+        segment: Bytes,
+        replicator: Option<&SegmentReplicator>,
+    ) -> bool {
+        // pin the segment before caching it, so it can't be evicted by a
+        // concurrent put
+        self.cache.pin(sid).await;
+        if let Err(err) = self.cache.put(sid, segment.clone()).await {
+            tracing::error!("failed to cache segment {:?}\n{:?}", sid, err);
+            self.cache.unpin(sid).await;
+            return false;
         }
+        let Some(replicator) = replicator else {
+            return true;
+        };
+        let result = replicator.replicate(segment).await;
+        self.metrics.replicated_segments.inc((&result).into());
+        if let Err(err) = result {
+            // fall back to acknowledging the segment once it's uploaded
+            tracing::error!("failed to replicate segment {:?}\n{:?}", sid, err);
+            self.cache.unpin(sid).await;
+            return false;
+        }
+        true
+    }
+}
+
+/// Receive the next replica, or wait forever if replicas aren't accepted
+async fn recv_replica(
+    replicas: &mut Option<mpsc::Receiver<StoreSegmentMsg>>,
+) -> Option<StoreSegmentMsg> {
+    match replicas {
+        Some(replicas) => replicas.recv().await,
+        None => std::future::pending().await,
     }
 }
 
+/// Upload a segment which has already been acknowledged, retrying until the
+/// upload succeeds. The segment is unpinned from the cache once it's uploaded.
+async fn upload_held_segment<C: Cache>(
+    metrics: Arc<SegmentUploaderMetrics>,
+    store: Arc<dyn ObjectStore>,
+    cache: Arc<C>,
+    sid: SegmentId,
+    segment: Bytes,
+) {
+    let mut delay = UPLOAD_RETRY_MIN;
+    while upload_segment(metrics.clone(), store.clone(), sid.clone(), segment.clone())
+        .await
+        .is_err()
+    {
+        tracing::warn!("retrying upload of segment {:?} in {:?}", sid, delay);
+        sleep(delay).await;
+        delay = (delay * 2).min(UPLOAD_RETRY_MAX);
+    }
+    cache.unpin(&sid).await;
+}
+
+async fn upload_segment(
+    metrics: Arc<SegmentUploaderMetrics>,
+    store: Arc<dyn ObjectStore>,
+    sid: SegmentId,
+    segment: Bytes,
+) -> Result<(), SegmentUploadErr> {
+    let path = Path::from(sid.pretty());
+    if let Err(err) = store
+        .put(&path, PutPayload::from(segment))
+        .inspect(|result| {
+            metrics.uploaded_segments.inc(result.into());
+        })
+        .await
+    {
+        tracing::error!("failed to upload segment {:?}\n{:?}", sid, err);
+        return Err(SegmentUploadErr);
+    }
+    Ok(())
+}
+
+/// Wait for a segment which is uploaded in the background to appear in the
+/// store, panicking if it doesn't appear within a few seconds
+#[cfg(test)]
+pub(crate) async fn testonly_wait_for_upload(store: &dyn ObjectStore, path: &Path) -> Bytes {
+    let poll = async {
+        loop {
+            match store.get(path).await {
+                Ok(obj) => return obj.bytes().await.unwrap(),
+                Err(_) => sleep(Duration::from_millis(10)).await,
+            }
+        }
+    };
+    tokio::time::timeout(Duration::from_secs(5), poll)
+        .await
+        .unwrap_or_else(|_| panic!("segment {path} was not uploaded"))
+}
+
 #[cfg(test)]
 mod tests {
     use graft_core::{gid::VolumeId, page::Page, pageidx};
@@ -190,8 +401,13 @@ mod tests {
         let store = Arc::new(InMemory::default());
         let cache = Arc::new(MemCache::default());
 
-        let task =
-            SegmentUploaderTask::new(Default::default(), input_rx, store.clone(), cache.clone());
+        let task = SegmentUploaderTask::new(
+            Default::default(),
+            input_rx,
+            store.clone(),
+            cache.clone(),
+            SegmentCompression::Zstd,
+        );
         task.testonly_spawn();
 
         let mut segment = OpenSegment::default();
@@ -212,7 +428,7 @@ mod tests {
         let event = Event::with_tag();
         let listener = event.listen();
         input_tx
-            .send(StoreSegmentMsg { segment, complete: event })
+            .send(StoreSegmentMsg::new(segment, event))
             .await
             .unwrap();
 
@@ -225,8 +441,15 @@ mod tests {
         let segment = ClosedSegment::from_bytes(&bytes).unwrap();
 
         assert_eq!(segment.pages(), 2);
-        assert_eq!(segment.find_page(&vid, pageidx!(1)), Some(page0));
-        assert_eq!(segment.find_page(&vid, pageidx!(2)), Some(page1));
+        assert_eq!(segment.compression(), SegmentCompression::Zstd);
+        assert_eq!(
+            segment.find_page(&vid, pageidx!(1)).unwrap(),
+            Some(page0.into())
+        );
+        assert_eq!(
+            segment.find_page(&vid, pageidx!(2)).unwrap(),
+            Some(page1.into())
+        );
 
         // check that the cached and stored segment are identical
         let cached = cache.get(&sid).await.unwrap().unwrap();
@@ -1,10 +1,10 @@
 //! A Segment writer is a task which builds open segments and passes them on
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use culprit::{Culprit, ResultExt};
 use event_listener::Event;
-use graft_core::{PageIdx, SegmentId, VolumeId, page::Page};
+use graft_core::{PageIdx, PageSize, SegmentId, VolumeId, lsn::LSN, page::Page};
 use measured::{Counter, MetricGroup};
 use splinter_rs::Splinter;
 use thiserror::Error;
@@ -14,13 +14,11 @@ use tokio::{
 };
 
 use super::{
+    delta::{PageDelta, SegmentPage},
     open::OpenSegment,
     uploader::{SegmentUploadEvent, SegmentUploadListener, StoreSegmentMsg},
 };
-use crate::{
-    segment::closed::SEGMENT_MAX_PAGES,
-    supervisor::{SupervisedTask, TaskCfg, TaskCtx},
-};
+use crate::supervisor::{SupervisedTask, TaskCfg, TaskCtx};
 
 #[derive(Debug, Error)]
 pub enum WriterErr {
@@ -39,31 +37,61 @@ pub struct SegmentWriterMetrics {
     /// Number of pages written to segments
     page_writes: Counter,
 
+    /// Number of pages stored as deltas
+    page_deltas: Counter,
+
     /// Number of segments that have been flushed
     flushed_segments: Counter,
 }
 
 pub struct WritePagesRequest {
     vid: VolumeId,
+    /// every page in the request must be this size
+    page_size: PageSize,
     pages: Vec<(PageIdx, Page)>,
+    /// the checkpoint version of pages in the request, along with the
+    /// checkpoint's LSN
+    delta_bases: Option<(LSN, HashMap<PageIdx, Page>)>,
     reply: oneshot::Sender<WritePagesResponse>,
 }
 
 impl WritePagesRequest {
     pub fn new(
         vid: VolumeId,
+        page_size: PageSize,
         pages: Vec<(PageIdx, Page)>,
         reply: oneshot::Sender<WritePagesResponse>,
     ) -> Self {
-        Self { vid, pages, reply }
+        Self {
+            vid,
+            page_size,
+            pages,
+            delta_bases: None,
+            reply,
+        }
+    }
+
+    /// Allow pages which mostly match their version in the checkpoint to be
+    /// stored as deltas
+    pub fn with_delta_bases(mut self, checkpoint: LSN, bases: HashMap<PageIdx, Page>) -> Self {
+        self.delta_bases = Some((checkpoint, bases));
+        self
     }
 }
 
 pub struct WritePagesResponse {
     segments: Vec<(SegmentId, Splinter, SegmentUploadListener)>,
+    delta_checkpoint: Option<LSN>,
 }
 
 impl WritePagesResponse {
+    /// If any pages were stored as deltas, returns the checkpoint the deltas
+    /// are based on
+    #[inline]
+    pub fn delta_checkpoint(&self) -> Option<LSN> {
+        self.delta_checkpoint
+    }
+
     #[inline]
     pub fn len(&self) -> usize {
         self.segments.len()
@@ -89,14 +117,19 @@ pub struct SegmentWriterTask {
     input: mpsc::Receiver<WritePagesRequest>,
     output: mpsc::Sender<StoreSegmentMsg>,
 
-    // the active open segment being written to
-    segment: OpenSegment,
-    event: SegmentUploadEvent,
+    // the active open segments being written to. segments only contain pages
+    // of a single size, so there is one open segment per page size
+    segments: HashMap<PageSize, ActiveSegment>,
 
     flush_interval: Duration,
     next_flush: Instant,
 }
 
+struct ActiveSegment {
+    segment: OpenSegment,
+    event: SegmentUploadEvent,
+}
+
 impl SupervisedTask for SegmentWriterTask {
     type Err = WriterErr;
 
@@ -109,20 +142,28 @@ impl SupervisedTask for SegmentWriterTask {
             tokio::select! {
                 biased;
 
-                _ = ctx.wait_shutdown() => {
-                    // Shutdown immediately, discarding any pending writes
-                    break;
-                }
+                _ = ctx.wait_shutdown() => break,
 
-                Some(req) = self.input.recv() => {
-                    self.handle_write(req).await?;
-                }
+                req = self.input.recv() => match req {
+                    Some(req) => self.handle_write(req).await?,
+                    None => break,
+                },
 
                 _ = sleep_until(self.next_flush) => {
                     self.handle_flush().await?;
                 }
             }
         }
+
+        // Stop accepting writes, and flush the writes which are already
+        // queued along with any open segments before exiting. Writes sent
+        // after shutdown starts are rejected.
+        tracing::info!("segment writer draining pending writes");
+        self.input.close();
+        while let Some(req) = self.input.recv().await {
+            self.handle_write(req).await?;
+        }
+        self.handle_flush().await?;
         Ok(())
     }
 }
@@ -138,8 +179,7 @@ impl SegmentWriterTask {
             metrics,
             input,
             output,
-            segment: Default::default(),
-            event: Event::with_tag(),
+            segments: HashMap::new(),
             flush_interval,
             next_flush: Instant::now() + flush_interval,
         }
@@ -149,22 +189,51 @@ impl SegmentWriterTask {
         tracing::trace!("writing {} pages to volume {:?}", req.pages.len(), req.vid);
         self.metrics.page_writes.inc_by(req.pages.len() as u64);
 
+        // store pages which mostly match their checkpoint version as deltas
+        let mut delta_checkpoint = None;
+        let pages: Vec<(PageIdx, SegmentPage)> = match req.delta_bases {
+            Some((checkpoint, bases)) => req
+                .pages
+                .into_iter()
+                .map(|(pageidx, page)| {
+                    let delta = bases
+                        .get(&pageidx)
+                        .and_then(|base| PageDelta::encode(base, &page));
+                    match delta {
+                        Some(delta) => {
+                            delta_checkpoint = Some(checkpoint);
+                            self.metrics.page_deltas.inc();
+                            (pageidx, delta.into())
+                        }
+                        None => (pageidx, page.into()),
+                    }
+                })
+                .collect(),
+            None => req
+                .pages
+                .into_iter()
+                .map(|(pageidx, page)| (pageidx, page.into()))
+                .collect(),
+        };
+
         let mut segments = Vec::new();
-        let mut pages = req.pages.into_iter();
+        let mut pages = pages.into_iter();
         loop {
             // flush current segment if full or it already contains the current volume.
             // this ensures that two separate write requests to the same volume
             // can't end up in the same segment
-            if !self.segment.has_space_for(&req.vid) || self.segment.contains_vid(&req.vid) {
-                self.handle_flush().await?
+            let active = self.active_segment(req.page_size);
+            if !active.segment.has_space_for(&req.vid) || active.segment.contains_vid(&req.vid) {
+                self.flush_segment(req.page_size).await?
             }
 
             // write as many pages as possible to the current segment
-            let graft = self
+            let active = self.active_segment(req.page_size);
+            let graft = active
                 .segment
                 .batch_insert(req.vid.clone(), &mut pages)
                 .expect("segment was just verified to have space");
-            segments.push((self.segment.sid().clone(), graft, self.event.listen()));
+            segments.push((active.segment.sid().clone(), graft, active.event.listen()));
 
             // if the iterator is exhausted we are done
             if pages.len() == 0 {
@@ -173,50 +242,76 @@ impl SegmentWriterTask {
         }
 
         // if the segment is full of pages, we can trigger an early flush
-        if self.segment.pages() == SEGMENT_MAX_PAGES {
-            self.handle_flush().await?;
+        let active = self.active_segment(req.page_size);
+        if active.segment.pages() == active.segment.max_pages() {
+            self.flush_segment(req.page_size).await?;
         }
 
         // reply to the write request
-        let _ = req.reply.send(WritePagesResponse { segments });
+        let _ = req
+            .reply
+            .send(WritePagesResponse { segments, delta_checkpoint });
 
         Ok(())
     }
 
-    /// Flush the current segment and start a new one
+    /// Returns the open segment for the specified page size, starting a new
+    /// one if needed
+    fn active_segment(&mut self, page_size: PageSize) -> &mut ActiveSegment {
+        self.segments
+            .entry(page_size)
+            .or_insert_with(|| ActiveSegment {
+                segment: OpenSegment::new(page_size),
+                event: Event::with_tag(),
+            })
+    }
+
+    /// Flush every open segment
     async fn handle_flush(&mut self) -> Result<(), Culprit<WriterErr>> {
+        let page_sizes: Vec<PageSize> = self.segments.keys().copied().collect();
+        for page_size in page_sizes {
+            self.flush_segment(page_size).await?;
+        }
+
+        // update next_flush
+        self.next_flush = Instant::now() + self.flush_interval;
+
+        Ok(())
+    }
+
+    /// Flush the open segment for the specified page size. The next write of
+    /// this page size will start a new segment.
+    async fn flush_segment(&mut self, page_size: PageSize) -> Result<(), Culprit<WriterErr>> {
+        let Some(ActiveSegment { segment, event }) = self.segments.remove(&page_size) else {
+            return Ok(());
+        };
+
         // only flush non-empty segments
-        if !self.segment.is_empty() {
+        if !segment.is_empty() {
             tracing::trace!(
                 "flushing segment to uploader with {} pages and {} volumes",
-                self.segment.pages(),
-                self.segment.volumes()
+                segment.pages(),
+                segment.volumes()
             );
 
             precept::expect_sometimes!(
-                self.segment.volumes() > 1,
+                segment.volumes() > 1,
                 "flushed segment has more than one volume",
                 {
-                    "volumes": self.segment.volumes(),
-                    "pages": self.segment.pages(),
+                    "volumes": segment.volumes(),
+                    "pages": segment.pages(),
                 }
             );
 
-            // send the current segment to the output
+            // send the segment to the output
             self.output
-                .send(StoreSegmentMsg::new(
-                    std::mem::take(&mut self.segment),
-                    std::mem::replace(&mut self.event, Event::with_tag()),
-                ))
+                .send(StoreSegmentMsg::new(segment, event))
                 .await
                 .or_into_ctx()?;
 
             self.metrics.flushed_segments.inc();
         }
 
-        // update next_flush
-        self.next_flush = Instant::now() + self.flush_interval;
-
         Ok(())
     }
 }
@@ -225,6 +320,8 @@ impl SegmentWriterTask {
 mod tests {
     use graft_core::{VolumeId, page::Page, pageidx};
 
+    use crate::supervisor::Supervisor;
+
     use super::*;
 
     #[graft_test::test]
@@ -248,25 +345,207 @@ mod tests {
         let (tx, rx) = oneshot::channel();
 
         input_tx
-            .send(WritePagesRequest {
-                vid: vid.clone(),
-                pages: vec![(pageidx!(1), page0.clone()), (pageidx!(2), page1.clone())],
-                reply: tx,
-            })
+            .send(WritePagesRequest::new(
+                vid.clone(),
+                PageSize::DEFAULT,
+                vec![(pageidx!(1), page0.clone()), (pageidx!(2), page1.clone())],
+                tx,
+            ))
             .await
             .unwrap();
 
         // wait for the reply
         let response = rx.await.unwrap();
 
+        assert_eq!(response.delta_checkpoint(), None);
         let (_, graft, _) = response.into_iter().next().unwrap();
         assert!(graft.contains(1));
         assert!(graft.contains(2));
 
         // wait for the flush
         let flush = output_rx.recv().await.unwrap();
-        let segment = flush.segment();
-        assert_eq!(segment.find_page(&vid, pageidx!(1)), Some(&page0));
-        assert_eq!(segment.find_page(&vid, pageidx!(2)), Some(&page1));
+        let segment = flush.segment().unwrap();
+        assert_eq!(segment.find_page(&vid, pageidx!(1)), Some(&page0.into()));
+        assert_eq!(segment.find_page(&vid, pageidx!(2)), Some(&page1.into()));
+    }
+
+    #[graft_test::test]
+    async fn test_writer_page_size_change() {
+        let (input_tx, input_rx) = mpsc::channel(1);
+        let (output_tx, mut output_rx) = mpsc::channel(2);
+
+        let task = SegmentWriterTask::new(
+            Default::default(),
+            input_rx,
+            output_tx,
+            Duration::from_secs(1),
+        );
+        task.testonly_spawn();
+
+        let vid1 = VolumeId::random();
+        let vid2 = VolumeId::random();
+        let page0 = Page::test_filled(1);
+        let page1 = Page::test_filled_sized(2, PageSize::MAX);
+
+        // interleave writes of different page sizes to two volumes
+        for (vid, page_size, page) in [
+            (vid1.clone(), PageSize::DEFAULT, page0.clone()),
+            (vid2.clone(), PageSize::MAX, page1.clone()),
+            (vid1.clone(), PageSize::DEFAULT, page0.clone()),
+        ] {
+            let (tx, rx) = oneshot::channel();
+            input_tx
+                .send(WritePagesRequest::new(
+                    vid,
+                    page_size,
+                    vec![(pageidx!(1), page)],
+                    tx,
+                ))
+                .await
+                .unwrap();
+            rx.await.unwrap();
+        }
+
+        // changing the page size doesn't flush the open segments, so the
+        // only early flush is caused by the second write to vid1
+        let flush = output_rx.recv().await.unwrap();
+        let segment = flush.segment().unwrap();
+        assert_eq!(segment.page_size(), PageSize::DEFAULT);
+        assert_eq!(
+            segment.find_page(&vid1, pageidx!(1)),
+            Some(&page0.clone().into())
+        );
+        assert!(!segment.contains_vid(&vid2));
+
+        // the remaining segments are flushed after the flush interval
+        let mut flushed = vec![];
+        for _ in 0..2 {
+            let flush = output_rx.recv().await.unwrap();
+            let segment = flush.segment().unwrap();
+            flushed.push((segment.page_size(), segment.volumes()));
+            match segment.page_size() {
+                PageSize::DEFAULT => assert_eq!(
+                    segment.find_page(&vid1, pageidx!(1)),
+                    Some(&page0.clone().into())
+                ),
+                _ => assert_eq!(
+                    segment.find_page(&vid2, pageidx!(1)),
+                    Some(&page1.clone().into())
+                ),
+            }
+        }
+        flushed.sort();
+        assert_eq!(flushed, vec![(PageSize::DEFAULT, 1), (PageSize::MAX, 1)]);
+    }
+
+    #[graft_test::test]
+    async fn test_writer_page_deltas() {
+        let (input_tx, input_rx) = mpsc::channel(1);
+        let (output_tx, mut output_rx) = mpsc::channel(1);
+
+        let task = SegmentWriterTask::new(
+            Default::default(),
+            input_rx,
+            output_tx,
+            Duration::from_secs(1),
+        );
+        task.testonly_spawn();
+
+        let vid = VolumeId::random();
+        let base = Page::test_filled(1);
+
+        // page 1 changes a single byte, page 2 is completely rewritten, and
+        // page 3 has no base
+        let mut data = bytes::BytesMut::from(base.clone());
+        data[100] = 0;
+        let page1 = Page::try_from(data).unwrap();
+        let page2 = Page::test_filled(2);
+        let page3 = Page::test_filled(3);
+        let bases = HashMap::from([(pageidx!(1), base.clone()), (pageidx!(2), base.clone())]);
+
+        let (tx, rx) = oneshot::channel();
+        input_tx
+            .send(
+                WritePagesRequest::new(
+                    vid.clone(),
+                    PageSize::DEFAULT,
+                    vec![
+                        (pageidx!(1), page1.clone()),
+                        (pageidx!(2), page2.clone()),
+                        (pageidx!(3), page3.clone()),
+                    ],
+                    tx,
+                )
+                .with_delta_bases(LSN::new(5), bases),
+            )
+            .await
+            .unwrap();
+        let response = rx.await.unwrap();
+        assert_eq!(response.delta_checkpoint(), Some(LSN::new(5)));
+
+        let flush = output_rx.recv().await.unwrap();
+        let segment = flush.segment().unwrap();
+        let Some(SegmentPage::Delta(delta)) = segment.find_page(&vid, pageidx!(1)) else {
+            panic!("expected page 1 to be stored as a delta");
+        };
+        assert_eq!(delta.apply(&base).unwrap(), page1);
+        assert_eq!(segment.find_page(&vid, pageidx!(2)), Some(&page2.into()));
+        assert_eq!(segment.find_page(&vid, pageidx!(3)), Some(&page3.into()));
+    }
+
+    #[graft_test::test]
+    async fn test_writer_drain_on_shutdown() {
+        let (input_tx, input_rx) = mpsc::channel(1);
+        let (output_tx, mut output_rx) = mpsc::channel(4);
+
+        // use a long flush interval, so segments are only flushed by the drain
+        let mut supervisor = Supervisor::default();
+        supervisor.spawn(SegmentWriterTask::new(
+            Default::default(),
+            input_rx,
+            output_tx,
+            Duration::from_secs(60),
+        ));
+
+        let write = |vid: &VolumeId| {
+            let (tx, rx) = oneshot::channel();
+            let req = WritePagesRequest::new(
+                vid.clone(),
+                PageSize::DEFAULT,
+                vec![(pageidx!(1), Page::test_filled(1))],
+                tx,
+            );
+            (req, rx)
+        };
+
+        let vid1 = VolumeId::random();
+        let vid2 = VolumeId::random();
+        let (req, rx) = write(&vid1);
+        input_tx.send(req).await.unwrap();
+        rx.await.unwrap();
+
+        // writes which are queued when shutdown starts are still accepted
+        let (req, rx) = write(&vid2);
+        input_tx.send(req).await.unwrap();
+
+        // the writer exits on shutdown, even though a sender still exists
+        supervisor.shutdown(Duration::from_secs(5)).await.unwrap();
+        rx.await.unwrap();
+
+        // writes sent after shutdown are rejected
+        let (req, _rx) = write(&VolumeId::random());
+        assert!(input_tx.send(req).await.is_err());
+
+        // both writes were flushed rather than discarded
+        let mut flushed = vec![];
+        while let Some(flush) = output_rx.recv().await {
+            let segment = flush.segment().unwrap();
+            for vid in [&vid1, &vid2] {
+                if segment.contains_vid(vid) {
+                    flushed.push(vid.clone());
+                }
+            }
+        }
+        assert_eq!(flushed, vec![vid1, vid2]);
     }
 }
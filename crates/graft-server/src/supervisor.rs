@@ -96,6 +96,12 @@ impl Supervisor {
         });
     }
 
+    /// Returns a token which is cancelled when the Supervisor starts shutting
+    /// down.
+    pub fn shutdown_token(&self) -> CancellationToken {
+        self.shutdown.child_token()
+    }
+
     /// Supervise the tasks until they all complete.
     /// CANCEL SAFETY: This task is cancel safe.
     pub async fn supervise(&mut self) -> Result<(), Culprit<BoxedCtx>> {
@@ -0,0 +1,571 @@
+//! Per-tenant admission control for writes. Tenants are identified by the
+//! subject of their API token, and each tenant is limited by a pair of token
+//! buckets (pages per second and bytes per day) along with a quota on the
+//! combined size of the volumes it writes.
+//!
+//! Storage usage is computed by the caller from the latest snapshot of each
+//! volume the tenant has written, and cached here for a short interval.
+
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use culprit::Culprit;
+use graft_core::{VolumeId, byte_unit::ByteUnit};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::time::Instant;
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Tenants which haven't written for this long are forgotten. By then their
+/// token buckets have usually refilled.
+const TENANT_IDLE_TIMEOUT: Duration = Duration::from_secs(86_400);
+
+/// How often idle tenants are evicted
+const TENANT_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How long a tenant's storage usage is cached by default
+const DEFAULT_STORAGE_CACHE: Duration = Duration::from_secs(10);
+
+/// The subject of the API token used to make a request
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tenant(String);
+
+impl Default for Tenant {
+    /// The tenant of requests which don't identify a tenant, such as requests
+    /// made with a token without a subject or while auth is disabled
+    fn default() -> Self {
+        Self::new("anonymous")
+    }
+}
+
+impl Tenant {
+    pub fn new(subject: impl Into<String>) -> Self {
+        Self(subject.into())
+    }
+
+    pub fn subject(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, Error)]
+pub enum LimitErr {
+    #[error("page write rate exceeded")]
+    PageRate,
+
+    #[error("daily write quota exceeded")]
+    DailyBytes,
+
+    #[error("storage quota exceeded")]
+    Storage,
+}
+
+/// Limits applied to a single tenant. Unset limits are not enforced.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct TenantLimits {
+    /// the number of pages the tenant may write per second, with bursts of
+    /// up to a second of writes
+    pub pages_per_sec: Option<u32>,
+
+    /// the number of bytes the tenant may write per day
+    pub bytes_per_day: Option<ByteUnit>,
+
+    /// the maximum combined size of the volumes written by the tenant
+    pub max_storage: Option<ByteUnit>,
+}
+
+impl TenantLimits {
+    fn is_unlimited(&self) -> bool {
+        self.pages_per_sec.is_none() && self.bytes_per_day.is_none() && self.max_storage.is_none()
+    }
+}
+
+/// A batch of pages written to a volume by a tenant
+#[derive(Debug, Clone)]
+pub struct TenantWrite<'a> {
+    pub vid: &'a VolumeId,
+    pub pages: usize,
+    pub bytes: ByteUnit,
+
+    /// the size of the volume once the batch has been written, determined by
+    /// the highest page index in the volume
+    pub volume_size: ByteUnit,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct TenantLimitsConfig {
+    /// limits applied to every tenant without an override
+    pub default: TenantLimits,
+
+    /// limits for specific tenants, keyed by token subject
+    pub overrides: HashMap<String, TenantLimits>,
+
+    /// how long a tenant's storage usage is cached before it's recomputed,
+    /// in milliseconds. Defaults to 10 seconds.
+    pub storage_cache_ms: Option<u64>,
+}
+
+/// A token bucket which refills continuously at `rate` tokens per second, up
+/// to `capacity` tokens.
+#[derive(Debug)]
+struct TokenBucket {
+    rate: f64,
+    capacity: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: f64, capacity: f64, now: Instant) -> Self {
+        Self {
+            rate,
+            capacity,
+            tokens: capacity,
+            updated: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.updated = now;
+    }
+
+    /// Returns how long to wait until tokens are available, or None if
+    /// tokens are available now
+    fn wait_time(&self) -> Option<Duration> {
+        (self.tokens <= 0.0).then(|| Duration::from_secs_f64(-self.tokens / self.rate))
+    }
+
+    /// Take tokens from the bucket. Requests larger than the bucket's
+    /// capacity would otherwise never be admitted, so the bucket may go into
+    /// debt, delaying subsequent requests until it has refilled.
+    fn take(&mut self, tokens: f64) {
+        self.tokens -= tokens;
+    }
+}
+
+/// The size of each volume written by a tenant
+#[derive(Debug)]
+struct StorageUsage {
+    /// when the usage was computed
+    computed: Instant,
+    volumes: HashMap<VolumeId, ByteUnit>,
+}
+
+#[derive(Debug)]
+struct TenantState {
+    pages: Option<TokenBucket>,
+    bytes: Option<TokenBucket>,
+
+    /// the tenant's storage usage, if it has been computed
+    storage: Option<StorageUsage>,
+
+    /// when the tenant last wrote
+    active: Instant,
+}
+
+impl TenantState {
+    fn new(now: Instant) -> Self {
+        Self {
+            pages: None,
+            bytes: None,
+            storage: None,
+            active: now,
+        }
+    }
+
+    /// The combined size of the tenant's volumes once `write` completes
+    fn storage_after(&self, write: &TenantWrite<'_>) -> ByteUnit {
+        let Some(storage) = &self.storage else {
+            return write.volume_size;
+        };
+        let prev = storage
+            .volumes
+            .get(write.vid)
+            .copied()
+            .unwrap_or(ByteUnit::ZERO);
+        let total: u64 = storage.volumes.values().map(|size| size.as_u64()).sum();
+        ByteUnit::new(total - prev.as_u64() + prev.max(write.volume_size).as_u64())
+    }
+}
+
+#[derive(Debug)]
+struct Tenants {
+    states: HashMap<Tenant, TenantState>,
+    swept: Instant,
+}
+
+impl Tenants {
+    /// Forget tenants which haven't written recently
+    fn sweep(&mut self, now: Instant) {
+        if now.saturating_duration_since(self.swept) < TENANT_SWEEP_INTERVAL {
+            return;
+        }
+        self.states
+            .retain(|_, state| now.saturating_duration_since(state.active) < TENANT_IDLE_TIMEOUT);
+        self.swept = now;
+    }
+}
+
+pub struct TenantLimiter {
+    config: TenantLimitsConfig,
+    storage_cache: Duration,
+    tenants: Mutex<Tenants>,
+}
+
+impl Default for TenantLimiter {
+    fn default() -> Self {
+        Self::new(TenantLimitsConfig::default())
+    }
+}
+
+impl TenantLimiter {
+    pub fn new(config: TenantLimitsConfig) -> Self {
+        let tenants = Tenants {
+            states: HashMap::new(),
+            swept: Instant::now(),
+        };
+        let storage_cache = config
+            .storage_cache_ms
+            .map_or(DEFAULT_STORAGE_CACHE, Duration::from_millis);
+        Self {
+            config,
+            storage_cache,
+            tenants: Mutex::new(tenants),
+        }
+    }
+
+    fn limits(&self, tenant: &Tenant) -> &TenantLimits {
+        self.config
+            .overrides
+            .get(tenant.subject())
+            .unwrap_or(&self.config.default)
+    }
+
+    /// Returns true if the size of the tenant's volumes is limited
+    pub fn has_storage_quota(&self, tenant: &Tenant) -> bool {
+        self.limits(tenant).max_storage.is_some()
+    }
+
+    /// Returns true if the tenant's storage usage must be computed and passed
+    /// to `set_storage` before its next write is admitted
+    pub fn needs_storage(&self, tenant: &Tenant) -> bool {
+        self.needs_storage_at(tenant, Instant::now())
+    }
+
+    fn needs_storage_at(&self, tenant: &Tenant, now: Instant) -> bool {
+        if !self.has_storage_quota(tenant) {
+            return false;
+        }
+        let tenants = self.tenants.lock().expect("tenant limiter mutex poisoned");
+        tenants
+            .states
+            .get(tenant)
+            .and_then(|state| state.storage.as_ref())
+            .is_none_or(|storage| {
+                now.saturating_duration_since(storage.computed) >= self.storage_cache
+            })
+    }
+
+    /// Cache the size of each volume written by the tenant
+    pub fn set_storage(&self, tenant: &Tenant, volumes: HashMap<VolumeId, ByteUnit>) {
+        self.set_storage_at(tenant, volumes, Instant::now())
+    }
+
+    fn set_storage_at(&self, tenant: &Tenant, volumes: HashMap<VolumeId, ByteUnit>, now: Instant) {
+        let mut tenants = self.tenants.lock().expect("tenant limiter mutex poisoned");
+        let state = tenants
+            .states
+            .entry(tenant.clone())
+            .or_insert_with(|| TenantState::new(now));
+        state.active = now;
+        state.storage = Some(StorageUsage { computed: now, volumes });
+    }
+
+    /// Admit a write, or return an error if the tenant has exceeded one of
+    /// its limits
+    pub fn admit(&self, tenant: &Tenant, write: &TenantWrite<'_>) -> Result<(), Culprit<LimitErr>> {
+        self.admit_at(tenant, write, true, Instant::now())
+    }
+
+    /// Charge a write against the tenant's limits without rejecting it. Used
+    /// for the remainder of a write which has already been admitted, so that
+    /// a write isn't rejected after some of its pages have been written.
+    pub fn charge(&self, tenant: &Tenant, write: &TenantWrite<'_>) {
+        self.admit_at(tenant, write, false, Instant::now())
+            .expect("charging a write never fails")
+    }
+
+    fn admit_at(
+        &self,
+        tenant: &Tenant,
+        write: &TenantWrite<'_>,
+        enforce: bool,
+        now: Instant,
+    ) -> Result<(), Culprit<LimitErr>> {
+        let limits = self.limits(tenant);
+        if limits.is_unlimited() {
+            return Ok(());
+        }
+
+        let mut tenants = self.tenants.lock().expect("tenant limiter mutex poisoned");
+        tenants.sweep(now);
+        let state = tenants
+            .states
+            .entry(tenant.clone())
+            .or_insert_with(|| TenantState::new(now));
+        state.active = now;
+
+        if enforce {
+            if let Some(max) = limits.max_storage {
+                let storage = state.storage_after(write);
+                if storage > max {
+                    return Err(Culprit::new_with_note(
+                        LimitErr::Storage,
+                        format!(
+                            "writing to volume {} grows tenant {} to {storage}, exceeding the quota of {max}",
+                            write.vid,
+                            tenant.subject()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let pages_bucket = limits.pages_per_sec.map(|rate| {
+            state
+                .pages
+                .get_or_insert_with(|| TokenBucket::new(rate as f64, rate as f64, now))
+        });
+        let bytes_bucket = limits.bytes_per_day.map(|quota| {
+            let quota = quota.as_u64() as f64;
+            state
+                .bytes
+                .get_or_insert_with(|| TokenBucket::new(quota / SECONDS_PER_DAY, quota, now))
+        });
+
+        // check every limit before taking tokens from any of them, so a
+        // rejected write doesn't count against the tenant
+        let mut buckets = [
+            (pages_bucket, LimitErr::PageRate, write.pages as f64),
+            (
+                bytes_bucket,
+                LimitErr::DailyBytes,
+                write.bytes.as_u64() as f64,
+            ),
+        ];
+        for (bucket, err, _) in &mut buckets {
+            let Some(bucket) = bucket else { continue };
+            bucket.refill(now);
+            if let Some(wait) = bucket.wait_time().filter(|_| enforce) {
+                return Err(Culprit::new_with_note(
+                    *err,
+                    format!(
+                        "tenant {} may retry in {}ms",
+                        tenant.subject(),
+                        wait.as_millis().max(1)
+                    ),
+                ));
+            }
+        }
+        for (bucket, _, tokens) in buckets {
+            if let Some(bucket) = bucket {
+                bucket.take(tokens);
+            }
+        }
+
+        // writes which haven't been committed yet aren't visible in the
+        // snapshots usage is computed from, so they are added to the cached
+        // usage until it's recomputed
+        if let Some(storage) = &mut state.storage {
+            let size = storage
+                .volumes
+                .entry(write.vid.clone())
+                .or_insert(ByteUnit::ZERO);
+            *size = (*size).max(write.volume_size);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(vid: &VolumeId, pages: usize, bytes: ByteUnit) -> TenantWrite<'_> {
+        TenantWrite {
+            vid,
+            pages,
+            bytes,
+            volume_size: ByteUnit::ZERO,
+        }
+    }
+
+    #[graft_test::test]
+    fn test_tenant_limits() {
+        let limited = Tenant::new("limited");
+        let unlimited = Tenant::new("unlimited");
+        let limiter = TenantLimiter::new(TenantLimitsConfig {
+            default: TenantLimits {
+                pages_per_sec: Some(100),
+                bytes_per_day: Some(ByteUnit::from_mb(1)),
+                max_storage: None,
+            },
+            overrides: HashMap::from([(unlimited.subject().to_string(), TenantLimits::default())]),
+            ..Default::default()
+        });
+        let vid = VolumeId::random();
+        let page = ByteUnit::from_kb(4);
+        let now = Instant::now();
+
+        // a burst of a second of writes is admitted, after which the tenant
+        // must wait for the bucket to refill
+        limiter
+            .admit_at(&limited, &write(&vid, 100, page), true, now)
+            .unwrap();
+        let err = limiter
+            .admit_at(&limited, &write(&vid, 1, page), true, now)
+            .unwrap_err();
+        assert!(matches!(err.ctx(), LimitErr::PageRate));
+        limiter
+            .admit_at(
+                &limited,
+                &write(&vid, 1, page),
+                true,
+                now + Duration::from_millis(20),
+            )
+            .unwrap();
+
+        // large writes may go into debt, delaying subsequent writes
+        let now = now + Duration::from_secs(1);
+        limiter
+            .admit_at(&limited, &write(&vid, 300, page), true, now)
+            .unwrap();
+        let now = now + Duration::from_secs(1);
+        let err = limiter
+            .admit_at(&limited, &write(&vid, 1, page), true, now)
+            .unwrap_err();
+        assert!(matches!(err.ctx(), LimitErr::PageRate));
+
+        // the rest of an admitted write is charged rather than rejected,
+        // delaying subsequent writes further
+        limiter
+            .admit_at(&limited, &write(&vid, 100, page), false, now)
+            .unwrap();
+        let err = limiter
+            .admit_at(
+                &limited,
+                &write(&vid, 1, page),
+                true,
+                now + Duration::from_secs(1),
+            )
+            .unwrap_err();
+        assert!(matches!(err.ctx(), LimitErr::PageRate));
+
+        // the daily byte quota is enforced independently of the page rate
+        let now = now + Duration::from_secs(10);
+        limiter
+            .admit_at(&limited, &write(&vid, 1, ByteUnit::from_mb(2)), true, now)
+            .unwrap();
+        let now = now + Duration::from_secs(10);
+        let err = limiter
+            .admit_at(&limited, &write(&vid, 1, page), true, now)
+            .unwrap_err();
+        assert!(matches!(err.ctx(), LimitErr::DailyBytes));
+
+        // idle tenants are forgotten, along with any debt
+        let now = now + TENANT_IDLE_TIMEOUT;
+        limiter
+            .admit_at(&limited, &write(&vid, 1, page), true, now)
+            .unwrap();
+
+        // overrides replace the default limits
+        limiter
+            .admit_at(
+                &unlimited,
+                &write(&vid, 1_000_000, ByteUnit::from_gb(10)),
+                true,
+                now,
+            )
+            .unwrap();
+        let tenants = limiter.tenants.lock().unwrap();
+        assert!(!tenants.states.contains_key(&unlimited));
+    }
+
+    #[graft_test::test]
+    fn test_tenant_storage_quota() {
+        let tenant = Tenant::new("tenant");
+        let limiter = TenantLimiter::new(TenantLimitsConfig {
+            default: TenantLimits {
+                max_storage: Some(ByteUnit::from_kb(64)),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let now = Instant::now();
+        let sized = |vid, kb| TenantWrite {
+            volume_size: ByteUnit::from_kb(kb),
+            ..write(vid, 1, ByteUnit::from_kb(4))
+        };
+
+        // usage must be computed before the tenant's first write
+        let (vid1, vid2) = (VolumeId::random(), VolumeId::random());
+        assert!(limiter.needs_storage_at(&tenant, now));
+        limiter.set_storage_at(
+            &tenant,
+            HashMap::from([
+                (vid1.clone(), ByteUnit::from_kb(32)),
+                (vid2.clone(), ByteUnit::from_kb(32)),
+            ]),
+            now,
+        );
+        assert!(!limiter.needs_storage_at(&tenant, now));
+
+        // the quota applies to the combined size of the tenant's volumes
+        let err = limiter
+            .admit_at(&tenant, &sized(&vid2, 36), true, now)
+            .unwrap_err();
+        assert!(matches!(err.ctx(), LimitErr::Storage));
+
+        // writes which don't grow a volume are admitted
+        limiter
+            .admit_at(&tenant, &sized(&vid1, 4), true, now)
+            .unwrap();
+
+        // growth is charged once a write has been admitted, after which
+        // writes to new volumes are rejected
+        limiter
+            .admit_at(&tenant, &sized(&vid2, 40), false, now)
+            .unwrap();
+        let vid3 = VolumeId::random();
+        let err = limiter
+            .admit_at(&tenant, &sized(&vid3, 4), true, now)
+            .unwrap_err();
+        assert!(matches!(err.ctx(), LimitErr::Storage));
+
+        // once the cache expires usage is recomputed, so truncating the first
+        // volume and deleting the second frees quota
+        let now = now + DEFAULT_STORAGE_CACHE;
+        assert!(limiter.needs_storage_at(&tenant, now));
+        limiter.set_storage_at(
+            &tenant,
+            HashMap::from([(vid1.clone(), ByteUnit::from_kb(16))]),
+            now,
+        );
+        limiter
+            .admit_at(&tenant, &sized(&vid3, 48), true, now)
+            .unwrap();
+
+        // other tenants have their own quota
+        let other = Tenant::new("other");
+        limiter.set_storage_at(&other, HashMap::new(), now);
+        limiter
+            .admit_at(&other, &sized(&vid1, 64), true, now)
+            .unwrap();
+    }
+}
@@ -3,6 +3,7 @@ use std::{
     io,
     ops::RangeBounds,
     path::{Path, PathBuf},
+    time::{Duration, SystemTime},
 };
 
 use bytes::{Buf, Bytes};
@@ -11,6 +12,7 @@ use fjall::{
     Batch, Config, Keyspace, KvSeparationOptions, Partition, PartitionCreateOptions, Slice,
 };
 use graft_core::{
+    SegmentId,
     gid::VolumeId,
     lsn::LSN,
     zerocopy_ext::{TryFromBytesExt, ZerocopyErr},
@@ -19,10 +21,10 @@ use graft_proto::common::v1::SegmentInfo;
 use serde::{Deserialize, Serialize};
 use splinter_rs::SplinterRef;
 use tryiter::TryIteratorExt;
-use zerocopy::{ConvertError, IntoBytes, SizeError, TryFromBytes};
+use zerocopy::{BE, ConvertError, IntoBytes, SizeError, TryFromBytes, U64};
 
 use super::{
-    commit::{Commit, CommitMeta, GraftValidationErr},
+    commit::{Commit, CommitMeta, GraftKind, GraftValidationErr},
     kv::{CommitKey, SegmentKey},
 };
 
@@ -106,6 +108,23 @@ pub struct VolumeCatalog {
 
     /// maps `kv::SegmentKey` to Graft
     segments: Partition,
+
+    /// maps `SegmentId` to the time in milliseconds since the unix epoch at
+    /// which the segment was first observed to be unreferenced
+    gc_marks: Partition,
+
+    /// maps a tenant's subject followed by a `VolumeId` to nothing, recording
+    /// the volumes written by each tenant
+    tenant_volumes: Partition,
+}
+
+/// The key prefix of the volumes written by a tenant. Subjects are length
+/// prefixed so that one subject's volumes never match another's prefix.
+fn tenant_prefix(subject: &str) -> Vec<u8> {
+    let mut key = Vec::with_capacity(4 + subject.len() + size_of::<VolumeId>());
+    key.extend_from_slice(&(subject.len() as u32).to_be_bytes());
+    key.extend_from_slice(subject.as_bytes());
+    key
 }
 
 impl VolumeCatalog {
@@ -128,7 +147,18 @@ impl VolumeCatalog {
             PartitionCreateOptions::default().with_kv_separation(KvSeparationOptions::default()),
         )?;
 
-        Ok(Self { keyspace, volumes, segments })
+        let gc_marks = keyspace.open_partition("gc_marks", PartitionCreateOptions::default())?;
+
+        let tenant_volumes =
+            keyspace.open_partition("tenant_volumes", PartitionCreateOptions::default())?;
+
+        Ok(Self {
+            keyspace,
+            volumes,
+            segments,
+            gc_marks,
+            tenant_volumes,
+        })
     }
 
     pub fn batch_insert(&self) -> VolumeCatalogBatch {
@@ -180,8 +210,7 @@ impl VolumeCatalog {
     ) -> Result<Option<CommitMeta>, Culprit<VolumeCatalogErr>> {
         if let Some(bytes) = self.volumes.get(CommitKey::new(vid, lsn))? {
             Ok(Some(
-                CommitMeta::try_read_from_bytes(&bytes)
-                    .or_into_culprit("failed to decode CommitMeta")?,
+                CommitMeta::decode(&bytes).or_into_culprit("failed to decode CommitMeta")?,
             ))
         } else {
             Ok(None)
@@ -200,26 +229,101 @@ impl VolumeCatalog {
             .rev()
             .err_into()
             .map_ok(|(_, bytes)| {
-                CommitMeta::try_read_from_bytes(&bytes)
-                    .or_into_culprit("failed to decode CommitMeta")
+                CommitMeta::decode(&bytes).or_into_culprit("failed to decode CommitMeta")
             })
             .try_next()
     }
 
-    /// scan the catalog for segments in the specified Volume. Segments are
-    /// scanned in reverse order by LSN.
+    /// Return the latest snapshot for the specified Volume which was committed
+    /// at or before the provided time.
+    ///
+    /// Rewrites are skipped as they are timestamped when the compaction or
+    /// checkpoint was created, rather than when their contents were committed.
+    pub fn snapshot_at_time(
+        &self,
+        vid: &VolumeId,
+        time: SystemTime,
+    ) -> Result<Option<CommitMeta>, Culprit<VolumeCatalogErr>> {
+        let mut scan = self
+            .volumes
+            .snapshot()
+            .prefix(vid)
+            .rev()
+            .err_into()
+            .map_ok(|(_, bytes)| {
+                CommitMeta::decode(&bytes).or_into_culprit("failed to decode CommitMeta")
+            });
+        while let Some(meta) = scan.try_next()? {
+            if !meta.is_rewrite() && meta.system_time() <= time {
+                return Ok(Some(meta));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the most recent rewrite commit in the specified range of LSNs
+    pub fn latest_rewrite<R: RangeBounds<LSN>>(
+        &self,
+        vid: &VolumeId,
+        lsns: &R,
+    ) -> Result<Option<CommitMeta>, Culprit<VolumeCatalogErr>> {
+        let mut scan = self
+            .volumes
+            .snapshot()
+            .range(CommitKey::range(vid, lsns))
+            .rev()
+            .err_into()
+            .map_ok(|(_, bytes)| {
+                CommitMeta::decode(&bytes).or_into_culprit("failed to decode CommitMeta")
+            });
+        while let Some(meta) = scan.try_next()? {
+            if meta.is_rewrite() {
+                return Ok(Some(meta));
+            }
+        }
+        Ok(None)
+    }
+
+    /// scan the catalog for segments containing pages visible in the specified
+    /// Volume. Segments are scanned in reverse order by LSN.
     pub fn scan_segments<R: RangeBounds<LSN>>(
         &self,
         vid: &VolumeId,
         lsns: &R,
     ) -> impl Iterator<Item = Result<(SegmentKey, SplinterRef<Bytes>), Culprit<VolumeCatalogErr>>> + use<R>
+    {
+        self.scan_segments_of_kind(vid, lsns, GraftKind::Pages)
+    }
+
+    /// scan the catalog for segments containing the base pages of deltas which
+    /// the specified Volume inherited from the volume it was forked from.
+    /// Segments are scanned in reverse order by LSN.
+    pub fn scan_delta_bases<R: RangeBounds<LSN>>(
+        &self,
+        vid: &VolumeId,
+        lsns: &R,
+    ) -> impl Iterator<Item = Result<(SegmentKey, SplinterRef<Bytes>), Culprit<VolumeCatalogErr>>> + use<R>
+    {
+        self.scan_segments_of_kind(vid, lsns, GraftKind::DeltaBase)
+    }
+
+    fn scan_segments_of_kind<R: RangeBounds<LSN>>(
+        &self,
+        vid: &VolumeId,
+        lsns: &R,
+        kind: GraftKind,
+    ) -> impl Iterator<Item = Result<(SegmentKey, SplinterRef<Bytes>), Culprit<VolumeCatalogErr>>> + use<R>
     {
         let range = CommitKey::range(vid, lsns);
         let scan = self.segments.snapshot().range(range).rev();
-        SegmentsIter { scan }
+        SegmentsIter { scan }.filter(move |kv| match kv {
+            Ok((key, _)) => key.kind() == kind,
+            Err(_) => true,
+        })
     }
 
-    /// scan the catalog for commits in the specified Volume in order by lsn
+    /// scan the catalog for commits in the specified Volume in order by lsn,
+    /// along with all of the segments they reference
     #[allow(clippy::type_complexity)]
     pub fn scan_volume<R: RangeBounds<LSN>>(
         &self,
@@ -245,8 +349,8 @@ impl VolumeCatalog {
             .map_ok(move |(key, meta)| {
                 let key = CommitKey::try_read_from_bytes(&key)
                     .or_into_culprit("failed to decode CommitKey")?;
-                let meta = CommitMeta::try_read_from_bytes(&meta)
-                    .or_into_culprit("failed to decode CommitMeta")?;
+                let meta =
+                    CommitMeta::decode(&meta).or_into_culprit("failed to decode CommitMeta")?;
 
                 // scan segments for this commit
                 let segments = self.segments.snapshot_at(seqno).prefix(key);
@@ -255,6 +359,93 @@ impl VolumeCatalog {
                 Ok((meta, segments))
             })
     }
+
+    /// Returns the time at which the segment was marked for garbage collection.
+    pub fn gc_mark(
+        &self,
+        sid: &SegmentId,
+    ) -> Result<Option<SystemTime>, Culprit<VolumeCatalogErr>> {
+        if let Some(bytes) = self.gc_marks.get(sid)? {
+            let millis = U64::<BE>::try_read_from_bytes(&bytes)
+                .or_into_culprit("failed to decode gc mark")?;
+            Ok(Some(
+                SystemTime::UNIX_EPOCH + Duration::from_millis(millis.get()),
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Mark a segment for garbage collection at the specified time.
+    pub fn set_gc_mark(
+        &self,
+        sid: &SegmentId,
+        time: SystemTime,
+    ) -> Result<(), Culprit<VolumeCatalogErr>> {
+        let millis = time
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        self.gc_marks
+            .insert(sid.as_bytes(), U64::<BE>::new(millis).as_bytes())?;
+        Ok(())
+    }
+
+    pub fn clear_gc_mark(&self, sid: &SegmentId) -> Result<(), Culprit<VolumeCatalogErr>> {
+        self.gc_marks.remove(sid.as_bytes())?;
+        Ok(())
+    }
+
+    /// scan all segments which are currently marked for garbage collection
+    pub fn scan_gc_marks(
+        &self,
+    ) -> impl Iterator<Item = Result<SegmentId, Culprit<VolumeCatalogErr>>> + use<> {
+        self.gc_marks.snapshot().keys().map(|key| {
+            let key = key?;
+            SegmentId::try_read_from_bytes(&key).or_into_culprit("failed to decode SegmentId")
+        })
+    }
+
+    /// Record that a tenant has written to a volume
+    pub fn add_tenant_volume(
+        &self,
+        subject: &str,
+        vid: &VolumeId,
+    ) -> Result<(), Culprit<VolumeCatalogErr>> {
+        let mut key = tenant_prefix(subject);
+        key.extend_from_slice(vid.as_bytes());
+        self.tenant_volumes.insert(key, [])?;
+        Ok(())
+    }
+
+    /// Forget that a tenant has written to a volume
+    pub fn remove_tenant_volume(
+        &self,
+        subject: &str,
+        vid: &VolumeId,
+    ) -> Result<(), Culprit<VolumeCatalogErr>> {
+        let mut key = tenant_prefix(subject);
+        key.extend_from_slice(vid.as_bytes());
+        self.tenant_volumes.remove(key)?;
+        Ok(())
+    }
+
+    /// scan the volumes written by a tenant
+    pub fn scan_tenant_volumes(
+        &self,
+        subject: &str,
+    ) -> impl Iterator<Item = Result<VolumeId, Culprit<VolumeCatalogErr>>> + use<> {
+        let prefix = tenant_prefix(subject);
+        let prefix_len = prefix.len();
+        self.tenant_volumes
+            .snapshot()
+            .prefix(prefix)
+            .map(move |kv| {
+                let (key, _) = kv?;
+                VolumeId::try_read_from_bytes(&key[prefix_len..])
+                    .or_into_culprit("failed to decode VolumeId")
+            })
+    }
 }
 
 pub struct VolumeCatalogBatch {
@@ -277,8 +468,8 @@ impl VolumeCatalogBatch {
         );
 
         let mut iter = commit.iter_grafts();
-        while let Some((sid, graft)) = iter.try_next().or_into_ctx()? {
-            let key = SegmentKey::new(commit_key.clone(), sid);
+        while let Some((sid, source_vid, graft, kind)) = iter.try_next().or_into_ctx()? {
+            let key = SegmentKey::new(commit_key.clone(), sid, source_vid).with_kind(kind);
             self.batch.insert(&self.segments, key, graft.into_inner());
         }
 
@@ -291,17 +482,43 @@ impl VolumeCatalogBatch {
         snapshot: CommitMeta,
         segments: Vec<SegmentInfo>,
     ) -> Result<(), Culprit<VolumeCatalogErr>> {
-        let commit_key = CommitKey::new(vid, snapshot.lsn());
+        let commit_key = CommitKey::new(vid.clone(), snapshot.lsn());
 
         self.batch
             .insert(&self.volumes, commit_key.as_bytes(), snapshot);
         for segment in segments {
-            let key = SegmentKey::new(commit_key.clone(), segment.sid.try_into()?);
+            let source_vid = segment.source_vid().or_into_ctx()?.unwrap_or(&vid).clone();
+            let kind = if segment.delta_base {
+                GraftKind::DeltaBase
+            } else {
+                GraftKind::Pages
+            };
+            let key = SegmentKey::new(commit_key.clone(), segment.sid.try_into()?, source_vid)
+                .with_kind(kind);
             self.batch.insert(&self.segments, key, segment.graft);
         }
         Ok(())
     }
 
+    /// Remove all commits in the specified LSN range along with the segments
+    /// they reference.
+    pub fn remove_commits<R: RangeBounds<LSN>>(
+        &mut self,
+        vid: &VolumeId,
+        lsns: &R,
+    ) -> Result<(), Culprit<VolumeCatalogErr>> {
+        let range = CommitKey::range(vid, lsns);
+        for kv in self.volumes.range(range.clone()) {
+            let (key, _) = kv?;
+            self.batch.remove(&self.volumes, key);
+        }
+        for kv in self.segments.range(range) {
+            let (key, _) = kv?;
+            self.batch.remove(&self.segments, key);
+        }
+        Ok(())
+    }
+
     pub fn commit(self) -> Result<(), Culprit<VolumeCatalogErr>> {
         self.batch.commit()?;
         Ok(())
@@ -318,8 +535,7 @@ impl<I: Iterator<Item = Result<(Slice, Slice), lsm_tree::Error>>> SegmentsIter<I
         entry: Result<(Slice, Slice), lsm_tree::Error>,
     ) -> Result<(SegmentKey, SplinterRef<Bytes>), Culprit<VolumeCatalogErr>> {
         let (key, value) = entry?;
-        let key =
-            SegmentKey::try_read_from_bytes(&key).or_into_culprit("failed to decode SegmentKey")?;
+        let key = SegmentKey::decode(&key).or_into_culprit("failed to decode SegmentKey")?;
         let val = SplinterRef::from_bytes(Bytes::from(value)).or_into_ctx()?;
         Ok((key, val))
     }
@@ -1,6 +1,5 @@
 use std::{
     fmt::Debug,
-    iter::once,
     num::ParseIntError,
     time::{Duration, SystemTime},
 };
@@ -9,9 +8,11 @@ use bytes::{Buf, Bytes};
 use culprit::{Culprit, ResultExt};
 use fjall::Slice;
 use graft_core::{
-    SegmentId, VolumeId,
+    PageSize, SegmentId, VolumeId,
+    commit_token::CommitToken,
     gid::{ClientId, GidParseErr},
     lsn::{InvalidLSN, LSN},
+    page::PageSizeErr,
     page_count::PageCount,
     zerocopy_ext::ZerocopyErr,
 };
@@ -20,12 +21,19 @@ use object_store::{PutPayload, path::Path};
 use prost_types::TimestampError;
 use splinter_rs::SplinterRef;
 use thiserror::Error;
-use zerocopy::{ConvertError, Immutable, IntoBytes, KnownLayout, TryFromBytes};
+use zerocopy::{
+    ConvertError, FromBytes, Immutable, IntoBytes, KnownLayout, TryFromBytes, Unaligned,
+    little_endian::U32,
+};
 
 use crate::bytes_vec::BytesVec;
 
+pub fn volumes_path_prefix() -> Path {
+    Path::from("volumes")
+}
+
 pub fn commit_key_path_prefix(vid: &VolumeId) -> Path {
-    Path::parse(format!("volumes/{}", vid.pretty())).expect("invalid object_store path")
+    volumes_path_prefix().child(vid.pretty())
 }
 
 pub fn commit_key_path(vid: &VolumeId, lsn: LSN) -> Path {
@@ -97,6 +105,46 @@ impl Debug for CommitMagic {
     }
 }
 
+/// The version of the serialized commit format, stored in the `CommitPrefix`
+/// of every commit. Changes to the format must bump the version and keep a
+/// read path for commits written with earlier versions.
+pub const COMMIT_VERSION: u8 = 1;
+
+/// The magic number which starts every `CommitPrefix`. Commits written before
+/// the commit format was versioned start with a `LegacyCommitMeta` instead,
+/// which is identified by `CommitMagic::Magic`.
+const COMMIT_PREFIX_MAGIC: U32 = U32::new(0x71DB116C);
+
+/// Every serialized commit starts with a prefix recording the version of the
+/// commit format along with a checksum of the rest of the commit.
+#[derive(Clone, IntoBytes, FromBytes, Immutable, KnownLayout)]
+#[repr(C)]
+struct CommitPrefix {
+    magic: U32,
+    version: u8,
+    _padding: [u8; 3],
+    /// blake3 hash of the commit header and grafts
+    checksum: [u8; 32],
+}
+
+static_assertions::assert_eq_size!(CommitPrefix, [u8; 40]);
+
+#[derive(Clone, Copy, IntoBytes, TryFromBytes, Immutable, KnownLayout, Debug, PartialEq, Eq)]
+#[repr(u8)]
+enum CommitKind {
+    Normal = 0,
+    /// Marks the volume as deleted. A tombstone is an empty checkpoint, so
+    /// none of the volume's segments remain referenced.
+    Tombstone = 1,
+    /// Part of a multi-volume transaction. The commit is only visible once
+    /// the transaction identified by the commit's token has committed.
+    Transaction = 2,
+    /// Written by compaction or checkpointing. A rewrite stores the latest
+    /// version of some of the volume's pages in new segments, so it never
+    /// changes the contents of the volume.
+    Rewrite = 3,
+}
+
 #[derive(Clone, IntoBytes, TryFromBytes, Immutable, KnownLayout, Debug)]
 #[repr(C)]
 pub struct CommitMeta {
@@ -107,9 +155,53 @@ pub struct CommitMeta {
     lsn: LSN,
     checkpoint_lsn: LSN,
     timestamp: u64,
+    token: CommitToken,
+    page_size: PageSize,
+    kind: CommitKind,
+    _padding: [u8; 2],
+    /// the volume's page count before this commit; only set on transaction
+    /// commits, which restore it if the transaction aborts
+    prev_page_count: PageCount,
 }
 
-static_assertions::const_assert_eq!(size_of::<CommitMeta>(), 64);
+static_assertions::const_assert_eq!(size_of::<CommitMeta>(), 88);
+
+/// The header of commits written before the commit format was versioned, which
+/// is also the layout of snapshots stored in catalogs created by earlier
+/// releases. Legacy commits have no checksum, and their pages are stored with
+/// the default page size.
+#[derive(Clone, IntoBytes, TryFromBytes, Immutable, KnownLayout)]
+#[repr(C)]
+struct LegacyCommitMeta {
+    magic: CommitMagic,
+    page_count: PageCount,
+    vid: VolumeId,
+    cid: ClientId,
+    lsn: LSN,
+    checkpoint_lsn: LSN,
+    timestamp: u64,
+}
+
+static_assertions::const_assert_eq!(size_of::<LegacyCommitMeta>(), 64);
+
+impl From<LegacyCommitMeta> for CommitMeta {
+    fn from(legacy: LegacyCommitMeta) -> Self {
+        Self {
+            magic: legacy.magic,
+            page_count: legacy.page_count,
+            vid: legacy.vid,
+            cid: legacy.cid,
+            lsn: legacy.lsn,
+            checkpoint_lsn: legacy.checkpoint_lsn,
+            timestamp: legacy.timestamp,
+            token: CommitToken::EMPTY,
+            page_size: PageSize::DEFAULT,
+            kind: CommitKind::Normal,
+            _padding: [0; 2],
+            prev_page_count: PageCount::ZERO,
+        }
+    }
+}
 
 impl CommitMeta {
     pub fn new(
@@ -118,6 +210,7 @@ impl CommitMeta {
         lsn: LSN,
         checkpoint: LSN,
         page_count: PageCount,
+        page_size: PageSize,
         timestamp: SystemTime,
     ) -> Self {
         assert!(
@@ -132,6 +225,83 @@ impl CommitMeta {
             checkpoint_lsn: checkpoint,
             page_count,
             timestamp: time_to_millis(timestamp),
+            token: CommitToken::EMPTY,
+            page_size,
+            kind: CommitKind::Normal,
+            _padding: [0; 2],
+            prev_page_count: PageCount::ZERO,
+        }
+    }
+
+    /// Decode a `CommitMeta` stored by a `VolumeCatalog`. Catalogs may still
+    /// contain snapshots stored using the legacy layout, which is identified
+    /// by its size. Any change to the layout must change its size and add a
+    /// case here.
+    pub fn decode(data: &[u8]) -> Result<Self, ZerocopyErr> {
+        if data.len() == size_of::<LegacyCommitMeta>() {
+            Ok(LegacyCommitMeta::try_read_from_bytes(data)?.into())
+        } else {
+            Ok(Self::try_read_from_bytes(data)?)
+        }
+    }
+
+    /// Create a tombstone which deletes the volume at the provided LSN
+    pub fn tombstone(
+        vid: VolumeId,
+        cid: ClientId,
+        lsn: LSN,
+        page_size: PageSize,
+        timestamp: SystemTime,
+    ) -> Self {
+        Self {
+            kind: CommitKind::Tombstone,
+            ..Self::new(vid, cid, lsn, lsn, PageCount::ZERO, page_size, timestamp)
+        }
+    }
+
+    /// Create a rewrite which follows the provided commit at the next LSN. The
+    /// rewrite inherits the page count, page size and client of the previous
+    /// commit. A rewrite which creates a checkpoint sets `checkpoint` to the
+    /// rewrite's own LSN.
+    pub fn rewrite(prev: &CommitMeta, lsn: LSN, checkpoint: LSN, timestamp: SystemTime) -> Self {
+        Self {
+            kind: CommitKind::Rewrite,
+            ..Self::new(
+                prev.vid.clone(),
+                prev.cid.clone(),
+                lsn,
+                checkpoint,
+                prev.page_count,
+                prev.page_size,
+                timestamp,
+            )
+        }
+    }
+
+    /// Record the token the client sent along with this commit
+    pub fn with_token(self, token: CommitToken) -> Self {
+        Self { token, ..self }
+    }
+
+    /// Mark this commit as part of the multi-volume transaction identified by
+    /// the commit's token. `prev_page_count` is the volume's page count before
+    /// this commit.
+    pub fn with_transaction(self, prev_page_count: PageCount) -> Self {
+        Self {
+            kind: CommitKind::Transaction,
+            prev_page_count,
+            ..self
+        }
+    }
+
+    /// Returns the metadata of this commit after its transaction aborted. The
+    /// aborted commit keeps its LSN but leaves the volume unchanged.
+    pub fn rollback(&self) -> Self {
+        Self {
+            page_count: self.prev_page_count,
+            kind: CommitKind::Normal,
+            prev_page_count: PageCount::ZERO,
+            ..self.clone()
         }
     }
 
@@ -160,11 +330,38 @@ impl CommitMeta {
         self.page_count
     }
 
+    #[inline]
+    pub fn page_size(&self) -> PageSize {
+        self.page_size
+    }
+
     #[inline]
     pub fn timestamp(&self) -> u64 {
         self.timestamp
     }
 
+    /// The token the client sent along with this commit, or
+    /// `CommitToken::EMPTY` if the client didn't send one.
+    #[inline]
+    pub fn token(&self) -> CommitToken {
+        self.token
+    }
+
+    #[inline]
+    pub fn is_tombstone(&self) -> bool {
+        self.kind == CommitKind::Tombstone
+    }
+
+    #[inline]
+    pub fn is_transaction(&self) -> bool {
+        self.kind == CommitKind::Transaction
+    }
+
+    #[inline]
+    pub fn is_rewrite(&self) -> bool {
+        self.kind == CommitKind::Rewrite
+    }
+
     #[inline]
     pub fn system_time(&self) -> SystemTime {
         millis_to_time(self.timestamp())
@@ -177,8 +374,10 @@ impl CommitMeta {
             self.lsn,
             self.checkpoint_lsn,
             self.page_count,
+            self.page_size,
             self.system_time(),
         )
+        .with_rewrite(self.is_rewrite())
     }
 }
 
@@ -204,6 +403,9 @@ pub enum TryFromSnapshotErr {
 
     #[error("invalid timestamp: {0}")]
     InvalidTimestamp(#[from] TimestampError),
+
+    #[error("invalid page size: {0}")]
+    InvalidPageSize(#[from] PageSizeErr),
 }
 
 impl TryFrom<Snapshot> for CommitMeta {
@@ -211,20 +413,67 @@ impl TryFrom<Snapshot> for CommitMeta {
 
     fn try_from(snapshot: Snapshot) -> Result<Self, Self::Error> {
         let ts = snapshot.system_time()?.unwrap_or(SystemTime::UNIX_EPOCH);
-        Ok(Self::new(
+        let meta = Self::new(
             snapshot.vid().cloned().or_into_ctx()?,
             snapshot.cid().cloned().or_into_ctx()?,
             snapshot.lsn().or_into_ctx()?,
             snapshot.checkpoint().or_into_ctx()?,
             snapshot.pages(),
+            snapshot.page_size().or_into_ctx()?,
             ts,
-        ))
+        );
+        // rewrites are the only kind of commit which catalogs populated from
+        // snapshots need to distinguish
+        if snapshot.is_rewrite() {
+            Ok(Self { kind: CommitKind::Rewrite, ..meta })
+        } else {
+            Ok(meta)
+        }
     }
 }
 
+/// Distinguishes grafts of pages which are visible in the volume from grafts
+/// which only hold the base pages of inherited page deltas
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    IntoBytes,
+    TryFromBytes,
+    Immutable,
+    KnownLayout,
+    Unaligned,
+    Debug,
+    PartialEq,
+    Eq,
+)]
+#[repr(u8)]
+pub enum GraftKind {
+    #[default]
+    Pages = 0,
+    /// Holds the base pages of deltas which a fork inherited from its source
+    /// volume. Delta bases are never visible in the volume, they only keep the
+    /// base pages resolvable and referenced by the fork. See
+    /// `segment::delta::resolve_deltas`.
+    DeltaBase = 1,
+}
+
 #[derive(Clone, IntoBytes, TryFromBytes, Immutable, KnownLayout)]
 #[repr(C)]
 pub struct GraftHeader {
+    sid: SegmentId,
+    /// the volume which the graft's pages are stored under in the segment
+    source_vid: VolumeId,
+    size: u32,
+    kind: GraftKind,
+    _padding: [u8; 3],
+}
+
+/// The graft header used by legacy commits, whose grafts always reference
+/// pages stored under the commit's own volume
+#[derive(Clone, IntoBytes, TryFromBytes, Immutable, KnownLayout)]
+#[repr(C)]
+struct LegacyGraftHeader {
     sid: SegmentId,
     size: u32,
 }
@@ -243,28 +492,66 @@ impl CommitBuilder {
     }
 
     pub fn write_graft(&mut self, sid: SegmentId, graft: Bytes) {
+        let source_vid = self.meta.vid.clone();
+        self.write_graft_from(sid, source_vid, graft);
+    }
+
+    /// Write a graft whose pages are stored under a different volume in the
+    /// segment. Used when forking a volume from another volume.
+    pub fn write_graft_from(&mut self, sid: SegmentId, source_vid: VolumeId, graft: Bytes) {
+        self.write_graft_kind(sid, source_vid, GraftKind::Pages, graft);
+    }
+
+    /// Write a graft of the base pages of deltas inherited from the source
+    /// volume. Used when forking a volume from another volume.
+    pub fn write_delta_base(&mut self, sid: SegmentId, source_vid: VolumeId, graft: Bytes) {
+        self.write_graft_kind(sid, source_vid, GraftKind::DeltaBase, graft);
+    }
+
+    fn write_graft_kind(
+        &mut self,
+        sid: SegmentId,
+        source_vid: VolumeId,
+        kind: GraftKind,
+        graft: Bytes,
+    ) {
         let header = GraftHeader {
             sid,
+            source_vid,
             size: graft.len().try_into().expect("bug: splinter too large"),
+            kind,
+            _padding: [0; 3],
         };
         self.grafts.put_slice(header.as_bytes());
         self.grafts.put(graft);
     }
 
     pub fn build(self) -> Commit<BytesVec> {
-        Commit { header: self.meta, grafts: self.grafts }
+        Commit {
+            header: self.meta,
+            grafts: self.grafts,
+            version: COMMIT_VERSION,
+        }
     }
 }
 
 #[derive(Debug, Error)]
-#[error("corrupt commit header: {0}")]
-pub struct CommitValidationErr(ZerocopyErr);
+pub enum CommitValidationErr {
+    #[error("corrupt commit header: {0}")]
+    CorruptHeader(#[from] ZerocopyErr),
+
+    #[error("commit checksum mismatch")]
+    ChecksumMismatch,
+
+    #[error("unsupported commit version {0}")]
+    UnsupportedVersion(u8),
+}
 
 impl<A, S, V> From<ConvertError<A, S, V>> for CommitValidationErr {
     #[inline]
     #[track_caller]
     fn from(value: ConvertError<A, S, V>) -> Self {
-        Self(value.into())
+        Self::CorruptHeader(value.into())
     }
 }
 
@@ -272,6 +559,9 @@ impl<A, S, V> From<ConvertError<A, S, V>> for CommitValidationErr {
 pub struct Commit<T> {
     header: CommitMeta,
     grafts: T,
+    /// the version of the format the commit was serialized with; 0 for legacy
+    /// commits
+    version: u8,
 }
 
 impl<T> Commit<T> {
@@ -290,26 +580,121 @@ impl<T> Commit<T> {
     }
 }
 
+impl<T: Default> Commit<T> {
+    /// Returns this commit after its transaction aborted, which contains no
+    /// grafts. See `CommitMeta::rollback`.
+    pub fn rollback(&self) -> Self {
+        Self {
+            header: self.header.rollback(),
+            grafts: T::default(),
+            version: self.version,
+        }
+    }
+}
+
 impl<T: Buf + Clone> Commit<T> {
     pub fn from_bytes(mut data: T) -> Result<Self, Culprit<CommitValidationErr>> {
-        if data.remaining() < size_of::<CommitMeta>() {
-            return Err(Culprit::new(CommitValidationErr(ZerocopyErr::InvalidSize)));
+        if data.remaining() < size_of::<U32>() {
+            return Err(Culprit::new(CommitValidationErr::CorruptHeader(
+                ZerocopyErr::InvalidSize,
+            )));
+        }
+        if data.clone().get_u32_le() == CommitMagic::Magic as u32 {
+            return Self::from_legacy_bytes(data);
+        }
+
+        if data.remaining() < size_of::<CommitPrefix>() + size_of::<CommitMeta>() {
+            return Err(Culprit::new(CommitValidationErr::CorruptHeader(
+                ZerocopyErr::InvalidSize,
+            )));
         }
+        let prefix = data.copy_to_bytes(size_of::<CommitPrefix>());
+        let prefix = CommitPrefix::try_read_from_bytes(&prefix)?;
+        if prefix.magic != COMMIT_PREFIX_MAGIC {
+            return Err(Culprit::new_with_note(
+                CommitValidationErr::CorruptHeader(ZerocopyErr::InvalidData),
+                "invalid commit magic",
+            ));
+        }
+        if prefix.version != COMMIT_VERSION {
+            return Err(Culprit::new(CommitValidationErr::UnsupportedVersion(
+                prefix.version,
+            )));
+        }
+
+        // verify the checksum against the rest of the commit
+        let mut hasher = blake3::Hasher::new();
+        let mut body = data.clone();
+        while body.has_remaining() {
+            let chunk = body.chunk();
+            hasher.update(chunk);
+            body.advance(chunk.len());
+        }
+        if hasher.finalize() != blake3::Hash::from(prefix.checksum) {
+            return Err(Culprit::new(CommitValidationErr::ChecksumMismatch));
+        }
+
         let header = data.copy_to_bytes(size_of::<CommitMeta>());
         let header = CommitMeta::try_read_from_bytes(&header)?;
+        if !header.page_size.is_valid() {
+            return Err(Culprit::new_with_note(
+                CommitValidationErr::CorruptHeader(ZerocopyErr::InvalidData),
+                format!("invalid page size {:?}", header.page_size),
+            ));
+        }
+
+        Ok(Self {
+            header,
+            grafts: data,
+            version: prefix.version,
+        })
+    }
 
-        Ok(Self { header, grafts: data })
+    /// Read a commit written before the commit format was versioned
+    fn from_legacy_bytes(mut data: T) -> Result<Self, Culprit<CommitValidationErr>> {
+        if data.remaining() < size_of::<LegacyCommitMeta>() {
+            return Err(Culprit::new(CommitValidationErr::CorruptHeader(
+                ZerocopyErr::InvalidSize,
+            )));
+        }
+        let header = data.copy_to_bytes(size_of::<LegacyCommitMeta>());
+        let header = LegacyCommitMeta::try_read_from_bytes(&header)?;
+        Ok(Self {
+            header: header.into(),
+            grafts: data,
+            version: 0,
+        })
     }
 
     pub fn iter_grafts(&self) -> GraftIter<T> {
-        GraftIter { grafts: self.grafts.clone() }
+        GraftIter {
+            grafts: self.grafts.clone(),
+            version: self.version,
+            vid: self.header.vid.clone(),
+        }
     }
 }
 
 impl Commit<BytesVec> {
     pub fn into_payload(self) -> PutPayload {
+        assert_eq!(
+            self.version, COMMIT_VERSION,
+            "only commits in the current format can be serialized"
+        );
         let header = Bytes::copy_from_slice(self.header.as_bytes());
-        once(header).chain(self.grafts).collect()
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&header);
+        for graft in self.grafts.iter() {
+            hasher.update(graft);
+        }
+        let prefix = CommitPrefix {
+            magic: COMMIT_PREFIX_MAGIC,
+            version: COMMIT_VERSION,
+            _padding: [0; 3],
+            checksum: hasher.finalize().into(),
+        };
+        let prefix = Bytes::copy_from_slice(prefix.as_bytes());
+        [prefix, header].into_iter().chain(self.grafts).collect()
     }
 }
 
@@ -335,30 +720,51 @@ impl<A, S, V> From<ConvertError<A, S, V>> for GraftValidationErr {
 
 pub struct GraftIter<T> {
     grafts: T,
+    /// the version of the commit, which determines the graft header layout
+    version: u8,
+    /// the volume of the commit, which legacy grafts are stored under
+    vid: VolumeId,
 }
 
 impl<T: Buf> GraftIter<T> {
+    fn read_header<H: TryFromBytes + KnownLayout + Immutable>(
+        &mut self,
+    ) -> Result<H, Culprit<GraftValidationErr>> {
+        if self.grafts.remaining() < size_of::<H>() {
+            return Err(Culprit::new_with_note(
+                GraftValidationErr::InvalidSize,
+                "header size is larger than remaining data",
+            ));
+        }
+        let header = self.grafts.copy_to_bytes(size_of::<H>());
+        Ok(H::try_read_from_bytes(&header)?)
+    }
+
+    #[allow(clippy::type_complexity)]
     fn next_inner(
         &mut self,
-    ) -> Result<Option<(SegmentId, SplinterRef<Bytes>)>, Culprit<GraftValidationErr>> {
+    ) -> Result<
+        Option<(SegmentId, VolumeId, SplinterRef<Bytes>, GraftKind)>,
+        Culprit<GraftValidationErr>,
+    > {
         if !self.grafts.has_remaining() {
             return Ok(None);
         }
 
         // read the next header
-        if self.grafts.remaining() < std::mem::size_of::<GraftHeader>() {
-            return Err(Culprit::new_with_note(
-                GraftValidationErr::InvalidSize,
-                "header size is larger than remaining data",
-            ));
-        }
-        let header = self
-            .grafts
-            .copy_to_bytes(std::mem::size_of::<GraftHeader>());
-        let header = GraftHeader::try_read_from_bytes(&header)?;
+        let (sid, source_vid, size, kind) = match self.version {
+            0 => {
+                let header: LegacyGraftHeader = self.read_header()?;
+                (header.sid, self.vid.clone(), header.size, GraftKind::Pages)
+            }
+            _ => {
+                let header: GraftHeader = self.read_header()?;
+                (header.sid, header.source_vid, header.size, header.kind)
+            }
+        };
 
         // read the splinter
-        let splinter_len = header.size as usize;
+        let splinter_len = size as usize;
         if self.grafts.remaining() < splinter_len {
             return Err(Culprit::new_with_note(
                 GraftValidationErr::InvalidSize,
@@ -368,14 +774,163 @@ impl<T: Buf> GraftIter<T> {
         let splinter = self.grafts.copy_to_bytes(splinter_len);
         let splinter = SplinterRef::from_bytes(splinter).or_into_ctx()?;
 
-        Ok(Some((header.sid, splinter)))
+        Ok(Some((sid, source_vid, splinter, kind)))
     }
 }
 
 impl<T: Buf> Iterator for GraftIter<T> {
-    type Item = Result<(SegmentId, SplinterRef<Bytes>), Culprit<GraftValidationErr>>;
+    #[allow(clippy::type_complexity)]
+    type Item =
+        Result<(SegmentId, VolumeId, SplinterRef<Bytes>, GraftKind), Culprit<GraftValidationErr>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.next_inner().transpose()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use bytes::BytesMut;
+    use splinter_rs::Splinter;
+
+    use super::*;
+
+    #[graft_test::test]
+    fn test_commit_checksum() {
+        let vid = VolumeId::random();
+        let meta = CommitMeta::new(
+            vid.clone(),
+            ClientId::random(),
+            LSN::FIRST,
+            LSN::FIRST,
+            PageCount::new(3),
+            PageSize::MAX,
+            SystemTime::now(),
+        );
+        let mut commit = CommitBuilder::new_with_capacity(meta, 2);
+        let sid = SegmentId::random();
+        commit.write_graft(
+            sid.clone(),
+            Splinter::from_iter([1u32, 2, 3]).serialize_to_bytes(),
+        );
+        let source_vid = VolumeId::random();
+        commit.write_delta_base(
+            sid.clone(),
+            source_vid.clone(),
+            Splinter::from_iter([2u32]).serialize_to_bytes(),
+        );
+
+        let payload = commit.build().into_payload();
+        let data: BytesMut = payload.iter().flat_map(|b| b.iter().copied()).collect();
+
+        // a valid commit round trips
+        let commit = Commit::from_bytes(data.clone().freeze()).unwrap();
+        assert_eq!(commit.vid(), &vid);
+        assert_eq!(commit.meta().page_size(), PageSize::MAX);
+        let grafts = commit.iter_grafts().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(grafts.len(), 2);
+        assert_eq!(grafts[0].0, sid);
+        assert_eq!(grafts[0].1, vid);
+        assert_eq!(grafts[0].3, GraftKind::Pages);
+        assert_eq!(grafts[1].1, source_vid);
+        assert_eq!(grafts[1].3, GraftKind::DeltaBase);
+
+        // flipping any byte causes the commit to fail validation
+        for idx in [8, size_of::<CommitPrefix>() + 1, data.len() - 1] {
+            let mut corrupt = data.clone();
+            corrupt[idx] ^= 0xFF;
+            let Err(err) = Commit::from_bytes(corrupt.freeze()) else {
+                panic!("expected corrupt commit at byte {idx} to fail validation");
+            };
+            assert_matches!(err.ctx(), CommitValidationErr::ChecksumMismatch);
+        }
+
+        // a corrupt magic or unknown version is rejected before the checksum
+        let mut corrupt = data.clone();
+        corrupt[0] ^= 0xFF;
+        let err = Commit::from_bytes(corrupt.freeze()).err().unwrap();
+        assert_matches!(err.ctx(), CommitValidationErr::CorruptHeader(_));
+
+        let mut corrupt = data.clone();
+        corrupt[4] = COMMIT_VERSION + 1;
+        let err = Commit::from_bytes(corrupt.freeze()).err().unwrap();
+        assert_matches!(
+            err.ctx(),
+            CommitValidationErr::UnsupportedVersion(v) if *v == COMMIT_VERSION + 1
+        );
+    }
+
+    #[graft_test::test]
+    fn test_snapshot_rewrite() {
+        let meta = CommitMeta::new(
+            VolumeId::random(),
+            ClientId::random(),
+            LSN::new(2),
+            LSN::FIRST,
+            PageCount::new(3),
+            PageSize::DEFAULT,
+            SystemTime::now(),
+        );
+        let snapshot = meta.clone().into_snapshot();
+        assert!(!snapshot.is_rewrite());
+        let meta: CommitMeta = snapshot.try_into().unwrap();
+        assert!(!meta.is_rewrite());
+
+        // catalogs populated from snapshots can tell rewrites apart
+        let rewrite = CommitMeta::rewrite(&meta, LSN::new(3), LSN::FIRST, SystemTime::now());
+        let snapshot = rewrite.into_snapshot();
+        assert!(snapshot.is_rewrite());
+        let rewrite: CommitMeta = snapshot.try_into().unwrap();
+        assert!(rewrite.is_rewrite());
+        assert_eq!(rewrite.lsn(), LSN::new(3));
+    }
+
+    #[graft_test::test]
+    fn test_legacy_commit() {
+        let vid = VolumeId::random();
+        let header = LegacyCommitMeta {
+            magic: CommitMagic::Magic,
+            page_count: PageCount::new(3),
+            vid: vid.clone(),
+            cid: ClientId::random(),
+            lsn: LSN::new(2),
+            checkpoint_lsn: LSN::FIRST,
+            timestamp: 1234,
+        };
+        let sid = SegmentId::random();
+        let splinter = Splinter::from_iter([1u32, 2, 3]).serialize_to_bytes();
+        let graft = LegacyGraftHeader {
+            sid: sid.clone(),
+            size: splinter.len() as u32,
+        };
+
+        let mut data = BytesMut::new();
+        data.extend_from_slice(header.as_bytes());
+        data.extend_from_slice(graft.as_bytes());
+        data.extend_from_slice(&splinter);
+
+        let commit = Commit::from_bytes(data.freeze()).unwrap();
+        assert_eq!(commit.vid(), &vid);
+        assert_eq!(commit.meta().lsn(), LSN::new(2));
+        assert_eq!(commit.meta().checkpoint(), LSN::FIRST);
+        assert_eq!(commit.meta().page_count(), PageCount::new(3));
+        assert_eq!(commit.meta().page_size(), PageSize::DEFAULT);
+        assert!(!commit.meta().is_rewrite());
+
+        // legacy grafts reference pages stored under the commit's volume
+        let grafts = commit.iter_grafts().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(grafts.len(), 1);
+        assert_eq!(grafts[0].0, sid);
+        assert_eq!(grafts[0].1, vid);
+        assert_eq!(grafts[0].2.cardinality(), 3);
+        assert_eq!(grafts[0].3, GraftKind::Pages);
+
+        // catalogs may also store legacy snapshots
+        let meta = CommitMeta::decode(header.as_bytes()).unwrap();
+        assert_eq!(meta.lsn(), LSN::new(2));
+        assert_eq!(meta.page_size(), PageSize::DEFAULT);
+        let current = CommitMeta::decode(commit.meta().as_bytes()).unwrap();
+        assert_eq!(current.lsn(), LSN::new(2));
+    }
+}
@@ -0,0 +1,87 @@
+//! Pagestores learn about volume deletions from the Metastore. Rather than
+//! asking the Metastore on every read, each volume's status is cached for a
+//! configurable interval.
+
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use graft_client::MetastoreClient;
+use graft_core::VolumeId;
+use tokio::{task::spawn_blocking, time::Instant};
+
+struct Checked {
+    at: Instant,
+    deleted: bool,
+}
+
+#[derive(Default)]
+struct CheckedVolumes {
+    volumes: HashMap<VolumeId, Checked>,
+    swept: Option<Instant>,
+}
+
+impl CheckedVolumes {
+    /// Forget volumes whose status has expired, at most once per `interval`
+    fn sweep(&mut self, now: Instant, interval: Duration) {
+        if self
+            .swept
+            .is_some_and(|swept| now.duration_since(swept) < interval)
+        {
+            return;
+        }
+        self.swept = Some(now);
+        self.volumes
+            .retain(|_, checked| now.duration_since(checked.at) < interval);
+    }
+}
+
+pub struct VolumeDeletionChecker {
+    interval: Duration,
+    checked: Mutex<CheckedVolumes>,
+}
+
+impl VolumeDeletionChecker {
+    /// Ask the Metastore whether a volume has been deleted at most once per
+    /// `interval`
+    pub fn new(interval: Duration) -> Self {
+        Self { interval, checked: Default::default() }
+    }
+
+    /// Returns true if the Metastore reports that the volume has been deleted.
+    /// If the Metastore can't be reached the volume is assumed to exist, and
+    /// is checked again on the next call.
+    pub async fn is_deleted(&self, client: &MetastoreClient, vid: &VolumeId) -> bool {
+        let now = Instant::now();
+        {
+            let checked = self.checked.lock().unwrap();
+            if let Some(checked) = checked.volumes.get(vid) {
+                if now.duration_since(checked.at) < self.interval {
+                    return checked.deleted;
+                }
+            }
+        }
+
+        // TODO: switch this to an async client once one exists
+        let result = {
+            let client = client.clone();
+            let vid = vid.clone();
+            spawn_blocking(move || client.snapshot(&vid, None))
+                .await
+                .expect("spawn_blocking failed")
+        };
+        let deleted = match result {
+            Ok(_) => false,
+            Err(err) if err.ctx().is_volume_deleted() => true,
+            Err(err) => {
+                tracing::warn!(?vid, "failed to check if volume was deleted: {err:?}");
+                return false;
+            }
+        };
+
+        let mut checked = self.checked.lock().unwrap();
+        checked.sweep(now, self.interval);
+        checked
+            .volumes
+            .insert(vid.clone(), Checked { at: now, deleted });
+        deleted
+    }
+}
@@ -6,10 +6,13 @@ use std::{
 use fjall::Slice;
 use graft_core::{
     lsn::{LSN, LSNRangeExt},
+    zerocopy_ext::ZerocopyErr,
     {SegmentId, VolumeId},
 };
 use zerocopy::{BE, Immutable, IntoBytes, KnownLayout, TryFromBytes, U64, Unaligned};
 
+use super::commit::GraftKind;
+
 #[derive(KnownLayout, Immutable, TryFromBytes, IntoBytes, Unaligned)]
 #[repr(C, packed)]
 pub struct CommitKey {
@@ -68,11 +71,62 @@ impl Clone for CommitKey {
 pub struct SegmentKey {
     commit: CommitKey,
     sid: SegmentId,
+
+    /// the volume which the graft's pages are stored under in the segment
+    source_vid: VolumeId,
+
+    /// a fork may reference the same segment for both visible pages and
+    /// delta bases, so the kind is part of the key
+    kind: GraftKind,
+}
+
+/// The `SegmentKey` layout used before grafts had a kind. These keys always
+/// reference visible pages.
+#[derive(KnownLayout, Immutable, TryFromBytes, IntoBytes, Unaligned)]
+#[repr(C, packed)]
+struct UnkindedSegmentKey {
+    commit: CommitKey,
+    sid: SegmentId,
+    source_vid: VolumeId,
+}
+
+/// The `SegmentKey` layout used before grafts recorded their source volume.
+/// Legacy keys always reference pages stored under the commit's own volume.
+#[derive(KnownLayout, Immutable, TryFromBytes, IntoBytes, Unaligned)]
+#[repr(C, packed)]
+struct LegacySegmentKey {
+    commit: CommitKey,
+    sid: SegmentId,
 }
 
 impl SegmentKey {
-    pub fn new(commit: CommitKey, sid: SegmentId) -> Self {
-        Self { commit, sid }
+    pub fn new(commit: CommitKey, sid: SegmentId, source_vid: VolumeId) -> Self {
+        Self {
+            commit,
+            sid,
+            source_vid,
+            kind: GraftKind::Pages,
+        }
+    }
+
+    pub fn with_kind(mut self, kind: GraftKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Decode a `SegmentKey`, accepting keys written with earlier layouts
+    pub fn decode(data: &[u8]) -> Result<Self, ZerocopyErr> {
+        if data.len() == size_of::<LegacySegmentKey>() {
+            let LegacySegmentKey { commit, sid } = LegacySegmentKey::try_read_from_bytes(data)?;
+            let source_vid = commit.vid.clone();
+            return Ok(Self::new(commit, sid, source_vid));
+        }
+        if data.len() == size_of::<UnkindedSegmentKey>() {
+            let UnkindedSegmentKey { commit, sid, source_vid } =
+                UnkindedSegmentKey::try_read_from_bytes(data)?;
+            return Ok(Self::new(commit, sid, source_vid));
+        }
+        Ok(Self::try_read_from_bytes(data)?)
     }
 
     pub fn vid(&self) -> &VolumeId {
@@ -86,6 +140,22 @@ impl SegmentKey {
     pub fn sid(&self) -> &SegmentId {
         &self.sid
     }
+
+    /// The volume which the graft's pages are stored under in the segment.
+    /// This is the same as `vid` unless the graft was inherited from the
+    /// volume this volume was forked from.
+    pub fn source_vid(&self) -> &VolumeId {
+        &self.source_vid
+    }
+
+    pub fn kind(&self) -> GraftKind {
+        self.kind
+    }
+
+    #[inline]
+    pub fn is_delta_base(&self) -> bool {
+        self.kind == GraftKind::DeltaBase
+    }
 }
 
 impl AsRef<[u8]> for SegmentKey {
@@ -117,6 +187,44 @@ impl Clone for SegmentKey {
         Self {
             commit: self.commit.clone(),
             sid: self.sid.clone(),
+            source_vid: self.source_vid.clone(),
+            kind: self.kind,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[graft_test::test]
+    fn test_decode_legacy_segment_key() {
+        let vid = VolumeId::random();
+        let sid = SegmentId::random();
+        let commit = CommitKey::new(vid.clone(), LSN::new(3));
+
+        let legacy = LegacySegmentKey { commit: commit.clone(), sid: sid.clone() };
+        let key = SegmentKey::decode(legacy.as_bytes()).unwrap();
+        assert_eq!(key.vid(), &vid);
+        assert_eq!(key.lsn(), LSN::new(3));
+        assert_eq!(key.sid(), &sid);
+        assert_eq!(key.source_vid(), &vid);
+
+        let source_vid = VolumeId::random();
+        let unkinded = UnkindedSegmentKey {
+            commit: commit.clone(),
+            sid: sid.clone(),
+            source_vid: source_vid.clone(),
+        };
+        let key = SegmentKey::decode(unkinded.as_bytes()).unwrap();
+        assert_eq!(key.source_vid(), &source_vid);
+        assert_eq!(key.kind(), GraftKind::Pages);
+
+        let current = SegmentKey::new(commit, sid.clone(), source_vid.clone())
+            .with_kind(GraftKind::DeltaBase);
+        let key = SegmentKey::decode(current.as_bytes()).unwrap();
+        assert_eq!(key.sid(), &sid);
+        assert_eq!(key.source_vid(), &source_vid);
+        assert!(key.is_delta_base());
+    }
+}
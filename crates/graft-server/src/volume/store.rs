@@ -1,17 +1,24 @@
-use std::{future::ready, ops::RangeBounds, sync::Arc};
+use std::{collections::BTreeMap, future::ready, ops::RangeBounds, sync::Arc, time::SystemTime};
 
 use bytes::Bytes;
 use culprit::{Culprit, ResultExt};
-use futures::{Stream, TryStreamExt, stream::FuturesUnordered};
+use futures::{Stream, StreamExt, TryStreamExt, stream::FuturesUnordered};
 use graft_core::{
     VolumeId,
+    commit_token::CommitToken,
+    gid::GidParseErr,
     lsn::{LSN, LSNRangeExt},
 };
 use object_store::{Attributes, ObjectStore, PutMode, PutOptions, TagSet};
 
+use zerocopy::{IntoBytes, TryFromBytes};
+
 use crate::{
     bytes_vec::BytesVec,
-    volume::commit::{CommitValidationErr, commit_key_path_prefix},
+    volume::{
+        commit::{CommitValidationErr, commit_key_path_prefix, volumes_path_prefix},
+        transaction::{TransactionOutcome, transaction_key_path, transactions_path_prefix},
+    },
 };
 
 use super::commit::{Commit, CommitKeyParseErr, commit_key_path, parse_commit_key};
@@ -31,6 +38,12 @@ pub enum VolumeStoreErr {
 
     #[error("Failed to parse commit key: {0}")]
     CommitKeyParseErr(#[from] CommitKeyParseErr),
+
+    #[error("Failed to parse volume id: {0}")]
+    GidParseErr(#[from] GidParseErr),
+
+    #[error("corrupt transaction outcome")]
+    CorruptTransactionOutcome,
 }
 
 impl From<object_store::Error> for VolumeStoreErr {
@@ -42,13 +55,31 @@ impl From<object_store::Error> for VolumeStoreErr {
     }
 }
 
+/// A volume discovered by listing the commits in the store
+#[derive(Debug, Clone)]
+pub struct ListedVolume {
+    pub vid: VolumeId,
+
+    /// when the volume's earliest remaining commit was written
+    pub created_at: SystemTime,
+}
+
 pub struct VolumeStore {
     store: Arc<dyn ObjectStore>,
+
+    /// set if the object store lists keys in lexicographic order
+    ordered_listing: bool,
 }
 
 impl VolumeStore {
     pub fn new(store: Arc<dyn ObjectStore>) -> Self {
-        Self { store }
+        Self { store, ordered_listing: true }
+    }
+
+    /// Use with object stores which list keys in an arbitrary order, such as
+    /// the local filesystem. Listing volumes must then list every commit.
+    pub fn with_unordered_listing(self) -> Self {
+        Self { ordered_listing: false, ..self }
     }
 
     pub async fn commit(&self, commit: Commit<BytesVec>) -> Result<(), Culprit<VolumeStoreErr>> {
@@ -68,6 +99,92 @@ impl VolumeStore {
         Ok(())
     }
 
+    /// Record the outcome of the transaction identified by `token`, unless an
+    /// outcome has already been recorded. Returns the recorded outcome.
+    pub async fn decide_transaction(
+        &self,
+        token: &CommitToken,
+        outcome: TransactionOutcome,
+    ) -> Result<TransactionOutcome, Culprit<VolumeStoreErr>> {
+        let key = transaction_key_path(token);
+        let result = self
+            .store
+            .put_opts(
+                &key,
+                Bytes::copy_from_slice(outcome.as_bytes()).into(),
+                PutOptions {
+                    mode: PutMode::Create,
+                    tags: TagSet::default(),
+                    attributes: Attributes::default(),
+                    extensions: Default::default(),
+                },
+            )
+            .await;
+        match result {
+            Ok(_) => Ok(outcome),
+            Err(object_store::Error::AlreadyExists { .. }) => {
+                self.transaction_outcome(token).await?.ok_or_else(|| {
+                    Culprit::new_with_note(
+                        VolumeStoreErr::ObjectStoreErr,
+                        format!("transaction {token} outcome disappeared"),
+                    )
+                })
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Retrieve the outcome of the transaction identified by `token`. Returns
+    /// None if the transaction is still in progress.
+    pub async fn transaction_outcome(
+        &self,
+        token: &CommitToken,
+    ) -> Result<Option<TransactionOutcome>, Culprit<VolumeStoreErr>> {
+        let key = transaction_key_path(token);
+        let data = match self.store.get(&key).await {
+            Ok(result) => result.bytes().await?,
+            Err(object_store::Error::NotFound { .. }) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let outcome = TransactionOutcome::try_read_from_bytes(&data).map_err(|_| {
+            Culprit::new_with_note(
+                VolumeStoreErr::CorruptTransactionOutcome,
+                format!("invalid outcome for transaction {token}"),
+            )
+        })?;
+        Ok(Some(outcome))
+    }
+
+    /// List every transaction with a recorded outcome, along with the time
+    /// its outcome was recorded.
+    pub async fn list_transactions(
+        &self,
+    ) -> Result<Vec<(CommitToken, SystemTime)>, Culprit<VolumeStoreErr>> {
+        let mut transactions = vec![];
+        let mut listing = self.store.list(Some(&transactions_path_prefix()));
+        while let Some(meta) = listing.try_next().await? {
+            let Some(token) = meta.location.filename().and_then(|f| f.parse().ok()) else {
+                tracing::trace!("ignoring non-transaction object {}", meta.location);
+                continue;
+            };
+            transactions.push((token, meta.last_modified.into()));
+        }
+        Ok(transactions)
+    }
+
+    /// Delete the recorded outcome of a transaction. This is only safe once no
+    /// commit in the store carries the transaction's token, otherwise readers
+    /// would abort the transaction.
+    pub async fn delete_transaction(
+        &self,
+        token: &CommitToken,
+    ) -> Result<(), Culprit<VolumeStoreErr>> {
+        match self.store.delete(&transaction_key_path(token)).await {
+            Ok(()) | Err(object_store::Error::NotFound { .. }) => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
     /// Replay all commits for a volume contained by the specified LSN range.
     pub fn replay_unordered<'a, R: RangeBounds<LSN> + 'a>(
         &'a self,
@@ -116,4 +233,89 @@ impl VolumeStore {
         let data = commit.bytes().await?;
         Commit::from_bytes(data).or_into_ctx()
     }
+
+    /// List every volume which has at least one commit in the store.
+    pub async fn list_volumes(&self) -> Result<Vec<VolumeId>, Culprit<VolumeStoreErr>> {
+        let result = self
+            .store
+            .list_with_delimiter(Some(&volumes_path_prefix()))
+            .await?;
+        result
+            .common_prefixes
+            .into_iter()
+            .map(|prefix| {
+                let vid = prefix.filename().unwrap_or_default();
+                Ok(vid.parse()?)
+            })
+            .collect()
+    }
+
+    /// List up to `limit` volumes which have at least one commit, following
+    /// `cursor`. Volumes are ordered by their key in the store, which is the
+    /// string encoding of their volume ID. Listing stops once `limit` volumes
+    /// have been found, unless the store lists keys in an arbitrary order.
+    pub async fn list_volumes_after(
+        &self,
+        cursor: Option<&VolumeId>,
+        limit: usize,
+    ) -> Result<Vec<ListedVolume>, Culprit<VolumeStoreErr>> {
+        let prefix = volumes_path_prefix();
+        let mut stream = match cursor {
+            // skip every commit of the cursor volume
+            Some(cursor) => self
+                .store
+                .list_with_offset(Some(&prefix), &commit_key_path(cursor, LSN::LAST)),
+            None => self.store.list(Some(&prefix)),
+        };
+
+        let mut volumes: BTreeMap<String, (LSN, ListedVolume)> = BTreeMap::new();
+        while let Some(meta) = stream.try_next().await? {
+            let (vid, lsn) = parse_commit_key(&meta.location).or_into_ctx()?;
+            let listed = ListedVolume {
+                vid,
+                created_at: meta.last_modified.into(),
+            };
+            let earliest = volumes
+                .entry(listed.vid.pretty())
+                .or_insert_with(|| (lsn, listed.clone()));
+            if lsn < earliest.0 {
+                *earliest = (lsn, listed);
+            }
+            if volumes.len() > limit {
+                volumes.pop_last();
+                // in an ordered listing, every remaining key follows the
+                // volume which was just discarded
+                if self.ordered_listing {
+                    break;
+                }
+            }
+        }
+        Ok(volumes.into_values().map(|(_, listed)| listed).collect())
+    }
+
+    /// Delete all commits for a volume contained by the specified LSN range.
+    /// Returns the LSNs of the deleted commits.
+    pub async fn delete_commits<R: RangeBounds<LSN>>(
+        &self,
+        vid: &VolumeId,
+        range: &R,
+    ) -> Result<Vec<LSN>, Culprit<VolumeStoreErr>> {
+        let mut lsns = vec![];
+        let mut listing = self.store.list(Some(&commit_key_path_prefix(vid)));
+        while let Some(meta) = listing.try_next().await? {
+            let (key_vid, lsn) = parse_commit_key(&meta.location).or_into_ctx()?;
+            assert!(vid == &key_vid, "Unexpected volume ID in commit key");
+            if range.contains(&lsn) {
+                lsns.push(lsn);
+            }
+        }
+
+        let paths = futures::stream::iter(lsns.iter().map(|&lsn| Ok(commit_key_path(vid, lsn))));
+        self.store
+            .delete_stream(paths.boxed())
+            .try_for_each(|_| ready(Ok(())))
+            .await?;
+
+        Ok(lsns)
+    }
 }
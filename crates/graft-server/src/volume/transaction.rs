@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use graft_core::commit_token::CommitToken;
+use object_store::path::Path;
+use zerocopy::{Immutable, IntoBytes, KnownLayout, TryFromBytes};
+
+/// How long a transaction may remain undecided before the Metastore considers
+/// its coordinator to have failed and aborts it. A transaction's age is
+/// measured against the timestamp written by its coordinator, so the timeout
+/// includes headroom for clock skew between Metastores.
+pub const DEFAULT_TRANSACTION_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub fn transactions_path_prefix() -> Path {
+    Path::from("transactions")
+}
+
+pub fn transaction_key_path(token: &CommitToken) -> Path {
+    transactions_path_prefix().child(token.to_string())
+}
+
+/// The outcome of a multi-volume transaction. Each transaction writes a commit
+/// to every volume it touches and then records its outcome under the
+/// transaction's token. The outcome is written at most once, so whichever
+/// Metastore records it first decides whether the transaction's commits are
+/// visible.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, IntoBytes, TryFromBytes, Immutable, KnownLayout)]
+#[repr(u8)]
+pub enum TransactionOutcome {
+    Committed = 1,
+    Aborted = 2,
+}
@@ -1,5 +1,10 @@
-use std::{fmt::Debug, ops::RangeBounds};
+use std::{
+    fmt::Debug,
+    ops::RangeBounds,
+    time::{Duration, SystemTime},
+};
 
+use bytes::Bytes;
 use culprit::{Culprit, ResultExt};
 use futures::TryStreamExt;
 use graft_client::MetastoreClient;
@@ -14,8 +19,9 @@ use crate::limiter::{Limiter, Permit};
 
 use super::{
     catalog::{VolumeCatalog, VolumeCatalogErr},
-    commit::CommitMeta,
+    commit::{Commit, CommitMeta},
     store::{VolumeStore, VolumeStoreErr},
+    transaction::{DEFAULT_TRANSACTION_TIMEOUT, TransactionOutcome},
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -32,11 +38,23 @@ pub enum UpdateErr {
 
 pub struct VolumeCatalogUpdater {
     limiter: Limiter<VolumeId>,
+    transaction_timeout: Duration,
 }
 
 impl VolumeCatalogUpdater {
     pub fn new(concurrency_limit: usize) -> Self {
-        Self { limiter: Limiter::new(concurrency_limit) }
+        Self {
+            limiter: Limiter::new(concurrency_limit),
+            transaction_timeout: DEFAULT_TRANSACTION_TIMEOUT,
+        }
+    }
+
+    /// Abort transactions which remain undecided for longer than `timeout`.
+    /// The timeout must exceed the maximum clock skew between Metastores,
+    /// otherwise transactions may be aborted while their coordinator is
+    /// still writing them.
+    pub fn with_transaction_timeout(self, timeout: Duration) -> Self {
+        Self { transaction_timeout: timeout, ..self }
     }
 
     /// Load the specified volume snapshot, updating the catalog if necessary.
@@ -165,15 +183,28 @@ impl VolumeCatalogUpdater {
         vid: &VolumeId,
         lsns: &R,
     ) -> Result<(), Culprit<UpdateErr>> {
-        let mut commits = store.replay_unordered(vid.clone(), lsns);
+        let mut commits: Vec<_> = store
+            .replay_unordered(vid.clone(), lsns)
+            .try_collect()
+            .await
+            .or_into_ctx()?;
+        commits.sort_by_key(|commit| commit.meta().lsn());
 
         let mut latest_lsn = lsns.try_start();
-        if let Some(commit) = commits.try_next().await.or_into_ctx()? {
+        if !commits.is_empty() {
             // only create a batch if we have commits to replay
             let mut batch = catalog.batch_insert();
-            latest_lsn = latest_lsn.max(Some(commit.meta().lsn()));
-            batch.insert_commit(&commit).or_into_ctx()?;
-            while let Some(commit) = commits.try_next().await.or_into_ctx()? {
+            for commit in commits {
+                let commit = if commit.meta().is_transaction() {
+                    match self.resolve_transaction(store, commit).await? {
+                        Some(commit) => commit,
+                        // later commits can't land until the transaction
+                        // has been decided
+                        None => break,
+                    }
+                } else {
+                    commit
+                };
                 latest_lsn = latest_lsn.max(Some(commit.meta().lsn()));
                 batch.insert_commit(&commit).or_into_ctx()?;
             }
@@ -185,6 +216,43 @@ impl VolumeCatalogUpdater {
         Ok(())
     }
 
+    /// Resolve a commit which is part of a multi-volume transaction. Returns
+    /// the commit if the transaction committed, an empty commit if it
+    /// aborted, or None if it is still in progress. Transactions which have
+    /// been in progress for longer than the transaction timeout are aborted.
+    async fn resolve_transaction(
+        &self,
+        store: &VolumeStore,
+        commit: Commit<Bytes>,
+    ) -> Result<Option<Commit<Bytes>>, Culprit<UpdateErr>> {
+        let token = commit.meta().token();
+        let vid = commit.vid();
+        let lsn = commit.meta().lsn();
+
+        let outcome = match store.transaction_outcome(&token).await.or_into_ctx()? {
+            Some(outcome) => outcome,
+            None => {
+                let age = SystemTime::now()
+                    .duration_since(commit.meta().system_time())
+                    .unwrap_or_default();
+                if age < self.transaction_timeout {
+                    tracing::debug!(?vid, ?lsn, ?token, "transaction in progress");
+                    return Ok(None);
+                }
+                tracing::warn!(?vid, ?lsn, ?token, "aborting expired transaction");
+                store
+                    .decide_transaction(&token, TransactionOutcome::Aborted)
+                    .await
+                    .or_into_ctx()?
+            }
+        };
+
+        Ok(Some(match outcome {
+            TransactionOutcome::Committed => commit,
+            TransactionOutcome::Aborted => commit.rollback(),
+        }))
+    }
+
     pub async fn update_catalog_from_metastore(
         &self,
         client: &MetastoreClient,
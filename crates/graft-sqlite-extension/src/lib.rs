@@ -47,6 +47,15 @@ struct ExtensionConfig {
     #[serde(default = "default_autosync")]
     autosync: bool,
 
+    /// long-poll the metastore for remote changes rather than polling every
+    /// volume each second
+    #[serde(default)]
+    watch: bool,
+
+    /// allow the pagestore to return additional pages when reading
+    #[serde(default)]
+    prefetch: bool,
+
     #[serde(default = "ClientId::random")]
     client_id: ClientId,
 
@@ -92,11 +101,12 @@ pub unsafe extern "C" fn sqlite3_graft_init(
 
     let client = NetClient::new(config.token);
     let metastore_client = MetastoreClient::new(config.metastore, client.clone());
-    let pagestore_client = PagestoreClient::new(config.pagestore, client.clone());
+    let pagestore_client =
+        PagestoreClient::new(config.pagestore, client.clone()).with_prefetch(config.prefetch);
     let clients = ClientPair::new(metastore_client, pagestore_client);
 
     let storage = Storage::open(config.data_dir).unwrap();
-    let runtime = Runtime::new(config.client_id, clients, storage);
+    let runtime = Runtime::new(config.client_id, clients, storage).with_watch(config.watch);
 
     runtime
         .start_sync_task(Duration::from_secs(1), 8, config.autosync, "graft-sync")
@@ -16,11 +16,7 @@ use graft_client::{
         volume_writer::{VolumeWrite, VolumeWriter},
     },
 };
-use graft_core::{
-    PageIdx,
-    page::{PAGESIZE, Page},
-    page_count::PageCount,
-};
+use graft_core::{PageIdx, page::Page, page_count::PageCount};
 use parking_lot::{Mutex, MutexGuard};
 use sqlite_plugin::flags::{LockLevel, OpenOpts};
 
@@ -260,19 +256,21 @@ impl VfsFile for VolFile {
             VolFileState::Reserved { writer, .. } => writer.pages(),
             VolFileState::Committing => return ErrCtx::InvalidVolumeState.into(),
         };
-        Ok((PAGESIZE * pages.to_usize()).as_usize())
+        Ok(pages.size(self.handle.page_size()).as_usize())
     }
 
     fn read(&mut self, offset: usize, data: &mut [u8]) -> Result<usize, ErrCtx> {
+        let page_size = self.handle.page_size().as_usize();
+
         // locate the page offset of the requested page
-        let page_idx: PageIdx = ((offset / PAGESIZE.as_usize()) + 1)
+        let page_idx: PageIdx = ((offset / page_size) + 1)
             .try_into()
             .expect("offset out of volume range");
         // local_offset is the offset *within* the requested page
-        let local_offset = offset % PAGESIZE;
+        let local_offset = offset % page_size;
 
         assert!(
-            local_offset + data.len() <= PAGESIZE,
+            local_offset + data.len() <= page_size,
             "read must not cross page boundary"
         );
 
@@ -297,7 +295,7 @@ impl VfsFile for VolFile {
             VolFileState::Committing => return ErrCtx::InvalidVolumeState.into(),
         };
 
-        let range = local_offset.as_usize()..(local_offset + data.len()).as_usize();
+        let range = local_offset..(local_offset + data.len());
         data.copy_from_slice(&page[range]);
 
         // check to see if SQLite is reading the file change counter, and if so,
@@ -307,7 +305,7 @@ impl VfsFile for VolFile {
             && local_offset + data.len() >= FILE_CHANGE_COUNTER_OFFSET + 4
         {
             // find the location of the file change counter within the out buffer
-            let fcc_offset = FILE_CHANGE_COUNTER_OFFSET - local_offset.as_usize();
+            let fcc_offset = FILE_CHANGE_COUNTER_OFFSET - local_offset;
 
             // we derive the change counter from the snapshot via hashing
             let snapshot = self.snapshot_or_latest()?;
@@ -323,6 +321,7 @@ impl VfsFile for VolFile {
     }
 
     fn truncate(&mut self, size: usize) -> Result<(), ErrCtx> {
+        let page_size = self.handle.page_size();
         let VolFileState::Reserved { writer, .. } = &mut self.state else {
             return Err(Culprit::new_with_note(
                 ErrCtx::InvalidVolumeState,
@@ -331,12 +330,12 @@ impl VfsFile for VolFile {
         };
 
         assert_eq!(
-            size % PAGESIZE.as_usize(),
+            size % page_size.as_usize(),
             0,
-            "size must be an even multiple of {PAGESIZE}"
+            "size must be an even multiple of {page_size}"
         );
 
-        let pages: PageCount = (size / PAGESIZE.as_usize())
+        let pages: PageCount = (size / page_size.as_usize())
             .try_into()
             .expect("size too large");
 
@@ -345,6 +344,7 @@ impl VfsFile for VolFile {
     }
 
     fn write(&mut self, offset: usize, data: &[u8]) -> Result<usize, ErrCtx> {
+        let page_size = self.handle.page_size().as_usize();
         let VolFileState::Reserved { writer, .. } = &mut self.state else {
             return Err(Culprit::new_with_note(
                 ErrCtx::InvalidVolumeState,
@@ -353,18 +353,18 @@ impl VfsFile for VolFile {
         };
 
         // locate the requested page index
-        let page_idx: PageIdx = ((offset / PAGESIZE.as_usize()) + 1)
+        let page_idx: PageIdx = ((offset / page_size) + 1)
             .try_into()
             .expect("offset out of volume range");
         // local_offset is the offset *within* the requested page
-        let local_offset = offset % PAGESIZE;
+        let local_offset = offset % page_size;
 
         assert!(
-            local_offset + data.len() <= PAGESIZE,
+            local_offset + data.len() <= page_size,
             "write must not cross page boundary"
         );
 
-        let page = if data.len() == PAGESIZE {
+        let page = if data.len() == page_size {
             // writing a full page
             Page::try_from(data).expect("data is a full page")
         } else {
@@ -375,7 +375,7 @@ impl VfsFile for VolFile {
                 .or_into_ctx()?
                 .into();
             // SAFETY: we already verified that the write does not cross a page boundary
-            let range = local_offset.as_usize()..(local_offset + data.len()).as_usize();
+            let range = local_offset..(local_offset + data.len());
             page[range].copy_from_slice(data);
             page.try_into().expect("we did not change the page size")
         };
@@ -9,6 +9,7 @@ use culprit::Culprit;
 use graft_client::{ClientPair, MetastoreClient, NetClient, PagestoreClient};
 use graft_core::{
     PageCount, PageIdx,
+    byte_unit::ByteUnit,
     page::{PAGESIZE, Page},
     pageidx,
 };
@@ -22,10 +23,15 @@ use graft_server::{
     metrics::registry::Registry,
     object_store_util::ObjectStoreConfig,
     segment::{
-        cache::mem::MemCache, loader::SegmentLoader, uploader::SegmentUploaderTask,
+        cache::mem::MemCache,
+        compactor::{CompactionTrigger, SegmentCompactorConfig, SegmentCompactorTask},
+        compression::SegmentCompression,
+        loader::SegmentLoader,
+        uploader::SegmentUploaderTask,
         writer::SegmentWriterTask,
     },
     supervisor::{ShutdownErr, Supervisor},
+    tenant::TenantLimitsConfig,
     volume::{catalog::VolumeCatalog, store::VolumeStore, updater::VolumeCatalogUpdater},
 };
 use graft_tracing::{TracingConsumer, init_tracing_with_writer};
@@ -64,6 +70,13 @@ pub struct GraftBackend {
 }
 
 pub fn start_graft_backend() -> (GraftBackend, ClientPair) {
+    start_graft_backend_with_tenant_limits(TenantLimitsConfig::default())
+}
+
+/// Start a backend whose pagestore enforces the specified tenant limits
+pub fn start_graft_backend_with_tenant_limits(
+    tenant_limits: TenantLimitsConfig,
+) -> (GraftBackend, ClientPair) {
     let (shutdown_tx, shutdown_rx) = oneshot::channel();
     let (result_tx, result_rx) = oneshot::channel();
 
@@ -81,6 +94,7 @@ pub fn start_graft_backend() -> (GraftBackend, ClientPair) {
     let pagestore = runtime.block_on(run_pagestore(
         net_client.clone(),
         metastore.clone(),
+        tenant_limits,
         &mut supervisor,
     ));
 
@@ -122,7 +136,10 @@ pub async fn run_metastore(net_client: NetClient, supervisor: &mut Supervisor) -
     let vol_store = Arc::new(VolumeStore::new(obj_store));
     let catalog = VolumeCatalog::open_temporary().unwrap();
     let updater = VolumeCatalogUpdater::new(8);
-    let state = Arc::new(MetastoreApiState::new(vol_store, catalog, updater));
+    let state = Arc::new(
+        MetastoreApiState::new(vol_store, catalog, updater)
+            .with_shutdown(supervisor.shutdown_token()),
+    );
     let router = build_router(Registry::default(), None, state, metastore_routes());
     let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
     let port = listener.local_addr().unwrap().port();
@@ -134,6 +151,7 @@ pub async fn run_metastore(net_client: NetClient, supervisor: &mut Supervisor) -
 pub async fn run_pagestore(
     net_client: NetClient,
     metastore: MetastoreClient,
+    tenant_limits: TenantLimitsConfig,
     supervisor: &mut Supervisor,
 ) -> PagestoreClient {
     let mut registry = Registry::default();
@@ -145,6 +163,7 @@ pub async fn run_pagestore(
 
     let (page_tx, page_rx) = mpsc::channel(128);
     let (store_tx, store_rx) = mpsc::channel(8);
+    let (compact_tx, compact_rx) = mpsc::channel(128);
 
     supervisor.spawn(SegmentWriterTask::new(
         registry.segment_writer(),
@@ -156,18 +175,39 @@ pub async fn run_pagestore(
     supervisor.spawn(SegmentUploaderTask::new(
         registry.segment_uploader(),
         store_rx,
-        obj_store,
-        cache,
+        obj_store.clone(),
+        cache.clone(),
+        SegmentCompression::Zstd,
     ));
 
-    let state = Arc::new(PagestoreApiState::new(
-        page_tx,
+    // compact and checkpoint aggressively to exercise them in tests
+    let compaction_config = SegmentCompactorConfig {
+        min_segments: 8,
+        checkpoint_interval: 16,
+        compression: SegmentCompression::Lz4,
+        ..Default::default()
+    };
+    let compaction = CompactionTrigger::new(compact_tx, &compaction_config);
+    supervisor.spawn(SegmentCompactorTask::new(
+        registry.segment_compactor(),
+        compaction_config,
+        compact_rx,
+        obj_store,
+        cache,
         catalog.clone(),
-        loader,
-        metastore,
-        updater,
-        10,
+        metastore.clone(),
     ));
+
+    let state = Arc::new(
+        PagestoreApiState::new(page_tx, catalog.clone(), loader, metastore, updater, 10)
+            .with_compaction(compaction)
+            .with_page_deltas(true)
+            // clients must opt into prefetching with PagestoreClient::with_prefetch
+            .with_prefetch_budget(ByteUnit::from_kb(64))
+            // check every read so tests observe deletions immediately
+            .with_deletion_check_interval(Duration::ZERO)
+            .with_tenant_limits(tenant_limits),
+    );
     let router = build_router(registry, None, state, pagestore_routes());
 
     let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
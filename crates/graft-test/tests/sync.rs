@@ -1,19 +1,29 @@
-use std::time::Duration;
+use std::{
+    thread::sleep,
+    time::{Duration, SystemTime},
+};
 
 use graft_client::{
+    ClientErr, ClientPair,
     oracle::NoopOracle,
     runtime::{
         runtime::Runtime,
         storage::{
-            Storage,
+            Storage, StorageErr,
             volume_state::{SyncDirection, VolumeConfig},
         },
+        volume_handle::{RestoreTarget, VolumeHandle},
         volume_reader::VolumeRead,
         volume_writer::VolumeWrite,
     },
 };
-use graft_core::{PageIdx, VolumeId, gid::ClientId, page::Page};
-use graft_test::start_graft_backend;
+use graft_core::{
+    PageIdx, PageSize, VolumeId, byte_unit::ByteUnit, gid::ClientId, lsn::LSN, page::Page,
+    page_count::PageCount, pageidx,
+};
+use graft_server::tenant::{TenantLimits, TenantLimitsConfig};
+use graft_test::{start_graft_backend, start_graft_backend_with_tenant_limits};
+use splinter_rs::Splinter;
 
 #[graft_test::test]
 fn test_client_sync_sanity() {
@@ -79,3 +89,813 @@ fn test_client_sync_sanity() {
     runtime2.shutdown_sync_task(Duration::from_secs(5)).unwrap();
     backend.shutdown(Duration::from_secs(5)).unwrap();
 }
+
+#[graft_test::test]
+fn test_client_sync_watch() {
+    let (backend, clients) = start_graft_backend();
+
+    let storage = Storage::open_temporary().unwrap();
+    let runtime = Runtime::new(ClientId::random(), clients.clone(), storage);
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, true, "sync-1")
+        .unwrap();
+
+    // the second client watches for changes; its refresh interval is too long
+    // for polling to deliver the writes in time
+    let storage2 = Storage::open_temporary().unwrap();
+    let runtime2 = Runtime::new(ClientId::random(), clients, storage2).with_watch(true);
+    runtime2
+        .start_sync_task(Duration::from_secs(60), 8, true, "sync-2")
+        .unwrap();
+
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Push))
+        .unwrap();
+    let handle2 = runtime2
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Pull))
+        .unwrap();
+
+    let subscription = handle2.subscribe_to_remote_changes();
+
+    for i in 1..5 {
+        let page = Page::test_filled(i as u8);
+        let mut writer = handle.writer().unwrap();
+        writer.write(PageIdx::FIRST, page.clone());
+        writer.commit().unwrap();
+
+        subscription
+            .recv_timeout(Duration::from_secs(5))
+            .expect("subscription failed");
+
+        let snapshot = handle2.snapshot().unwrap().unwrap();
+        assert_eq!(snapshot.local(), i);
+        let reader = handle2.reader_at(Some(snapshot));
+        let received = reader.read(&mut NoopOracle, PageIdx::FIRST).unwrap();
+        assert_eq!(received, page, "received page does not match written page");
+    }
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    runtime2.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_client_restore() {
+    let (backend, clients) = start_graft_backend();
+
+    let storage = Storage::open_temporary().unwrap();
+    let runtime = Runtime::new(ClientId::random(), clients.clone(), storage);
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-1")
+        .unwrap();
+
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+
+    let commit = |pages: &[(PageIdx, u8)], truncate: Option<PageCount>| {
+        let mut writer = handle.writer().unwrap();
+        for &(pageidx, fill) in pages {
+            writer.write(pageidx, Page::test_filled(fill));
+        }
+        if let Some(truncate) = truncate {
+            writer.truncate(truncate);
+        }
+        writer.commit().unwrap();
+        handle.sync_with_remote(SyncDirection::Push).unwrap();
+        handle.snapshot().unwrap().unwrap().remote().unwrap()
+    };
+
+    let expect = |expected: &[(PageIdx, Page)]| {
+        let reader = handle.reader().unwrap();
+        assert_eq!(
+            reader.snapshot().unwrap().pages(),
+            PageCount::new(expected.len() as u32)
+        );
+        for (pageidx, page) in expected {
+            let actual = reader.read(&mut NoopOracle, *pageidx).unwrap();
+            assert_eq!(&actual, page, "page {pageidx} does not match");
+        }
+    };
+
+    let lsn1 = commit(&[(pageidx!(1), 1), (pageidx!(2), 2)], None);
+    let lsn2 = commit(&[(pageidx!(1), 3), (pageidx!(3), 4)], None);
+
+    // restore to the timestamp the metastore recorded for the second commit
+    let time2 = clients
+        .metastore()
+        .snapshot(&vid, Some(lsn2))
+        .unwrap()
+        .unwrap()
+        .system_time()
+        .unwrap()
+        .unwrap();
+
+    // commits are timestamped with millisecond precision, so ensure the next
+    // commit lands strictly after the second commit
+    while SystemTime::now() < time2 + Duration::from_millis(1) {
+        sleep(Duration::from_millis(1));
+    }
+
+    // truncate the volume and then grow it again
+    commit(&[], Some(PageCount::new(1)));
+    commit(&[(pageidx!(4), 5)], None);
+
+    // restore by time
+    handle.restore(RestoreTarget::Time(time2)).unwrap();
+    expect(&[
+        (pageidx!(1), Page::test_filled(3)),
+        (pageidx!(2), Page::test_filled(2)),
+        (pageidx!(3), Page::test_filled(4)),
+    ]);
+
+    // restore by lsn
+    let snapshot = handle.restore(RestoreTarget::Lsn(lsn1)).unwrap().unwrap();
+    assert!(snapshot.remote().unwrap() > lsn1);
+    expect(&[
+        (pageidx!(1), Page::test_filled(1)),
+        (pageidx!(2), Page::test_filled(2)),
+    ]);
+
+    // restoring to a missing lsn fails
+    let err = handle
+        .restore(RestoreTarget::Lsn(LSN::new(1000)))
+        .unwrap_err();
+    assert!(matches!(
+        err.ctx(),
+        graft_client::ClientErr::RestoreTargetMissing
+    ));
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_client_restore_checkpoint() {
+    let (backend, clients) = start_graft_backend();
+
+    let storage = Storage::open_temporary().unwrap();
+    let runtime = Runtime::new(ClientId::random(), clients.clone(), storage);
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-1")
+        .unwrap();
+
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+
+    let commit = |pages: &[(PageIdx, u8)]| {
+        let mut writer = handle.writer().unwrap();
+        for &(pageidx, fill) in pages {
+            writer.write(pageidx, Page::test_filled(fill));
+        }
+        writer.commit().unwrap();
+        handle.sync_with_remote(SyncDirection::Push).unwrap();
+        handle.snapshot().unwrap().unwrap().remote().unwrap()
+    };
+
+    let target = commit(&[
+        (pageidx!(1), 1),
+        (pageidx!(2), 2),
+        (pageidx!(3), 3),
+        (pageidx!(4), 4),
+    ]);
+
+    // rewrite the first page until the pagestore checkpoints the volume,
+    // which rewrites every page
+    let checkpointed = || {
+        clients
+            .metastore()
+            .pull_commits(&vid, target..)
+            .unwrap()
+            .iter()
+            .any(|commit| commit.snapshot().is_rewrite())
+    };
+    for fill in 5..=100 {
+        commit(&[(pageidx!(1), fill)]);
+        if checkpointed() {
+            break;
+        }
+    }
+    for _ in 0..50 {
+        if checkpointed() {
+            break;
+        }
+        sleep(Duration::from_millis(100));
+    }
+    assert!(checkpointed(), "expected the volume to be checkpointed");
+    commit(&[(pageidx!(2), 101)]);
+
+    // only the pages which changed since the target are rewritten
+    let snapshot = handle.restore(RestoreTarget::Lsn(target)).unwrap().unwrap();
+    let lsn = snapshot.remote().unwrap();
+    let commits = clients.metastore().pull_commits(&vid, lsn..=lsn).unwrap();
+    let mut rewritten = Splinter::default();
+    for commit in commits {
+        for segment in commit.segments {
+            for pageidx in segment.graft().unwrap().iter() {
+                rewritten.insert(pageidx);
+            }
+        }
+    }
+    assert_eq!(rewritten.iter().collect::<Vec<_>>(), vec![1, 2]);
+
+    let reader = handle.reader().unwrap();
+    for i in 1..=4u8 {
+        let pageidx = PageIdx::try_from(i as u32).unwrap();
+        assert_eq!(
+            reader.read(&mut NoopOracle, pageidx).unwrap(),
+            Page::test_filled(i)
+        );
+    }
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_client_fork() {
+    let (backend, clients) = start_graft_backend();
+
+    let storage = Storage::open_temporary().unwrap();
+    let runtime = Runtime::new(ClientId::random(), clients.clone(), storage);
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-1")
+        .unwrap();
+
+    let source_vid = VolumeId::random();
+    let source = runtime
+        .open_volume(&source_vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+
+    let commit = |handle: &VolumeHandle, pages: &[(PageIdx, u8)]| {
+        let mut writer = handle.writer().unwrap();
+        for &(pageidx, fill) in pages {
+            writer.write(pageidx, Page::test_filled(fill));
+        }
+        writer.commit().unwrap();
+        handle.sync_with_remote(SyncDirection::Push).unwrap();
+        handle.snapshot().unwrap().unwrap().remote().unwrap()
+    };
+
+    let expect = |handle: &VolumeHandle, expected: &[(PageIdx, u8)]| {
+        let reader = handle.reader().unwrap();
+        assert_eq!(
+            reader.snapshot().unwrap().pages(),
+            PageCount::new(expected.len() as u32)
+        );
+        for &(pageidx, fill) in expected {
+            let actual = reader.read(&mut NoopOracle, pageidx).unwrap();
+            assert_eq!(
+                actual,
+                Page::test_filled(fill),
+                "page {pageidx} does not match"
+            );
+        }
+    };
+
+    let lsn1 = commit(&source, &[(pageidx!(1), 1), (pageidx!(2), 2)]);
+    commit(&source, &[(pageidx!(2), 3), (pageidx!(3), 4)]);
+
+    // fork the latest and the first snapshot of the source volume
+    let fork_vid = VolumeId::random();
+    let snapshot = clients
+        .metastore()
+        .fork(&source_vid, None, &fork_vid, &ClientId::random())
+        .unwrap();
+    assert_eq!(snapshot.lsn().unwrap(), LSN::FIRST);
+    assert_eq!(snapshot.pages(), PageCount::new(3));
+
+    let old_fork_vid = VolumeId::random();
+    clients
+        .metastore()
+        .fork(&source_vid, Some(lsn1), &old_fork_vid, &ClientId::random())
+        .unwrap();
+
+    // the forks contain the source pages
+    let fork = runtime
+        .open_volume(&fork_vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+    fork.sync_with_remote(SyncDirection::Pull).unwrap();
+    expect(
+        &fork,
+        &[(pageidx!(1), 1), (pageidx!(2), 3), (pageidx!(3), 4)],
+    );
+
+    let old_fork = runtime
+        .open_volume(&old_fork_vid, VolumeConfig::new(SyncDirection::Pull))
+        .unwrap();
+    old_fork.sync_with_remote(SyncDirection::Pull).unwrap();
+    expect(&old_fork, &[(pageidx!(1), 1), (pageidx!(2), 2)]);
+
+    // writes to the fork are not visible in the source volume
+    commit(&fork, &[(pageidx!(1), 5)]);
+    expect(
+        &fork,
+        &[(pageidx!(1), 5), (pageidx!(2), 3), (pageidx!(3), 4)],
+    );
+    expect(
+        &source,
+        &[(pageidx!(1), 1), (pageidx!(2), 3), (pageidx!(3), 4)],
+    );
+
+    // a page which barely changed since the source checkpoint is stored as a
+    // delta, which the fork resolves against the source checkpoint
+    let mut data = Page::test_filled(1).as_ref().to_vec();
+    data[0] = 0xFF;
+    let page = Page::try_from(data.as_slice()).unwrap();
+    let mut writer = source.writer().unwrap();
+    writer.write(pageidx!(1), page.clone());
+    writer.commit().unwrap();
+    source.sync_with_remote(SyncDirection::Push).unwrap();
+
+    let delta_fork_vid = VolumeId::random();
+    clients
+        .metastore()
+        .fork(&source_vid, None, &delta_fork_vid, &ClientId::random())
+        .unwrap();
+    let delta_fork = runtime
+        .open_volume(&delta_fork_vid, VolumeConfig::new(SyncDirection::Pull))
+        .unwrap();
+    delta_fork.sync_with_remote(SyncDirection::Pull).unwrap();
+    let reader = delta_fork.reader().unwrap();
+    assert_eq!(reader.read(&mut NoopOracle, pageidx!(1)).unwrap(), page);
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_client_page_size() {
+    let (backend, clients) = start_graft_backend();
+
+    let storage = Storage::open_temporary().unwrap();
+    let runtime = Runtime::new(ClientId::random(), clients.clone(), storage);
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-1")
+        .unwrap();
+
+    let storage2 = Storage::open_temporary().unwrap();
+    let runtime2 = Runtime::new(ClientId::random(), clients, storage2);
+    runtime2
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-2")
+        .unwrap();
+
+    let page_size = PageSize::MAX;
+    let config = VolumeConfig::new(SyncDirection::Both).with_page_size(page_size);
+
+    let vid = VolumeId::random();
+    let handle = runtime.open_volume(&vid, config.clone()).unwrap();
+    assert_eq!(handle.page_size(), page_size);
+
+    // pages must match the volume page size
+    let mut writer = handle.writer().unwrap();
+    writer.write(pageidx!(1), Page::test_filled(1));
+    assert!(writer.commit().is_err());
+
+    let mut writer = handle.writer().unwrap();
+    writer.write(pageidx!(1), Page::test_filled_sized(1, page_size));
+    writer.write(pageidx!(3), Page::test_filled_sized(3, page_size));
+    writer.commit().unwrap();
+    handle.sync_with_remote(SyncDirection::Push).unwrap();
+
+    // the volume page size can't change once the volume has a snapshot
+    assert!(
+        runtime
+            .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+            .is_err()
+    );
+
+    // pulling the volume with a different page size fails
+    let mismatch = runtime2
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Pull))
+        .unwrap();
+    assert!(mismatch.sync_with_remote(SyncDirection::Pull).is_err());
+
+    // the volume has no local snapshot yet so its page size can be fixed
+    let handle2 = runtime2
+        .open_volume(&vid, config.with_sync(SyncDirection::Pull))
+        .unwrap();
+    handle2.sync_with_remote(SyncDirection::Pull).unwrap();
+    let reader = handle2.reader().unwrap();
+    assert_eq!(reader.snapshot().unwrap().pages(), PageCount::new(3));
+    for (pageidx, expected) in [
+        (pageidx!(1), Page::test_filled_sized(1, page_size)),
+        (pageidx!(2), Page::empty(page_size)),
+        (pageidx!(3), Page::test_filled_sized(3, page_size)),
+    ] {
+        let actual = reader.read(&mut NoopOracle, pageidx).unwrap();
+        assert_eq!(actual.size(), page_size);
+        assert_eq!(actual, expected, "page {pageidx} does not match");
+    }
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    runtime2.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_client_prefetch() {
+    let (backend, clients) = start_graft_backend();
+
+    let storage = Storage::open_temporary().unwrap();
+    let runtime = Runtime::new(ClientId::random(), clients.clone(), storage);
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-1")
+        .unwrap();
+
+    // the second client prefetches pages from the segments the pagestore
+    // loads to serve its reads
+    let clients2 = ClientPair::new(
+        clients.metastore().clone(),
+        clients.pagestore().clone().with_prefetch(true),
+    );
+    let storage2 = Storage::open_temporary().unwrap();
+    let runtime2 = Runtime::new(ClientId::random(), clients2, storage2);
+    runtime2
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-2")
+        .unwrap();
+
+    // write several pages into a single segment
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Push))
+        .unwrap();
+    let mut writer = handle.writer().unwrap();
+    for i in 1..=4u32 {
+        writer.write(PageIdx::try_from(i).unwrap(), Page::test_filled(i as u8));
+    }
+    writer.commit().unwrap();
+    handle.sync_with_remote(SyncDirection::Push).unwrap();
+
+    let handle2 = runtime2
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Pull))
+        .unwrap();
+    handle2.sync_with_remote(SyncDirection::Pull).unwrap();
+    let reader = handle2.reader().unwrap();
+
+    // reading the first page without an oracle prefetches the rest
+    assert_eq!(
+        reader.read(&mut NoopOracle, pageidx!(1)).unwrap(),
+        Page::test_filled(1)
+    );
+
+    // prefetched pages are stored locally, so they can be read without the
+    // backend
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    runtime2.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+    for i in 2..=4u32 {
+        let pageidx = PageIdx::try_from(i).unwrap();
+        assert_eq!(
+            reader.read(&mut NoopOracle, pageidx).unwrap(),
+            Page::test_filled(i as u8),
+            "page {pageidx} was not prefetched"
+        );
+    }
+}
+
+#[graft_test::test]
+fn test_client_page_deltas() {
+    let (backend, clients) = start_graft_backend();
+
+    let storage = Storage::open_temporary().unwrap();
+    let runtime = Runtime::new(ClientId::random(), clients.clone(), storage);
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-1")
+        .unwrap();
+
+    let storage2 = Storage::open_temporary().unwrap();
+    let runtime2 = Runtime::new(ClientId::random(), clients.clone(), storage2);
+    runtime2
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-2")
+        .unwrap();
+
+    let vid = VolumeId::random();
+    let writer_handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+
+    // the first commit is the checkpoint, subsequent commits only change a few
+    // bytes of page 1 and are stored as deltas against it
+    let mut data = Page::test_filled(1).to_vec();
+    let mut expected = vec![];
+    for i in 0..4u8 {
+        data[i as usize * 100] = 0xF0 | i;
+        let page = Page::try_from(data.as_slice()).unwrap();
+        let mut writer = writer_handle.writer().unwrap();
+        writer.write(pageidx!(1), page.clone());
+        writer.write(pageidx!(2), Page::test_filled(10 + i));
+        writer.commit().unwrap();
+        writer_handle.sync_with_remote(SyncDirection::Push).unwrap();
+        expected.push(page);
+    }
+
+    let expect = |handle: &VolumeHandle| {
+        let reader = handle.reader().unwrap();
+        assert_eq!(reader.snapshot().unwrap().pages(), PageCount::new(2));
+        let page = reader.read(&mut NoopOracle, pageidx!(1)).unwrap();
+        assert_eq!(&page, expected.last().unwrap());
+        let page = reader.read(&mut NoopOracle, pageidx!(2)).unwrap();
+        assert_eq!(page, Page::test_filled(13));
+    };
+
+    // a second client reconstructs the pages from the pagestore
+    let reader_handle = runtime2
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Pull))
+        .unwrap();
+    reader_handle.sync_with_remote(SyncDirection::Pull).unwrap();
+    expect(&reader_handle);
+
+    // deltas remain readable through a fork of the volume
+    let fork_vid = VolumeId::random();
+    clients
+        .metastore()
+        .fork(&vid, None, &fork_vid, &ClientId::random())
+        .unwrap();
+    let fork = runtime2
+        .open_volume(&fork_vid, VolumeConfig::new(SyncDirection::Pull))
+        .unwrap();
+    fork.sync_with_remote(SyncDirection::Pull).unwrap();
+    expect(&fork);
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    runtime2.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_client_delete_volume() {
+    let (backend, clients) = start_graft_backend();
+
+    let storage = Storage::open_temporary().unwrap();
+    let runtime = Runtime::new(ClientId::random(), clients.clone(), storage);
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-1")
+        .unwrap();
+
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+    let mut writer = handle.writer().unwrap();
+    writer.write(pageidx!(1), Page::test_filled(1));
+    writer.commit().unwrap();
+    handle.sync_with_remote(SyncDirection::Push).unwrap();
+
+    // delete the volume on the remote
+    let tombstone = clients
+        .metastore()
+        .delete_volume(&vid, runtime.cid())
+        .unwrap();
+    assert_eq!(tombstone.lsn().unwrap(), LSN::new(2));
+
+    // pushing a local commit fails, but keeps the volume in local storage
+    let mut writer = handle.writer().unwrap();
+    writer.write(pageidx!(2), Page::test_filled(2));
+    writer.commit().unwrap();
+    let err = handle.sync_with_remote(SyncDirection::Push).unwrap_err();
+    assert!(err.ctx().is_volume_deleted(), "unexpected error: {err:?}");
+    assert!(runtime.volume_exists(vid.clone()).unwrap());
+
+    // the next pull fails and drops the volume from local storage
+    let err = handle.sync_with_remote(SyncDirection::Pull).unwrap_err();
+    assert!(err.ctx().is_volume_deleted(), "unexpected error: {err:?}");
+    assert!(!runtime.volume_exists(vid.clone()).unwrap());
+
+    // the deleted volume can't be read or written
+    let err = clients.metastore().snapshot(&vid, None).unwrap_err();
+    assert!(err.ctx().is_volume_deleted());
+    let err = clients
+        .metastore()
+        .commit(
+            &vid,
+            runtime.cid(),
+            Some(LSN::new(2)),
+            PageCount::new(1),
+            PageSize::DEFAULT,
+            vec![],
+        )
+        .unwrap_err();
+    assert!(err.ctx().is_volume_deleted());
+    let graft = Splinter::from_slice(&[1]).serialize_to_bytes();
+    let err = clients
+        .pagestore()
+        .read_pages(&vid, LSN::FIRST, graft)
+        .unwrap_err();
+    assert!(err.ctx().is_volume_deleted(), "unexpected error: {err:?}");
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_client_commit_atomic() {
+    let (backend, clients) = start_graft_backend();
+
+    let storage = Storage::open_temporary().unwrap();
+    let runtime = Runtime::new(ClientId::random(), clients.clone(), storage);
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-1")
+        .unwrap();
+
+    let vid1 = VolumeId::random();
+    let vid2 = VolumeId::random();
+    let handle1 = runtime
+        .open_volume(&vid1, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+    let handle2 = runtime
+        .open_volume(&vid2, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+
+    // the first volume already exists on the remote
+    let mut writer = handle1.writer().unwrap();
+    writer.write(pageidx!(1), Page::test_filled(1));
+    writer.commit().unwrap();
+    handle1.sync_with_remote(SyncDirection::Push).unwrap();
+
+    // commit to both volumes at once
+    let mut writer1 = handle1.writer().unwrap();
+    writer1.write(pageidx!(2), Page::test_filled(2));
+    let mut writer2 = handle2.writer().unwrap();
+    writer2.write(pageidx!(1), Page::test_filled(3));
+    let readers = runtime.commit_atomic([writer1, writer2]).unwrap();
+
+    // the transaction is visible locally and doesn't need to be pushed
+    assert_eq!(
+        readers[0].read(&mut NoopOracle, pageidx!(2)).unwrap(),
+        Page::test_filled(2)
+    );
+    assert_eq!(
+        readers[1].read(&mut NoopOracle, pageidx!(1)).unwrap(),
+        Page::test_filled(3)
+    );
+    let snapshot1 = handle1.snapshot().unwrap().unwrap();
+    assert_eq!(snapshot1.remote(), Some(LSN::new(2)));
+    assert_eq!(snapshot1.remote_local(), Some(snapshot1.local()));
+    let snapshot2 = handle2.snapshot().unwrap().unwrap();
+    assert_eq!(snapshot2.remote(), Some(LSN::FIRST));
+    assert_eq!(snapshot2.remote_local(), Some(snapshot2.local()));
+
+    // a second client sees both commits
+    let storage = Storage::open_temporary().unwrap();
+    let runtime2 = Runtime::new(ClientId::random(), clients.clone(), storage);
+    runtime2
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-2")
+        .unwrap();
+    for (vid, pageidx, fill) in [(&vid1, pageidx!(2), 2), (&vid2, pageidx!(1), 3)] {
+        let handle = runtime2
+            .open_volume(vid, VolumeConfig::new(SyncDirection::Pull))
+            .unwrap();
+        handle.sync_with_remote(SyncDirection::Pull).unwrap();
+        let reader = handle.reader().unwrap();
+        assert_eq!(
+            reader.read(&mut NoopOracle, pageidx).unwrap(),
+            Page::test_filled(fill)
+        );
+    }
+
+    // volumes with local commits must be pushed before they can be part of a
+    // transaction, in which case no volume changes
+    let mut writer = handle1.writer().unwrap();
+    writer.write(pageidx!(3), Page::test_filled(4));
+    writer.commit().unwrap();
+    let mut writer1 = handle1.writer().unwrap();
+    writer1.write(pageidx!(4), Page::test_filled(5));
+    let mut writer2 = handle2.writer().unwrap();
+    writer2.write(pageidx!(2), Page::test_filled(6));
+    let err = runtime.commit_atomic([writer2, writer1]).unwrap_err();
+    assert!(
+        matches!(err.ctx(), ClientErr::StorageErr(StorageErr::PendingCommits)),
+        "unexpected error: {err:?}"
+    );
+    let remote = clients.metastore().snapshot(&vid2, None).unwrap().unwrap();
+    assert_eq!(remote.lsn().unwrap(), LSN::FIRST);
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    runtime2.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_compaction_settles() {
+    let (backend, clients) = start_graft_backend();
+
+    let storage = Storage::open_temporary().unwrap();
+    let runtime = Runtime::new(ClientId::random(), clients.clone(), storage);
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-1")
+        .unwrap();
+
+    // write each page in its own segment
+    let vid = VolumeId::random();
+    let handle = runtime
+        .open_volume(&vid, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+    for i in 1..=10u8 {
+        let mut writer = handle.writer().unwrap();
+        writer.write(PageIdx::try_from(i as u32).unwrap(), Page::test_filled(i));
+        writer.commit().unwrap();
+        handle.sync_with_remote(SyncDirection::Push).unwrap();
+    }
+    let lsn = LSN::new(10);
+    let latest = || {
+        let snapshot = clients.metastore().snapshot(&vid, None).unwrap().unwrap();
+        snapshot.lsn().unwrap()
+    };
+    assert_eq!(latest(), lsn);
+
+    // reading every page scans enough segments to request compaction, which
+    // appends a rewrite to the volume
+    let graft = Splinter::from_iter(1u32..=10).serialize_to_bytes();
+    clients
+        .pagestore()
+        .read_pages(&vid, lsn, graft.clone())
+        .unwrap();
+    for _ in 0..50 {
+        if latest() > lsn {
+            break;
+        }
+        sleep(Duration::from_millis(100));
+    }
+    assert_eq!(latest(), lsn.next().unwrap());
+
+    // reading the same snapshot requests compaction again, but the rewrite
+    // already covers the volume so nothing is appended
+    clients.pagestore().read_pages(&vid, lsn, graft).unwrap();
+    sleep(Duration::from_secs(1));
+    assert_eq!(latest(), lsn.next().unwrap());
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
+
+#[graft_test::test]
+fn test_client_storage_quota() {
+    // the quota fits four pages, and usage is recomputed on every write
+    let (backend, clients) = start_graft_backend_with_tenant_limits(TenantLimitsConfig {
+        default: TenantLimits {
+            max_storage: Some(ByteUnit::from_kb(16)),
+            ..Default::default()
+        },
+        storage_cache_ms: Some(0),
+        ..Default::default()
+    });
+
+    let storage = Storage::open_temporary().unwrap();
+    let runtime = Runtime::new(ClientId::random(), clients.clone(), storage);
+    runtime
+        .start_sync_task(Duration::from_secs(1), 8, false, "sync-1")
+        .unwrap();
+
+    let (vid1, vid2) = (VolumeId::random(), VolumeId::random());
+    let handle1 = runtime
+        .open_volume(&vid1, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+    let handle2 = runtime
+        .open_volume(&vid2, VolumeConfig::new(SyncDirection::Both))
+        .unwrap();
+
+    // the first volume uses the entire quota
+    let mut writer = handle1.writer().unwrap();
+    for i in 1..=4u8 {
+        writer.write(PageIdx::try_from(i as u32).unwrap(), Page::test_filled(i));
+    }
+    writer.commit().unwrap();
+    handle1.sync_with_remote(SyncDirection::Push).unwrap();
+
+    let mut writer = handle2.writer().unwrap();
+    writer.write(pageidx!(1), Page::test_filled(5));
+    writer.commit().unwrap();
+    let err = handle2.sync_with_remote(SyncDirection::Push).unwrap_err();
+    assert!(err.ctx().is_limit_exceeded(), "unexpected error: {err:?}");
+
+    // truncating the first volume frees enough quota for the second
+    let mut writer = handle1.writer().unwrap();
+    writer.truncate(PageCount::new(2));
+    writer.commit().unwrap();
+    handle1.sync_with_remote(SyncDirection::Push).unwrap();
+    handle2.sync_with_remote(SyncDirection::Push).unwrap();
+
+    let mut writer = handle2.writer().unwrap();
+    writer.write(pageidx!(2), Page::test_filled(6));
+    writer.write(pageidx!(3), Page::test_filled(7));
+    writer.commit().unwrap();
+    let err = handle2.sync_with_remote(SyncDirection::Push).unwrap_err();
+    assert!(err.ctx().is_limit_exceeded(), "unexpected error: {err:?}");
+
+    // deleting the first volume frees the rest of its quota
+    clients
+        .metastore()
+        .delete_volume(&vid1, runtime.cid())
+        .unwrap();
+    handle2.sync_with_remote(SyncDirection::Push).unwrap();
+    let remote = clients.metastore().snapshot(&vid2, None).unwrap().unwrap();
+    assert_eq!(remote.pages(), PageCount::new(3));
+
+    runtime.shutdown_sync_task(Duration::from_secs(5)).unwrap();
+    backend.shutdown(Duration::from_secs(5)).unwrap();
+}
@@ -53,6 +53,11 @@ enum Tool {
         #[clap(long)]
         sk: HexEncodedKey,
 
+        /// Issue the token to a Graft service, such as a pagestore, rather
+        /// than a client. Service tokens may call internal endpoints.
+        #[clap(long)]
+        service: bool,
+
         subject: String,
     },
 
@@ -87,12 +92,16 @@ fn main() {
             let rand = rand::random::<[u8; 32]>();
             println!("{}", hex::encode(rand));
         }
-        Tool::Token { sk, subject } => {
+        Tool::Token { sk, service, subject } => {
             use rusty_paseto::prelude::*;
 
             let key = PasetoSymmetricKey::from(Key::from(sk));
-            let token = PasetoBuilder::<V4, Local>::default()
-                .set_claim(SubjectClaim::from(subject.as_str()))
+            let mut builder = PasetoBuilder::<V4, Local>::default();
+            builder.set_claim(SubjectClaim::from(subject.as_str()));
+            if service {
+                builder.set_claim(CustomClaim::try_from(("graft_service", true)).unwrap());
+            }
+            let token = builder
                 .set_no_expiration_danger_acknowledged()
                 .build(&key)
                 .unwrap();